@@ -4,44 +4,234 @@ use core::fmt;
 
 use alloc::string::String;
 
+use crate::result::{Error, Result};
+
+mod aimer;
+mod aimer_delay;
+mod capabilities;
+pub mod data_edit;
+pub mod data_format;
+mod defaults;
+mod device_info;
+mod eci;
+mod feedback;
+mod fixed_list;
+mod host_ack;
+mod illumination;
 pub mod image_ship;
 pub mod image_snap;
+pub mod keyboard;
 #[macro_use]
 mod macros;
+mod led_power;
 mod manual_trigger;
 mod mobile_phone;
+mod modifier;
+pub mod operating_mode;
 mod pdf;
+pub mod postal;
 mod qr;
 mod query;
+mod read_timing;
+mod reply;
+mod reset;
+pub mod rs232;
 mod serial_trigger;
 mod software_rev;
+mod storage;
 mod symbologies;
+mod symbology_id;
 mod trigger;
+mod usb_interface;
 
-pub use image_ship::ImageShip;
-pub use image_snap::ImageSnap;
+pub use aimer::*;
+pub use aimer_delay::*;
+pub use capabilities::{Capabilities, EngineGeneration};
+pub use data_edit::{Prefix, Suffix};
+pub use data_format::{DataFormat, DataFormatCommand, FormatCommand};
+pub use defaults::*;
+pub use device_info::*;
+pub use eci::*;
+pub use feedback::*;
+pub use fixed_list::FixedList;
+pub use host_ack::*;
+pub use illumination::*;
+pub use image_ship::{ImageShip, ImageShipSequence};
+pub use image_snap::{ImageSnap, ImageSnapSequence};
+pub use keyboard::{ControlAscii, FunctionCodeTransmit, KeyboardCountry, KeyboardStyle};
+pub use led_power::*;
 pub use manual_trigger::*;
 pub use mobile_phone::*;
+pub use modifier::*;
+pub use operating_mode::{
+    CodeGate, HandsFreeTimeout, OperatingMode, OperatingModeConfig, PresentationCentering,
+    PresentationLed,
+};
 pub use pdf::*;
+pub use postal::{
+    AustralianPostInterpretation, PlanetCheckDigit, Postal, PostalConfig, PostnetCheckDigit,
+};
 pub use qr::*;
 pub use query::*;
+pub use read_timing::*;
+pub use reply::*;
+pub use reset::*;
+pub use rs232::{AckNak, BaudRate, RtsCts, WordLength, XonXoff};
 pub use serial_trigger::*;
 pub use software_rev::*;
+pub use storage::*;
 pub use symbologies::*;
+pub use symbology_id::*;
 pub use trigger::*;
+pub use usb_interface::*;
+
+/// Prefix for menu command frames (`<SYN>M<CR>`).
+pub const MENU_PREFIX: &str = "\x16M\x0d";
+
+type ParseCommand = fn(&str) -> Result<SerialCommand>;
+
+macro_rules! parser {
+    ($tag:literal, $ty:ident) => {
+        parser!($tag, $ty => $ty)
+    };
+    ($tag:literal, $ty:ident => $variant:ident) => {
+        ($tag, |v| $ty::try_from(v).map(SerialCommand::$variant))
+    };
+    ($tag:literal, $ty:ident::$parse:ident) => {
+        ($tag, |v| $ty::$parse(v).map(SerialCommand::$ty))
+    };
+}
+
+// Menu command tags, with the parser for each [SerialCommand] variant.
+//
+// Tags shared by several variants are tried in order, e.g. symbology ID before a custom prefix.
+const PARSERS: &[(&str, ParseCommand)] = &[
+    parser!("232ACK", AckNak),
+    parser!("232BAD", BaudRate),
+    parser!("232CTS", RtsCts),
+    parser!("232WRD", WordLength),
+    parser!("232XON", XonXoff),
+    parser!("ALLENA", AllSymbologies),
+    parser!("AOSCGD", CodeGate),
+    parser!("AUSINT", AustralianPostInterpretation),
+    parser!("BEPBEP", GoodReadBeep),
+    parser!("BEPBIP", BeepDuration),
+    parser!("BEPFQ1", BeeperPitch),
+    parser!("BEPLED", GoodReadLed),
+    parser!("BEPLVL", BeeperVolume),
+    parser!("BEPPWR", PowerUpBeep),
+    parser!("BEPRPT", BeepCount),
+    parser!("DEFALT", Defaults),
+    parser!("DEFOVR", Defaults),
+    parser!("DFM", DataFormatCommand => DataFormat),
+    parser!("DLYCHR", IntercharacterDelay),
+    parser!("DLYFNC", InterfunctionDelay),
+    parser!("DLYGRD", GoodReadDelay),
+    parser!("DLYRRD", RereadDelay),
+    parser!("DLYSCD", SameCodeDelay),
+    parser!("DLY_MG", IntermessageDelay),
+    parser!("ECIDEC", EciDecoder),
+    parser!("HSTACK", HostAck),
+    parser!("HSTDLY", HostAckTimeout),
+    parser!("IMGSHP", ImageShip::try_from_strict),
+    parser!("IMGSHP", ImageShipSequence),
+    parser!("IMGSNP", ImageSnap::try_from_strict),
+    parser!("IMGSNP", ImageSnapSequence),
+    parser!("KBDCAS", ControlAscii),
+    parser!("KBDCTY", KeyboardCountry),
+    parser!("KBDSTY", KeyboardStyle),
+    parser!("MNUCD", Defaults),
+    parser!("NETCKX", PostnetCheckDigit),
+    parser!("PAP", OperatingMode),
+    parser!("PAP", UsbInterface),
+    parser!("PDCLED", PresentationLed),
+    parser!("PDCWIN", PresentationCentering),
+    parser!("PDF", PDF417),
+    parser!("PLNCKX", PlanetCheckDigit),
+    parser!("POSTAL", Postal),
+    parser!("PRE", SymbologyIdTransmit),
+    parser!("PRE", Prefix),
+    parser!("PWRLDC", LedPowerLevel),
+    parser!("QRCDCP", QRCodePage),
+    parser!("QRC", QRCode),
+    parser!("RESET", Reset),
+    parser!("REVINF", SoftwareRevision),
+    parser!("RMVFNC", FunctionCodeTransmit),
+    parser!("SCNAIM", AimerMode),
+    parser!("SCNDLY", AimerDelay),
+    parser!("SCNLED", Illumination),
+    parser!("SUF", Suffix),
+    parser!("TRGPTO", HandsFreeTimeout),
+    parser!("TRGSTO", SerialTriggerMode),
+    parser!("TRMUSB", UsbInterface),
+];
 
 /// Represents Honeywell BCS serial commands.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum SerialCommand {
+    AckNak(AckNak),
+    AimerDelay(AimerDelay),
+    AimerMode(AimerMode),
     AllSymbologies(AllSymbologies),
+    AustralianPostInterpretation(AustralianPostInterpretation),
+    BaudRate(BaudRate),
+    BeepCount(BeepCount),
+    BeepDuration(BeepDuration),
+    BeeperPitch(BeeperPitch),
+    BeeperVolume(BeeperVolume),
+    CodeGate(CodeGate),
+    ControlAscii(ControlAscii),
+    DataFormat(DataFormatCommand),
+    Defaults(Defaults),
+    EciDecoder(EciDecoder),
+    FunctionCodeTransmit(FunctionCodeTransmit),
+    GoodReadBeep(GoodReadBeep),
+    GoodReadDelay(GoodReadDelay),
+    GoodReadLed(GoodReadLed),
+    HandsFreeTimeout(HandsFreeTimeout),
+    HostAck(HostAck),
+    HostAckTimeout(HostAckTimeout),
+    Illumination(Illumination),
     ImageSnap(ImageSnap),
+    ImageSnapSequence(ImageSnapSequence),
     ImageShip(ImageShip),
+    ImageShipSequence(ImageShipSequence),
+    IntercharacterDelay(IntercharacterDelay),
+    InterfunctionDelay(InterfunctionDelay),
+    IntermessageDelay(IntermessageDelay),
+    KeyboardCountry(KeyboardCountry),
+    KeyboardStyle(KeyboardStyle),
+    LedPowerLevel(LedPowerLevel),
     ManualTriggerMode(ManualTriggerMode),
     MobilePhoneReadMode(MobilePhoneReadMode),
+    OperatingMode(OperatingMode),
     PDF417(PDF417),
+    PlanetCheckDigit(PlanetCheckDigit),
+    Postal(Postal),
+    PostnetCheckDigit(PostnetCheckDigit),
+    PowerUpBeep(PowerUpBeep),
+    Prefix(Prefix),
+    PresentationCentering(PresentationCentering),
+    PresentationLed(PresentationLed),
     QRCode(QRCode),
+    QRCodePage(QRCodePage),
+    RereadDelay(RereadDelay),
+    Reset(Reset),
+    RtsCts(RtsCts),
+    SameCodeDelay(SameCodeDelay),
+    SerialTriggerMode(SerialTriggerMode),
     SoftwareRevision(SoftwareRevision),
+    Suffix(Suffix),
+    SymbologyIdTransmit(SymbologyIdTransmit),
     Trigger(Trigger),
+    UsbInterface(UsbInterface),
+    WordLength(WordLength),
+    XonXoff(XonXoff),
 }
 
 impl SerialCommand {
@@ -50,18 +240,79 @@ impl SerialCommand {
         Self::AllSymbologies(AllSymbologies::new())
     }
 
+    /// Validates the [SerialCommand] settings against the device [Capabilities].
+    ///
+    /// Only [ImageSnap] exposure limits differ between devices, other commands are range-checked
+    /// on construction and always valid.
+    pub fn validate(&self, caps: &Capabilities) -> Result<()> {
+        match self {
+            Self::ImageSnap(cmd) => cmd.validate(caps),
+            Self::ImageSnapSequence(cmd) => cmd.validate(caps),
+            _ => Ok(()),
+        }
+    }
+
     /// Gets the ASCII-encoded [SerialCommand].
     pub fn command(&self) -> String {
         match self {
+            Self::AckNak(cmd) => cmd.command().into(),
+            Self::AimerDelay(cmd) => cmd.command(),
+            Self::AimerMode(cmd) => cmd.command().into(),
             Self::AllSymbologies(cmd) => cmd.command().into(),
+            Self::AustralianPostInterpretation(cmd) => cmd.command().into(),
+            Self::BaudRate(cmd) => cmd.command(),
+            Self::BeepCount(cmd) => cmd.command(),
+            Self::BeepDuration(cmd) => cmd.command().into(),
+            Self::BeeperPitch(cmd) => cmd.command(),
+            Self::BeeperVolume(cmd) => cmd.command().into(),
+            Self::CodeGate(cmd) => cmd.command().into(),
+            Self::ControlAscii(cmd) => cmd.command().into(),
+            Self::DataFormat(cmd) => cmd.command(),
+            Self::Defaults(cmd) => cmd.command().into(),
+            Self::EciDecoder(cmd) => cmd.command().into(),
+            Self::FunctionCodeTransmit(cmd) => cmd.command().into(),
+            Self::GoodReadBeep(cmd) => cmd.command().into(),
+            Self::GoodReadDelay(cmd) => cmd.command(),
+            Self::GoodReadLed(cmd) => cmd.command().into(),
+            Self::HandsFreeTimeout(cmd) => cmd.command(),
+            Self::HostAck(cmd) => cmd.command().into(),
+            Self::HostAckTimeout(cmd) => cmd.command(),
+            Self::Illumination(cmd) => cmd.command().into(),
             Self::ImageSnap(cmd) => cmd.command(),
+            Self::ImageSnapSequence(cmd) => cmd.command(),
             Self::ImageShip(cmd) => cmd.command(),
+            Self::ImageShipSequence(cmd) => cmd.command(),
+            Self::IntercharacterDelay(cmd) => cmd.command(),
+            Self::InterfunctionDelay(cmd) => cmd.command(),
+            Self::IntermessageDelay(cmd) => cmd.command(),
+            Self::KeyboardCountry(cmd) => cmd.command(),
+            Self::KeyboardStyle(cmd) => cmd.command(),
+            Self::LedPowerLevel(cmd) => cmd.command().into(),
             Self::ManualTriggerMode(cmd) => cmd.command().into(),
             Self::MobilePhoneReadMode(cmd) => cmd.command().into(),
+            Self::OperatingMode(cmd) => cmd.command().into(),
             Self::PDF417(cmd) => cmd.command().into(),
+            Self::PlanetCheckDigit(cmd) => cmd.command().into(),
+            Self::Postal(cmd) => cmd.command(),
+            Self::PostnetCheckDigit(cmd) => cmd.command().into(),
+            Self::PowerUpBeep(cmd) => cmd.command().into(),
+            Self::Prefix(cmd) => cmd.command(),
+            Self::PresentationCentering(cmd) => cmd.command().into(),
+            Self::PresentationLed(cmd) => cmd.command().into(),
             Self::QRCode(cmd) => cmd.command().into(),
+            Self::QRCodePage(cmd) => cmd.command(),
+            Self::RereadDelay(cmd) => cmd.command(),
+            Self::Reset(cmd) => cmd.command().into(),
+            Self::RtsCts(cmd) => cmd.command().into(),
+            Self::SameCodeDelay(cmd) => cmd.command(),
+            Self::SerialTriggerMode(cmd) => cmd.command(),
             Self::SoftwareRevision(cmd) => cmd.command().into(),
+            Self::Suffix(cmd) => cmd.command(),
+            Self::SymbologyIdTransmit(cmd) => cmd.command(),
             Self::Trigger(cmd) => cmd.command().into(),
+            Self::UsbInterface(cmd) => cmd.command().into(),
+            Self::WordLength(cmd) => cmd.command(),
+            Self::XonXoff(cmd) => cmd.command().into(),
         }
     }
 }
@@ -72,11 +323,57 @@ impl Default for SerialCommand {
     }
 }
 
+impl TryFrom<&str> for SerialCommand {
+    type Error = Error;
+
+    /// Parses a [SerialCommand] from a single menu command, without the [Storage] terminator.
+    ///
+    /// If more than one variant accepts the command, the variant that re-encodes to the exact
+    /// command is preferred, e.g. [SymbologyIdTransmit] over a custom [Prefix].
+    ///
+    /// Image commands are parsed strictly, see [ImageShip::try_from_strict].
+    ///
+    /// If no variant accepts the command, returns the most detailed parse error.
+    fn try_from(val: &str) -> Result<Self> {
+        let mut first = None;
+        let mut err = Error::InvalidVariant;
+
+        for (_, parse) in PARSERS.iter().filter(|(tag, _)| val.starts_with(tag)) {
+            match parse(val) {
+                Ok(cmd) if cmd.command() == val => return Ok(cmd),
+                Ok(cmd) => {
+                    first.get_or_insert(cmd);
+                }
+                Err(e) if err == Error::InvalidVariant => err = e,
+                Err(_) => (),
+            }
+        }
+
+        first.ok_or(err)
+    }
+}
+
+// Length of the tag shared by the commands of a menu group, e.g. `BEP`.
+const TAG_LEN: usize = 3;
+
+// Expands a command that reuses the tag of the previous command, e.g. `LED0` after `BEPBEP1`.
+//
+// Commands that parse on their own set the `tag` for the commands following them.
+pub(crate) fn expand_tag<'a>(tag: &mut &'a str, cmd: &'a str) -> String {
+    if !tag.is_empty() && SerialCommand::try_from(cmd).is_err() {
+        format!("{tag}{cmd}")
+    } else {
+        *tag = cmd.get(..TAG_LEN).unwrap_or_default();
+        cmd.into()
+    }
+}
+
 /// Represents a Honeywell BCS serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Command {
     serial: SerialCommand,
     query: Option<QueryCommand>,
+    storage: Storage,
 }
 
 impl Command {
@@ -85,8 +382,47 @@ impl Command {
         Self {
             serial: SerialCommand::new(),
             query: None,
+            storage: Storage::new(),
         }
     }
+
+    /// Gets the [SerialCommand] for [Command].
+    pub const fn serial(&self) -> SerialCommand {
+        self.serial
+    }
+
+    /// Builder function that sets the [SerialCommand] for [Command].
+    pub fn with_serial(mut self, val: SerialCommand) -> Self {
+        self.serial = val;
+        self
+    }
+
+    /// Gets the [QueryCommand] for [Command].
+    pub const fn query(&self) -> Option<QueryCommand> {
+        self.query
+    }
+
+    /// Builder function that sets the [QueryCommand] for [Command].
+    pub fn with_query(mut self, val: QueryCommand) -> Self {
+        self.query = Some(val);
+        self
+    }
+
+    /// Gets the [Storage] for [Command].
+    pub const fn storage(&self) -> Storage {
+        self.storage
+    }
+
+    /// Builder function that sets the [Storage] for [Command].
+    pub fn with_storage(mut self, val: Storage) -> Self {
+        self.storage = val;
+        self
+    }
+
+    /// Gets the menu command frame sent to the device, including the [MENU_PREFIX].
+    pub fn frame(&self) -> String {
+        format!("{MENU_PREFIX}{self}")
+    }
 }
 
 impl Default for Command {
@@ -95,6 +431,31 @@ impl Default for Command {
     }
 }
 
+impl TryFrom<&str> for Command {
+    type Error = Error;
+
+    /// Parses a [Command] from a single menu command, with an optional [MENU_PREFIX] and
+    /// [Storage] terminator.
+    ///
+    /// Commands without a terminator are permanent.
+    fn try_from(val: &str) -> Result<Self> {
+        let val = val.trim();
+        let val = val.strip_prefix(MENU_PREFIX).unwrap_or(val);
+        let (val, storage) = match val.bytes().last().and_then(Storage::from_terminator) {
+            Some(storage) => (&val[..val.len() - 1], storage),
+            None => (val, Storage::new()),
+        };
+
+        SerialCommand::try_from(val).map(|cmd| Self::from(cmd).with_storage(storage))
+    }
+}
+
+impl From<SerialCommand> for Command {
+    fn from(val: SerialCommand) -> Self {
+        Self::new().with_serial(val)
+    }
+}
+
 impl fmt::Display for Command {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let cmd = self.serial.command();
@@ -102,7 +463,100 @@ impl fmt::Display for Command {
             .query
             .map(|q| String::from(q.command()))
             .unwrap_or_default();
+        let storage = self.storage.command();
+
+        write!(f, "{cmd}{query}{storage}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            "232BAD5",
+            "BEPLVL2",
+            "DLYGRD500",
+            "PAPSSM",
+            "PAP124",
+            "TRMUSB130",
+            "PREBK2995C80",
+            "PREBK2995C8041",
+            "PRECL299",
+            "SUFBK2990D",
+            "QRCDCP3",
+            "QRCDFT",
+            "MNUCDP",
+            "IMGSNP1P0L8000E",
+            "IMGSNP8000E1P0L",
+            "IMGSNP08E",
+            "IMGSNP0001P",
+            "IMGSHP1ix1iy50J8D",
+            "DFMBK30099999999F502F2140D",
+            "REVINF",
+        ]
+        .into_iter()
+        .for_each(|val| {
+            let cmd = SerialCommand::try_from(val).unwrap();
+            let copy = cmd;
+            assert_eq!(cmd.command(), val);
+            assert_eq!(Command::from(copy).serial(), cmd);
+        });
+
+        assert_eq!(
+            SerialCommand::try_from("PRECL299"),
+            Ok(SerialCommand::SymbologyIdTransmit(SymbologyIdTransmit::Off))
+        );
+        assert!(matches!(
+            SerialCommand::try_from("PREBK2995C8041"),
+            Ok(SerialCommand::Prefix(_))
+        ));
+        // modifiers out of field order, or repeated, keep the command order
+        assert!(matches!(
+            SerialCommand::try_from("IMGSNP1P0L8000E"),
+            Ok(SerialCommand::ImageSnap(_))
+        ));
+        assert!(matches!(
+            SerialCommand::try_from("IMGSNP8000E1P0L"),
+            Ok(SerialCommand::ImageSnapSequence(_))
+        ));
+
+        assert_eq!(
+            Command::try_from("\x16M\r232BAD5!"),
+            Ok(Command::from(SerialCommand::BaudRate(BaudRate::Baud9600))
+                .with_storage(Storage::Temporary))
+        );
+        assert_eq!(
+            Command::try_from("SCNAIM0"),
+            Ok(Command::from(SerialCommand::AimerMode(AimerMode::Off)))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        ["", "XYZABC", "BEPLVL7", "SCNAIM?", "\x16M\r."]
+            .into_iter()
+            .for_each(|val| {
+                assert_eq!(Command::try_from(val), Err(Error::InvalidVariant), "{val}");
+            });
 
-        write!(f, "{cmd}{query}.")
+        // known tags report the invalid data
+        assert_eq!(
+            Command::try_from("232BADx."),
+            Err(Error::invalid_data("232BAD", "x", 6))
+        );
+        assert_eq!(
+            SerialCommand::try_from("IMGSHP5000K"),
+            Err(Error::out_of_range(5000, 0, 1000)
+                .with_token("5000K", 6)
+                .with_tag("IMGSHP"))
+        );
+        // image commands are parsed strictly
+        assert_eq!(
+            SerialCommand::try_from("IMGSNP1P9Z"),
+            Err(Error::invalid_data("IMGSNP", "9Z", 8))
+        );
     }
 }
@@ -0,0 +1,72 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "SCNAIM0";
+const INTERLACED: &str = "SCNAIM1";
+const CONCURRENT: &str = "SCNAIM2";
+
+/// Represents the `Aimer Mode` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AimerMode {
+    /// Aimer off.
+    Off,
+    /// Aimer alternates with the illumination LEDs, and is off while an image is captured.
+    Interlaced,
+    /// Aimer stays on while an image is captured.
+    Concurrent,
+}
+
+impl AimerMode {
+    /// Creates a new [AimerMode].
+    pub const fn new() -> Self {
+        Self::Interlaced
+    }
+
+    /// Gets the ASCII serial command code for [AimerMode].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::Interlaced => INTERLACED,
+            Self::Concurrent => CONCURRENT,
+        }
+    }
+}
+
+impl Default for AimerMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for AimerMode {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(INTERLACED) => Ok(Self::Interlaced),
+            v if v.contains(CONCURRENT) => Ok(Self::Concurrent),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [AimerMode::Off, AimerMode::Interlaced, AimerMode::Concurrent]
+            .into_iter()
+            .zip([OFF, INTERLACED, CONCURRENT])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(AimerMode::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+use crate::serde_value;
+
+const AIMER_DELAY: &str = "SCNDLY";
+/// Maximum delay (in milliseconds) for [AimerDelay].
+pub const MAX_AIMER_DELAY: u32 = 4_000;
+
+/// Represents the `Aimer Delay` serial command.
+///
+/// Delay between the aimer turning on and image capture, allowing the user to aim at the barcode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AimerDelay {
+    ms: u32,
+}
+
+impl AimerDelay {
+    /// Creates a new [AimerDelay].
+    pub const fn new() -> Self {
+        Self { ms: 0 }
+    }
+
+    /// Gets the ASCII serial command code for [AimerDelay].
+    pub fn command(&self) -> String {
+        format!("{AIMER_DELAY}{}", self.ms)
+    }
+
+    /// Attempts to convert a [`u32`] number of delay milliseconds into a [AimerDelay].
+    ///
+    /// **NOTE**: `ms` must be below [MAX_AIMER_DELAY] number of milliseconds.
+    pub const fn try_from_ms(ms: u32) -> Result<Self> {
+        if ms <= MAX_AIMER_DELAY {
+            Ok(Self { ms })
+        } else {
+            Err(Error::out_of_range(
+                ms as usize,
+                0,
+                MAX_AIMER_DELAY as usize,
+            ))
+        }
+    }
+
+    /// Converts a [AimerDelay] into a [`u32`] number of milliseconds.
+    pub const fn into_ms(self) -> u32 {
+        self.ms
+    }
+}
+
+impl Default for AimerDelay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for AimerDelay {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(AIMER_DELAY).ok_or(Error::InvalidVariant)?;
+        let pos = i + AIMER_DELAY.len();
+
+        let ms = val[pos..]
+            .parse::<u32>()
+            .map_err(|_| Error::invalid_data(AIMER_DELAY, &val[pos..], pos))?;
+
+        Self::try_from_ms(ms).map_err(|err| err.with_tag(AIMER_DELAY))
+    }
+}
+
+impl TryFrom<String> for AimerDelay {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<Self> {
+        val.as_str().try_into()
+    }
+}
+
+serde_value!(AimerDelay, u32, Self::try_from_ms, |c| c.into_ms());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        (0..=MAX_AIMER_DELAY).for_each(|ms| {
+            let exp_cmd = AimerDelay { ms };
+            let exp_ascii_cmd = format!("{AIMER_DELAY}{ms}");
+
+            assert_eq!(AimerDelay::try_from_ms(ms), Ok(exp_cmd));
+            assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+            assert_eq!(AimerDelay::try_from(exp_ascii_cmd), Ok(exp_cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            AimerDelay::try_from_ms(MAX_AIMER_DELAY + 1),
+            Err(Error::out_of_range(
+                MAX_AIMER_DELAY as usize + 1,
+                0,
+                MAX_AIMER_DELAY as usize
+            ))
+        );
+        assert_eq!(
+            AimerDelay::try_from("SCNDLY99999"),
+            Err(Error::out_of_range(99999, 0, MAX_AIMER_DELAY as usize).with_tag(AIMER_DELAY))
+        );
+        assert_eq!(
+            AimerDelay::try_from("SCNDLY1s"),
+            Err(Error::invalid_data(AIMER_DELAY, "1s", 6))
+        );
+    }
+}
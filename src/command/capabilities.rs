@@ -0,0 +1,209 @@
+use crate::result::{Error, Result};
+
+use super::device_info::normalize;
+use super::image_snap::Exposure;
+use super::DeviceInfo;
+
+const GEN6: &str = "gen6";
+const GEN7: &str = "gen7";
+
+/// Represents the scan engine generation of a device.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum EngineGeneration {
+    /// Generation 6 scan engine.
+    #[default]
+    Gen6,
+    /// Generation 7 scan engine.
+    Gen7,
+}
+
+impl EngineGeneration {
+    /// Creates a new [EngineGeneration].
+    pub const fn new() -> Self {
+        Self::Gen6
+    }
+
+    /// Attempts to detect the [EngineGeneration] from a [DeviceInfo] report.
+    ///
+    /// Searches the product name and engine fields for a generation marker, e.g. `Gen7`.
+    pub fn from_device_info(info: &DeviceInfo) -> Option<Self> {
+        info.product_name()
+            .into_iter()
+            .chain(info.engine().iter().map(|(_, v)| v.as_str()))
+            .find_map(|v| {
+                let v = normalize(v);
+                if v.contains(GEN7) {
+                    Some(Self::Gen7)
+                } else if v.contains(GEN6) {
+                    Some(Self::Gen6)
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+/// Represents the configuration limits of a device.
+///
+/// [Exposure] is the only setting with a range that differs between engine generations. Limits
+/// default to the documented range for the [EngineGeneration], and can be further restricted for
+/// specific device models.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Capabilities {
+    generation: EngineGeneration,
+    max_exposure: u32,
+}
+
+impl Capabilities {
+    /// Creates a new [Capabilities] (GEN 6).
+    pub const fn new() -> Self {
+        Self::from_generation(EngineGeneration::Gen6)
+    }
+
+    /// Creates a new [Capabilities] with the limits of an [EngineGeneration].
+    pub const fn from_generation(generation: EngineGeneration) -> Self {
+        let max_exposure = match generation {
+            EngineGeneration::Gen6 => Exposure::max_units_gen6(),
+            EngineGeneration::Gen7 => Exposure::max_units_gen7(),
+        };
+
+        Self {
+            generation,
+            max_exposure,
+        }
+    }
+
+    /// Creates a new [Capabilities] from a [DeviceInfo] report.
+    ///
+    /// If the [EngineGeneration] cannot be detected, the more restrictive GEN 6 limits are used.
+    pub fn from_device_info(info: &DeviceInfo) -> Self {
+        Self::from_generation(EngineGeneration::from_device_info(info).unwrap_or_default())
+    }
+
+    /// Gets the [EngineGeneration].
+    pub const fn generation(&self) -> EngineGeneration {
+        self.generation
+    }
+
+    /// Gets the maximum [Exposure] units.
+    pub const fn max_exposure(&self) -> u32 {
+        self.max_exposure
+    }
+
+    /// Builder function that sets the maximum [Exposure] units.
+    pub const fn with_max_exposure(mut self, val: u32) -> Self {
+        self.max_exposure = val;
+        self
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<EngineGeneration> for Capabilities {
+    fn from(val: EngineGeneration) -> Self {
+        Self::from_generation(val)
+    }
+}
+
+impl From<&DeviceInfo> for Capabilities {
+    fn from(val: &DeviceInfo) -> Self {
+        Self::from_device_info(val)
+    }
+}
+
+// Checks that a setting is within the inclusive range supported by the device.
+pub(crate) fn check_range<T: Copy + Into<u32>>(value: T, min: T, max: T) -> Result<()> {
+    let (value, min, max) = (value.into(), min.into(), max.into());
+
+    if (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange {
+            value: value as usize,
+            min: min as usize,
+            max: max as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::image_ship::JpegImageQuality;
+    use crate::command::{ImageShip, ImageSnap, SerialCommand};
+
+    #[test]
+    fn test_valid() {
+        let gen6 = Capabilities::new();
+        let gen7 = Capabilities::from(EngineGeneration::Gen7);
+        let max6 = Exposure::try_from_unit(Exposure::max_units_gen6()).unwrap();
+        let max7 = Exposure::try_from_unit(Exposure::max_units_gen7()).unwrap();
+
+        assert_eq!(gen6.generation(), EngineGeneration::Gen6);
+        assert_eq!(gen6.max_exposure(), Exposure::max_units_gen6());
+        assert_eq!(gen7.max_exposure(), Exposure::max_units_gen7());
+
+        let snap = ImageSnap::new().with_exposure(max6);
+        assert_eq!(snap.validate(&gen6), Ok(()));
+        assert_eq!(SerialCommand::ImageSnap(snap).validate(&gen7), Ok(()));
+        assert_eq!(ImageSnap::new().validate(&gen6), Ok(()));
+
+        let snap = ImageSnap::new().with_exposure(max7);
+        assert_eq!(snap.validate(&gen7), Ok(()));
+
+        let ship = ImageShip::new()
+            .with_jpeg_image_quality(JpegImageQuality::try_from_quality(100).unwrap());
+        assert_eq!(SerialCommand::ImageShip(ship).validate(&gen6), Ok(()));
+        assert_eq!(SerialCommand::new().validate(&gen6), Ok(()));
+
+        let info = DeviceInfo::parse("Product Name: 3320g\nEngine Type: Gen7 N6700").unwrap();
+        assert_eq!(
+            EngineGeneration::from_device_info(&info),
+            Some(EngineGeneration::Gen7)
+        );
+        assert_eq!(Capabilities::from(&info), gen7);
+
+        let info = DeviceInfo::parse("Product Name: 3320g").unwrap();
+        assert_eq!(EngineGeneration::from_device_info(&info), None);
+        assert_eq!(Capabilities::from(&info), gen6);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let gen6 = Capabilities::new();
+        let gen7 = Capabilities::from(EngineGeneration::Gen7);
+        let units = Exposure::max_units_gen6() + 1;
+        let snap = ImageSnap::new().with_exposure(Exposure::try_from_unit(units).unwrap());
+
+        assert_eq!(
+            SerialCommand::ImageSnap(snap).validate(&gen6),
+            Err(Error::OutOfRange {
+                value: units as usize,
+                min: 1,
+                max: Exposure::max_units_gen6() as usize,
+            }
+            .with_tag("IMGSNP"))
+        );
+
+        let caps = gen7.with_max_exposure(Exposure::max_units_gen6());
+        assert_eq!(caps.generation(), EngineGeneration::Gen7);
+        assert_eq!(
+            SerialCommand::ImageSnap(snap).validate(&caps),
+            Err(Error::OutOfRange {
+                value: units as usize,
+                min: 1,
+                max: Exposure::max_units_gen6() as usize,
+            }
+            .with_tag("IMGSNP"))
+        );
+    }
+}
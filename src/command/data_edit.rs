@@ -0,0 +1,390 @@
+//! Types and algorithms related to `Prefix` / `Suffix` data editing configuration.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::decode::Symbology;
+use crate::result::{Error, Result};
+
+use super::{FixedList, QueryCommand};
+
+/// Maximum number of characters in an [Affix].
+pub const MAX_AFFIX_CHARS: usize = 32;
+
+const ALL_SYMBOLOGIES: u8 = 0x99;
+const CODE_ID_CHAR: &str = "5C80";
+const AIM_ID_CHAR: &str = "5C81";
+
+const PREFIX_ADD: &str = "PREBK2";
+const PREFIX_CLEAR: &str = "PRECL2";
+const PREFIX_CLEAR_ALL: &str = "PRECA2";
+const SUFFIX_ADD: &str = "SUFBK2";
+const SUFFIX_CLEAR: &str = "SUFCL2";
+const SUFFIX_CLEAR_ALL: &str = "SUFCA2";
+
+/// Represents the symbologies a data editing command applies to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SymbologyScope {
+    /// Applies to all symbologies (`99`).
+    All,
+    /// Applies to a single [Symbology], identified by its `Code ID`.
+    Symbology(Symbology),
+}
+
+impl SymbologyScope {
+    /// Creates a new [SymbologyScope].
+    pub const fn new() -> Self {
+        Self::All
+    }
+
+    /// Gets the two hex-digit code for the [SymbologyScope].
+    pub fn command(&self) -> String {
+        match self {
+            Self::All => format!("{ALL_SYMBOLOGIES:02X}"),
+            Self::Symbology(sym) => format!("{:02X}", sym.code_id()),
+        }
+    }
+}
+
+impl Default for SymbologyScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for SymbologyScope {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match parse_hex(val)? {
+            ALL_SYMBOLOGIES => Ok(Self::All),
+            id => Symbology::try_from_code_id(id).map(Self::Symbology),
+        }
+    }
+}
+
+/// Represents a single character added by a prefix or suffix.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum EditChar {
+    /// Literal byte value.
+    Byte(u8),
+    /// The Honeywell `Code ID` of the decoded symbology.
+    CodeId,
+    /// The `AIM ID` of the decoded symbology.
+    AimId,
+}
+
+impl EditChar {
+    /// Gets the hex-encoded [EditChar].
+    pub fn command(&self) -> String {
+        match self {
+            Self::Byte(b) => format!("{b:02X}"),
+            Self::CodeId => CODE_ID_CHAR.into(),
+            Self::AimId => AIM_ID_CHAR.into(),
+        }
+    }
+}
+
+fn parse_hex(val: &str) -> Result<u8> {
+    match val.as_bytes() {
+        [h, l] if h.is_ascii_hexdigit() && l.is_ascii_hexdigit() => {
+            u8::from_str_radix(val, 16).map_err(|_| Error::InvalidVariant)
+        }
+        _ => Err(Error::InvalidVariant),
+    }
+}
+
+/// Represents the characters added to decoded data for a [SymbologyScope].
+///
+/// Holds up to [MAX_AFFIX_CHARS] characters. With the `serde` feature, characters are
+/// represented as a hex string (e.g. `0D0A`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "AffixFields", try_from = "AffixFields")
+)]
+pub struct Affix {
+    scope: SymbologyScope,
+    chars: FixedList<EditChar, MAX_AFFIX_CHARS>,
+}
+
+impl Affix {
+    /// Attempts to create a new [Affix].
+    pub fn try_new(scope: SymbologyScope, chars: &[EditChar]) -> Result<Self> {
+        if chars.is_empty() {
+            Err(Error::InvalidVariant)
+        } else {
+            Ok(Self {
+                scope,
+                chars: FixedList::try_with_fill(EditChar::Byte(0), chars)?,
+            })
+        }
+    }
+
+    /// Attempts to create a new [Affix] from literal bytes.
+    pub fn try_from_bytes(scope: SymbologyScope, bytes: &[u8]) -> Result<Self> {
+        let chars: Vec<EditChar> = bytes.iter().copied().map(EditChar::Byte).collect();
+        Self::try_new(scope, &chars)
+    }
+
+    /// Attempts to create a new [Affix] from hex-encoded character values (e.g. `0D0A`).
+    pub fn try_from_hex(scope: SymbologyScope, hex: &str) -> Result<Self> {
+        if hex.is_empty() || !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+            return Err(Error::InvalidData {
+                data: hex.into(),
+                position: 0,
+            });
+        }
+
+        let mut chars = Vec::with_capacity(hex.len() / 2);
+        let mut pos = 0;
+
+        while pos < hex.len() {
+            let c = match hex.get(pos..pos + 4) {
+                Some(CODE_ID_CHAR) => EditChar::CodeId,
+                Some(AIM_ID_CHAR) => EditChar::AimId,
+                _ => {
+                    let data = &hex[pos..pos + 2];
+                    let b = parse_hex(data).map_err(|_| Error::InvalidData {
+                        data: data.into(),
+                        position: pos,
+                    })?;
+                    chars.push(EditChar::Byte(b));
+                    pos += 2;
+                    continue;
+                }
+            };
+            chars.push(c);
+            pos += 4;
+        }
+
+        Self::try_new(scope, &chars)
+    }
+
+    /// Gets the [SymbologyScope] of the [Affix].
+    pub const fn scope(&self) -> SymbologyScope {
+        self.scope
+    }
+
+    /// Gets the [EditChar]s of the [Affix].
+    pub fn chars(&self) -> &[EditChar] {
+        &self.chars
+    }
+
+    /// Gets the hex-encoded [SymbologyScope] and characters.
+    pub fn command(&self) -> String {
+        let mut cmd = self.scope.command();
+        self.chars.iter().for_each(|c| {
+            let _ = write!(cmd, "{}", c.command());
+        });
+        cmd
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AffixFields {
+    scope: SymbologyScope,
+    chars: String,
+}
+
+#[cfg(feature = "serde")]
+impl From<Affix> for AffixFields {
+    fn from(val: Affix) -> Self {
+        let scope = val.scope;
+        let chars = val.chars.iter().map(EditChar::command).collect();
+
+        Self { scope, chars }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<AffixFields> for Affix {
+    type Error = Error;
+
+    fn try_from(val: AffixFields) -> Result<Self> {
+        Self::try_from_hex(val.scope, &val.chars)
+    }
+}
+
+impl TryFrom<&str> for Affix {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let scope = val.get(..2).ok_or(Error::InvalidVariant)?;
+        let hex = val.get(2..).ok_or(Error::InvalidVariant)?;
+
+        Self::try_from_hex(SymbologyScope::try_from(scope)?, hex)
+    }
+}
+
+macro_rules! affix_command {
+    ($(#[$doc:meta])+ $cmd:ident: $add:ident, $clear:ident, $clear_all:ident$(,)?) => {
+        $(#[$doc])+
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(rename_all = "snake_case")
+        )]
+        pub enum $cmd {
+            /// Adds characters for a [SymbologyScope].
+            Add(Affix),
+            /// Clears characters for a [SymbologyScope].
+            Clear(SymbologyScope),
+            /// Clears characters for all symbologies.
+            ClearAll,
+        }
+
+        paste::paste! {
+            impl $cmd {
+                #[doc = "Creates a new [" $cmd "]."]
+                pub const fn new() -> Self {
+                    Self::ClearAll
+                }
+
+                #[doc = "Gets the ASCII serial command code for [" $cmd "]."]
+                pub fn command(&self) -> String {
+                    match self {
+                        Self::Add(affix) => format!("{}{}", $add, affix.command()),
+                        Self::Clear(scope) => format!("{}{}", $clear, scope.command()),
+                        Self::ClearAll => $clear_all.into(),
+                    }
+                }
+
+                #[doc = "Gets the ASCII serial command code to query the [" $cmd "] settings."]
+                pub fn query(query: QueryCommand) -> String {
+                    format!("{}{}", $add, query.command())
+                }
+            }
+
+            impl Default for $cmd {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl TryFrom<&str> for $cmd {
+                type Error = Error;
+
+                fn try_from(val: &str) -> Result<Self> {
+                    if let Some(i) = val.find($add) {
+                        Affix::try_from(&val[i + $add.len()..]).map(Self::Add)
+                    } else if let Some(i) = val.find($clear) {
+                        let scope = val
+                            .get(i + $clear.len()..i + $clear.len() + 2)
+                            .ok_or(Error::InvalidVariant)?;
+                        SymbologyScope::try_from(scope).map(Self::Clear)
+                    } else if val.contains($clear_all) {
+                        Ok(Self::ClearAll)
+                    } else {
+                        Err(Error::InvalidVariant)
+                    }
+                }
+            }
+        }
+    };
+}
+
+affix_command! {
+    /// Represents the `Prefix` data editing serial commands.
+    Prefix: PREFIX_ADD, PREFIX_CLEAR, PREFIX_CLEAR_ALL,
+}
+
+affix_command! {
+    /// Represents the `Suffix` data editing serial commands.
+    Suffix: SUFFIX_ADD, SUFFIX_CLEAR, SUFFIX_CLEAR_ALL,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let pdf = SymbologyScope::Symbology(Symbology::Pdf417);
+        let cr = Affix::try_from_bytes(SymbologyScope::All, b"\r").unwrap();
+        let stx = Affix::try_from_hex(pdf, "02").unwrap();
+        let ids =
+            Affix::try_new(SymbologyScope::All, &[EditChar::CodeId, EditChar::AimId]).unwrap();
+
+        [
+            (Prefix::Add(stx), "PREBK27202"),
+            (Prefix::Add(ids), "PREBK2995C805C81"),
+            (Prefix::Clear(pdf), "PRECL272"),
+            (Prefix::ClearAll, "PRECA2"),
+        ]
+        .into_iter()
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(Prefix::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+
+        [
+            (Suffix::Add(cr), "SUFBK2990D"),
+            (Suffix::Add(stx), "SUFBK27202"),
+            (Suffix::Clear(SymbologyScope::All), "SUFCL299"),
+            (Suffix::ClearAll, "SUFCA2"),
+        ]
+        .into_iter()
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(Suffix::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+
+        assert_eq!(Prefix::query(QueryCommand::CurrentValue), "PREBK2?");
+        assert_eq!(Suffix::query(QueryCommand::DefaultValue), "SUFBK2^");
+    }
+
+    #[test]
+    fn test_invalid() {
+        [
+            ("", "", 0),
+            ("0", "0", 0),
+            ("0D0", "0D0", 0),
+            ("0G", "0G", 0),
+            ("0D0Z", "0Z", 2),
+        ]
+        .into_iter()
+        .for_each(|(hex, data, position)| {
+            assert_eq!(
+                Affix::try_from_hex(SymbologyScope::All, hex),
+                Err(Error::InvalidData {
+                    data: data.into(),
+                    position
+                })
+            );
+        });
+
+        assert_eq!(
+            Affix::try_from_bytes(SymbologyScope::All, b""),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            Affix::try_from_bytes(SymbologyScope::All, &[b'-'; MAX_AFFIX_CHARS + 1]),
+            Err(Error::OutOfRange {
+                value: MAX_AFFIX_CHARS + 1,
+                min: 0,
+                max: MAX_AFFIX_CHARS
+            })
+        );
+        assert_eq!(Prefix::try_from("PRECL2"), Err(Error::InvalidVariant));
+        assert_eq!(
+            Prefix::try_from("PRECL27E"),
+            Err(Error::InvalidValue(b'~' as usize))
+        );
+        assert_eq!(Suffix::try_from("PREBK2990D"), Err(Error::InvalidVariant));
+    }
+}
@@ -0,0 +1,759 @@
+//! Types and algorithms related to `Data Format` editor configuration.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::decode::Symbology;
+use crate::result::{Error, Result};
+
+use super::{data_edit::SymbologyScope, FixedList};
+
+const DATA_FORMAT_ADD: &str = "DFMBK3";
+const DATA_FORMAT_CLEAR: &str = "DFMCL3";
+const DATA_FORMAT_CLEAR_ALL: &str = "DFMCA3";
+
+/// Maximum data format number (primary format `0`, alternate formats `1`-`3`).
+pub const MAX_FORMAT_NUMBER: u8 = 3;
+/// Terminal ID matching all terminal types.
+pub const ANY_TERMINAL: u16 = 99;
+/// Maximum terminal ID.
+pub const MAX_TERMINAL: u16 = 999;
+/// Message length matching reads of any length.
+pub const ANY_LENGTH: u16 = 9999;
+/// Maximum count parameter for format commands.
+pub const MAX_FORMAT_COUNT: u8 = 99;
+/// Maximum length of the encoded [FormatCommand]s of a [DataFormat].
+pub const MAX_FORMAT_LEN: usize = 200;
+
+// Length of the format number, terminal ID, code ID and length fields.
+const HEADER_LEN: usize = 10;
+
+/// Represents a single `Data Format` editor command.
+///
+/// Send commands output characters starting at the cursor, and advance the cursor past them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FormatCommand {
+    /// Send all remaining characters, followed by a character (`F1`).
+    SendAll(u8),
+    /// Send a number of characters, followed by a character (`F2`).
+    SendCount { count: u8, then: u8 },
+    /// Send all characters up to a stop character, followed by a character (`F3`).
+    SendUntil { stop: u8, then: u8 },
+    /// Insert a character a number of times (`F4`).
+    Insert { ch: u8, count: u8 },
+    /// Move the cursor forward a number of characters (`F5`).
+    Forward(u8),
+    /// Move the cursor backward a number of characters (`F6`).
+    Backward(u8),
+    /// Move the cursor to the beginning of the data (`F7`).
+    Home,
+    /// Move the cursor forward to the next occurrence of a character (`F8`).
+    ForwardTo(u8),
+    /// Move the cursor backward to the previous occurrence of a character (`F9`).
+    BackwardTo(u8),
+    /// Send all but the last number of characters (`E9`).
+    SendAllButLast(u8),
+    /// Move the cursor to the end of the data (`EA`).
+    End,
+    /// Suppress the listed characters in subsequent send commands (`FB`).
+    Suppress(Vec<u8>),
+    /// Stop suppressing characters (`FC`).
+    StopSuppress,
+    /// Replace characters in subsequent send commands, listed as `(from, to)` pairs (`E4`).
+    Replace(Vec<(u8, u8)>),
+    /// Stop replacing characters (`E5`).
+    StopReplace,
+    /// Compare the character at the cursor, moving the cursor forward one character on a match,
+    /// and failing the format on a mismatch (`FE`).
+    Compare(u8),
+    /// Check the character at the cursor is numeric, failing the format otherwise (`EC`).
+    CheckNumeric,
+    /// Check the character at the cursor is non-numeric, failing the format otherwise (`ED`).
+    CheckNonNumeric,
+}
+
+impl FormatCommand {
+    /// Gets the format string encoding of the [FormatCommand].
+    ///
+    /// Characters are encoded as two hex digits, counts as two decimal digits.
+    pub fn command(&self) -> String {
+        match self {
+            Self::SendAll(then) => format!("F1{then:02X}"),
+            Self::SendCount { count, then } => format!("F2{count:02}{then:02X}"),
+            Self::SendUntil { stop, then } => format!("F3{stop:02X}{then:02X}"),
+            Self::Insert { ch, count } => format!("F4{ch:02X}{count:02}"),
+            Self::Forward(n) => format!("F5{n:02}"),
+            Self::Backward(n) => format!("F6{n:02}"),
+            Self::Home => "F7".into(),
+            Self::ForwardTo(c) => format!("F8{c:02X}"),
+            Self::BackwardTo(c) => format!("F9{c:02X}"),
+            Self::SendAllButLast(n) => format!("E9{n:02}"),
+            Self::End => "EA".into(),
+            Self::Suppress(chars) => {
+                let mut cmd = format!("FB{:02}", chars.len());
+                chars.iter().for_each(|c| {
+                    let _ = write!(cmd, "{c:02X}");
+                });
+                cmd
+            }
+            Self::StopSuppress => "FC".into(),
+            Self::Replace(pairs) => {
+                let mut cmd = format!("E4{:02}", pairs.len() * 2);
+                pairs.iter().for_each(|(from, to)| {
+                    let _ = write!(cmd, "{from:02X}{to:02X}");
+                });
+                cmd
+            }
+            Self::StopReplace => "E5".into(),
+            Self::Compare(c) => format!("FE{c:02X}"),
+            Self::CheckNumeric => "EC".into(),
+            Self::CheckNonNumeric => "ED".into(),
+        }
+    }
+
+    /// Validates the count parameters of the [FormatCommand].
+    pub fn validate(&self) -> Result<()> {
+        let count = match self {
+            Self::SendCount { count, .. } | Self::Insert { count, .. } => *count as usize,
+            Self::Forward(n) | Self::Backward(n) | Self::SendAllButLast(n) => *n as usize,
+            Self::Suppress(chars) => chars.len(),
+            Self::Replace(pairs) => pairs.len() * 2,
+            _ => 0,
+        };
+
+        if count <= MAX_FORMAT_COUNT as usize {
+            Ok(())
+        } else {
+            Err(Error::out_of_range(count, 0, MAX_FORMAT_COUNT as usize))
+        }
+    }
+
+    /// Parses a [FormatCommand] from the start of a format string, returning the number of
+    /// characters consumed.
+    fn parse(val: &str) -> Result<(Self, usize)> {
+        let hex = |i: usize| {
+            val.get(i..i + 2)
+                .filter(|s| s.bytes().all(|c| c.is_ascii_hexdigit()))
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .ok_or(Error::InvalidVariant)
+        };
+        let dec = |i: usize| {
+            val.get(i..i + 2)
+                .filter(|s| s.bytes().all(|c| c.is_ascii_digit()))
+                .and_then(|s| s.parse::<u8>().ok())
+                .ok_or(Error::InvalidVariant)
+        };
+
+        let op = val.get(..2).ok_or(Error::InvalidVariant)?;
+        let cmd = match op {
+            "F1" => (Self::SendAll(hex(2)?), 4),
+            "F2" => (
+                Self::SendCount {
+                    count: dec(2)?,
+                    then: hex(4)?,
+                },
+                6,
+            ),
+            "F3" => (
+                Self::SendUntil {
+                    stop: hex(2)?,
+                    then: hex(4)?,
+                },
+                6,
+            ),
+            "F4" => (
+                Self::Insert {
+                    ch: hex(2)?,
+                    count: dec(4)?,
+                },
+                6,
+            ),
+            "F5" => (Self::Forward(dec(2)?), 4),
+            "F6" => (Self::Backward(dec(2)?), 4),
+            "F7" => (Self::Home, 2),
+            "F8" => (Self::ForwardTo(hex(2)?), 4),
+            "F9" => (Self::BackwardTo(hex(2)?), 4),
+            "E9" => (Self::SendAllButLast(dec(2)?), 4),
+            "EA" => (Self::End, 2),
+            "FB" => {
+                let n = dec(2)? as usize;
+                let chars = (0..n).map(|i| hex(4 + i * 2)).collect::<Result<Vec<_>>>()?;
+                (Self::Suppress(chars), 4 + n * 2)
+            }
+            "FC" => (Self::StopSuppress, 2),
+            "E4" => {
+                let n = dec(2)? as usize;
+                if !n.is_multiple_of(2) {
+                    return Err(Error::InvalidValue(n));
+                }
+                let pairs = (0..n / 2)
+                    .map(|i| Ok((hex(4 + i * 4)?, hex(6 + i * 4)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                (Self::Replace(pairs), 4 + n * 2)
+            }
+            "E5" => (Self::StopReplace, 2),
+            "FE" => (Self::Compare(hex(2)?), 4),
+            "EC" => (Self::CheckNumeric, 2),
+            "ED" => (Self::CheckNonNumeric, 2),
+            _ => return Err(Error::InvalidVariant),
+        };
+
+        Ok(cmd)
+    }
+}
+
+/// Represents a `Data Format` editor program.
+///
+/// Commands are stored encoded, as in the device format string, up to [MAX_FORMAT_LEN]
+/// characters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "DataFormatFields", try_from = "DataFormatFields")
+)]
+pub struct DataFormat {
+    number: u8,
+    terminal: u16,
+    scope: SymbologyScope,
+    length: u16,
+    commands: FixedList<u8, MAX_FORMAT_LEN>,
+}
+
+impl DataFormat {
+    /// Creates a new [DataFormat].
+    ///
+    /// The default format is the primary format (`0`), for all terminals, symbologies and
+    /// lengths, with no commands.
+    pub const fn new() -> Self {
+        Self {
+            number: 0,
+            terminal: ANY_TERMINAL,
+            scope: SymbologyScope::All,
+            length: ANY_LENGTH,
+            commands: FixedList::with_fill(0),
+        }
+    }
+
+    /// Gets the format number.
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Builder function that sets the format number (`0`-`3`).
+    pub fn with_number(mut self, number: u8) -> Result<Self> {
+        if number <= MAX_FORMAT_NUMBER {
+            self.number = number;
+            Ok(self)
+        } else {
+            Err(Error::out_of_range(
+                number as usize,
+                0,
+                MAX_FORMAT_NUMBER as usize,
+            ))
+        }
+    }
+
+    /// Gets the terminal ID.
+    pub const fn terminal(&self) -> u16 {
+        self.terminal
+    }
+
+    /// Builder function that sets the terminal ID ([ANY_TERMINAL] for all terminals).
+    pub fn with_terminal(mut self, terminal: u16) -> Result<Self> {
+        if terminal <= MAX_TERMINAL {
+            self.terminal = terminal;
+            Ok(self)
+        } else {
+            Err(Error::out_of_range(
+                terminal as usize,
+                0,
+                MAX_TERMINAL as usize,
+            ))
+        }
+    }
+
+    /// Gets the [SymbologyScope].
+    pub const fn scope(&self) -> SymbologyScope {
+        self.scope
+    }
+
+    /// Builder function that sets the [SymbologyScope].
+    pub fn with_scope(mut self, scope: SymbologyScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Gets the message length the format applies to.
+    pub const fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Builder function that sets the message length ([ANY_LENGTH] for all lengths).
+    pub fn with_length(mut self, length: u16) -> Result<Self> {
+        if length <= ANY_LENGTH {
+            self.length = length;
+            Ok(self)
+        } else {
+            Err(Error::out_of_range(length as usize, 0, ANY_LENGTH as usize))
+        }
+    }
+
+    /// Gets the list of [FormatCommand]s.
+    pub fn commands(&self) -> Vec<FormatCommand> {
+        let mut cmds = Vec::new();
+        let mut rem = self.encoded_commands();
+
+        // commands are validated when added, so always decode
+        while let Ok((cmd, len)) = FormatCommand::parse(rem) {
+            cmds.push(cmd);
+            rem = &rem[len..];
+        }
+
+        cmds
+    }
+
+    /// Builder function that appends a [FormatCommand].
+    ///
+    /// Returns an [OutOfRange](Error::OutOfRange) error if the encoded commands exceed
+    /// [MAX_FORMAT_LEN] characters.
+    pub fn with_command(mut self, cmd: FormatCommand) -> Result<Self> {
+        cmd.validate()?;
+        self.commands.try_extend(cmd.command().as_bytes())?;
+        Ok(self)
+    }
+
+    // Gets the encoded commands, as in the device format string.
+    fn encoded_commands(&self) -> &str {
+        core::str::from_utf8(&self.commands).unwrap_or_default()
+    }
+
+    /// Gets the device format string (header fields, followed by the encoded commands).
+    pub fn format_string(&self) -> String {
+        let mut fmt = format!(
+            "{}{:03}{}{:04}",
+            self.number,
+            self.terminal,
+            self.scope.command(),
+            self.length
+        );
+        fmt.push_str(self.encoded_commands());
+        fmt
+    }
+
+    /// Gets whether the [DataFormat] applies to a read of the given [Symbology] and length.
+    pub fn matches(&self, symbology: Option<Symbology>, len: usize) -> bool {
+        let sym_match = match (self.scope, symbology) {
+            (SymbologyScope::All, _) => true,
+            (SymbologyScope::Symbology(s), Some(sym)) => s.code_id() == sym.code_id(),
+            (SymbologyScope::Symbology(_), None) => false,
+        };
+
+        sym_match && (self.length == ANY_LENGTH || self.length as usize == len)
+    }
+
+    /// Applies the [DataFormat] program to a payload, as the device would before transmission.
+    ///
+    /// Returns [InvalidData](Error::InvalidData) with the failing command, and its position in the
+    /// [format string](Self::format_string), if the data does not satisfy the program (e.g. a failed compare, or moving the cursor out of
+    /// range). Devices fall back to alternate formats, or transmit a format error, in this case.
+    pub fn apply(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(payload.len());
+        let mut cursor = 0usize;
+        let mut suppress: Vec<u8> = Vec::new();
+        let mut replace: Vec<(u8, u8)> = Vec::new();
+        let len = payload.len();
+
+        let mut pos = HEADER_LEN;
+
+        for cmd in self.commands().iter() {
+            let data = cmd.command();
+            let err = Error::InvalidData {
+                data: data.clone(),
+                position: pos,
+            };
+            pos += data.len();
+
+            match cmd {
+                FormatCommand::SendAll(then) => {
+                    send(&mut out, &payload[cursor..], &suppress, &replace);
+                    out.push(*then);
+                    cursor = len;
+                }
+                FormatCommand::SendCount { count, then } => {
+                    let end = cursor + *count as usize;
+                    send(
+                        &mut out,
+                        payload.get(cursor..end).ok_or(err)?,
+                        &suppress,
+                        &replace,
+                    );
+                    out.push(*then);
+                    cursor = end;
+                }
+                FormatCommand::SendUntil { stop, then } => {
+                    let end = cursor
+                        + payload[cursor..]
+                            .iter()
+                            .position(|c| c == stop)
+                            .ok_or(err)?;
+                    send(&mut out, &payload[cursor..end], &suppress, &replace);
+                    out.push(*then);
+                    cursor = end;
+                }
+                FormatCommand::Insert { ch, count } => {
+                    out.extend(core::iter::repeat_n(*ch, *count as usize));
+                }
+                FormatCommand::Forward(n) => {
+                    cursor = Some(cursor + *n as usize)
+                        .filter(|&c| c <= len)
+                        .ok_or(err)?;
+                }
+                FormatCommand::Backward(n) => {
+                    cursor = cursor.checked_sub(*n as usize).ok_or(err)?;
+                }
+                FormatCommand::Home => cursor = 0,
+                FormatCommand::End => cursor = len,
+                FormatCommand::ForwardTo(c) => {
+                    cursor += payload[cursor..].iter().position(|p| p == c).ok_or(err)?;
+                }
+                FormatCommand::BackwardTo(c) => {
+                    cursor = payload[..cursor].iter().rposition(|p| p == c).ok_or(err)?;
+                }
+                FormatCommand::SendAllButLast(n) => {
+                    let end = len
+                        .checked_sub(*n as usize)
+                        .filter(|&e| e >= cursor)
+                        .ok_or(err)?;
+                    send(&mut out, &payload[cursor..end], &suppress, &replace);
+                    cursor = end;
+                }
+                FormatCommand::Suppress(chars) => suppress = chars.clone(),
+                FormatCommand::StopSuppress => suppress.clear(),
+                FormatCommand::Replace(pairs) => replace = pairs.clone(),
+                FormatCommand::StopReplace => replace.clear(),
+                FormatCommand::Compare(c) => {
+                    if payload.get(cursor) != Some(c) {
+                        return Err(err);
+                    }
+                    cursor += 1;
+                }
+                FormatCommand::CheckNumeric => {
+                    if !payload.get(cursor).is_some_and(u8::is_ascii_digit) {
+                        return Err(err);
+                    }
+                }
+                FormatCommand::CheckNonNumeric => {
+                    if payload.get(cursor).is_none_or(u8::is_ascii_digit) {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+// Sends data to the output, applying the active suppress and replace lists.
+fn send(out: &mut Vec<u8>, data: &[u8], suppress: &[u8], replace: &[(u8, u8)]) {
+    out.extend(data.iter().filter(|c| !suppress.contains(c)).map(|c| {
+        replace
+            .iter()
+            .find(|(from, _)| from == c)
+            .map(|(_, to)| *to)
+            .unwrap_or(*c)
+    }));
+}
+
+impl Default for DataFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for DataFormat {
+    type Error = Error;
+
+    /// Parses a [DataFormat] from its device format string.
+    fn try_from(val: &str) -> Result<Self> {
+        let header = val.get(..HEADER_LEN).ok_or(Error::InvalidVariant)?;
+        let num = |r: core::ops::Range<usize>| {
+            header[r.clone()]
+                .parse::<u16>()
+                .map_err(|_| Error::InvalidData {
+                    data: header[r.clone()].into(),
+                    position: r.start,
+                })
+        };
+
+        let mut fmt = Self::new()
+            .with_number(num(0..1)? as u8)?
+            .with_terminal(num(1..4)?)?
+            .with_scope(SymbologyScope::try_from(&header[4..6])?)
+            .with_length(num(6..10)?)?;
+
+        let mut pos = HEADER_LEN;
+        while pos < val.len() {
+            let (cmd, len) = FormatCommand::parse(&val[pos..]).map_err(|err| match err {
+                Error::InvalidVariant => Error::InvalidData {
+                    data: val[pos..].into(),
+                    position: pos,
+                },
+                err => err.with_token(&val[pos..], pos),
+            })?;
+            fmt = fmt.with_command(cmd)?;
+            pos += len;
+        }
+
+        Ok(fmt)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct DataFormatFields {
+    number: u8,
+    terminal: u16,
+    scope: SymbologyScope,
+    length: u16,
+    commands: Vec<FormatCommand>,
+}
+
+#[cfg(feature = "serde")]
+impl Default for DataFormatFields {
+    fn default() -> Self {
+        DataFormat::new().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<DataFormat> for DataFormatFields {
+    fn from(val: DataFormat) -> Self {
+        Self {
+            number: val.number,
+            terminal: val.terminal,
+            scope: val.scope,
+            length: val.length,
+            commands: val.commands(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DataFormatFields> for DataFormat {
+    type Error = Error;
+
+    fn try_from(val: DataFormatFields) -> Result<Self> {
+        val.commands.into_iter().try_fold(
+            Self::new()
+                .with_number(val.number)?
+                .with_terminal(val.terminal)?
+                .with_scope(val.scope)
+                .with_length(val.length)?,
+            Self::with_command,
+        )
+    }
+}
+
+/// Represents the `Data Format` editor serial commands.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum DataFormatCommand {
+    /// Adds a [DataFormat] program.
+    Add(DataFormat),
+    /// Clears the [DataFormat] program matching the format number, terminal, scope and length.
+    Clear(DataFormat),
+    /// Clears all [DataFormat] programs.
+    ClearAll,
+}
+
+impl DataFormatCommand {
+    /// Creates a new [DataFormatCommand].
+    pub const fn new() -> Self {
+        Self::ClearAll
+    }
+
+    /// Gets the ASCII serial command code for [DataFormatCommand].
+    pub fn command(&self) -> String {
+        match self {
+            Self::Add(fmt) => format!("{DATA_FORMAT_ADD}{}", fmt.format_string()),
+            Self::Clear(fmt) => {
+                format!("{DATA_FORMAT_CLEAR}{}", &fmt.format_string()[..HEADER_LEN])
+            }
+            Self::ClearAll => DATA_FORMAT_CLEAR_ALL.into(),
+        }
+    }
+}
+
+impl Default for DataFormatCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for DataFormatCommand {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        if let Some(i) = val.find(DATA_FORMAT_ADD) {
+            DataFormat::try_from(&val[i + DATA_FORMAT_ADD.len()..]).map(Self::Add)
+        } else if let Some(i) = val.find(DATA_FORMAT_CLEAR) {
+            let header = val
+                .get(i + DATA_FORMAT_CLEAR.len()..i + DATA_FORMAT_CLEAR.len() + HEADER_LEN)
+                .ok_or(Error::InvalidVariant)?;
+            DataFormat::try_from(header).map(Self::Clear)
+        } else if val.contains(DATA_FORMAT_CLEAR_ALL) {
+            Ok(Self::ClearAll)
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        // Strip the GS1 AI `01` from GS1-128 reads, and send the GTIN followed by CR
+        let fmt = DataFormat::new()
+            .with_scope(SymbologyScope::Symbology(Symbology::Gs1_128))
+            .with_command(FormatCommand::Forward(2))
+            .and_then(|f| {
+                f.with_command(FormatCommand::SendCount {
+                    count: 14,
+                    then: b'\r',
+                })
+            })
+            .unwrap();
+        let exp_ascii_cmd = "DFMBK30099499999F502F2140D";
+        let cmd = DataFormatCommand::Add(fmt);
+
+        assert_eq!(cmd.command(), exp_ascii_cmd);
+        assert_eq!(DataFormatCommand::try_from(exp_ascii_cmd), Ok(cmd));
+        assert_eq!(
+            fmt.apply(b"010950110153000310ABC"),
+            Ok(b"09501101530003\r".to_vec())
+        );
+        assert!(fmt.matches(Some(Symbology::Gs1_128), 21));
+        assert!(!fmt.matches(Some(Symbology::Code128), 21));
+
+        let fmt = DataFormat::try_from("1099999999F40203FB012DE4024142F82DF3430DE5F100").unwrap();
+
+        assert_eq!(fmt.number(), 1);
+        assert_eq!(
+            fmt.commands(),
+            [
+                FormatCommand::Insert { ch: 0x02, count: 3 },
+                FormatCommand::Suppress(vec![b'-']),
+                FormatCommand::Replace(vec![(b'A', b'B')]),
+                FormatCommand::ForwardTo(b'-'),
+                FormatCommand::SendUntil {
+                    stop: b'C',
+                    then: b'\r'
+                },
+                FormatCommand::StopReplace,
+                FormatCommand::SendAll(0),
+            ]
+        );
+
+        let fmt = DataFormat::new()
+            .with_command(FormatCommand::Compare(b'A'))
+            .and_then(|f| f.with_command(FormatCommand::CheckNumeric))
+            .and_then(|f| f.with_command(FormatCommand::SendAllButLast(2)))
+            .and_then(|f| f.with_command(FormatCommand::End))
+            .and_then(|f| f.with_command(FormatCommand::BackwardTo(b'-')))
+            .and_then(|f| f.with_command(FormatCommand::SendAll(b'\n')))
+            .unwrap();
+
+        assert_eq!(fmt.apply(b"A12-34"), Ok(b"12--34\n".to_vec()));
+
+        // a compare moves past the matched character, so a check follows directly
+        let fmt = DataFormat::new()
+            .with_command(FormatCommand::Compare(b'A'))
+            .and_then(|f| f.with_command(FormatCommand::CheckNonNumeric))
+            .and_then(|f| f.with_command(FormatCommand::SendAll(b'\n')))
+            .unwrap();
+
+        assert_eq!(fmt.apply(b"AB1"), Ok(b"B1\n".to_vec()));
+        assert_eq!(
+            fmt.apply(b"A1B"),
+            Err(Error::InvalidData {
+                data: "ED".into(),
+                position: 14
+            })
+        );
+        assert_eq!(DataFormatCommand::Clear(fmt).command(), "DFMCL30099999999");
+        assert_eq!(DataFormatCommand::ClearAll.command(), DATA_FORMAT_CLEAR_ALL);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let invalid = |data: &str, position| Error::InvalidData {
+            data: data.into(),
+            position,
+        };
+
+        assert_eq!(
+            DataFormat::new().with_number(4),
+            Err(Error::out_of_range(4, 0, 3))
+        );
+        assert_eq!(
+            DataFormat::new().with_terminal(1000),
+            Err(Error::out_of_range(1000, 0, 999))
+        );
+        assert_eq!(
+            DataFormat::new().with_command(FormatCommand::Forward(100)),
+            Err(Error::out_of_range(100, 0, 99))
+        );
+
+        [
+            ("", Error::InvalidVariant),
+            ("X099999999", invalid("X", 0)),
+            ("0099999999F", invalid("F", 10)),
+            ("0099999999F1", invalid("F1", 10)),
+            ("0099999999F7ZZ", invalid("ZZ", 12)),
+            ("0099999999FB02AA", invalid("FB02AA", 10)),
+            (
+                "0099999999E401AA",
+                Error::InvalidValue(1).with_token("E401AA", 10),
+            ),
+        ]
+        .into_iter()
+        .for_each(|(s, err)| {
+            assert_eq!(DataFormat::try_from(s), Err(err), "{s:?}");
+        });
+
+        let full = (0..MAX_FORMAT_LEN / 2).try_fold(DataFormat::new(), |f, _| {
+            f.with_command(FormatCommand::Home)
+        });
+        assert_eq!(
+            full.and_then(|f| f.with_command(FormatCommand::End)),
+            Err(Error::OutOfRange {
+                value: MAX_FORMAT_LEN + 2,
+                min: 0,
+                max: MAX_FORMAT_LEN
+            })
+        );
+
+        let fmt = DataFormat::new()
+            .with_command(FormatCommand::Compare(b'A'))
+            .and_then(|f| f.with_command(FormatCommand::SendCount { count: 5, then: 0 }))
+            .unwrap();
+
+        assert_eq!(fmt.apply(b"B1234"), Err(invalid("FE41", 10)));
+        assert_eq!(fmt.apply(b"A123"), Err(invalid("F20500", 14)));
+    }
+}
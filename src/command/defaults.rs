@@ -0,0 +1,89 @@
+use crate::result::{Error, Result};
+
+const ACTIVATE: &str = "DEFALT";
+const REMOVE_CUSTOM: &str = "DEFOVR";
+const SET_CUSTOM: &str = "MNUCDP";
+const SAVE_CUSTOM: &str = "MNUCDS";
+
+/// Represents the `Default Settings` serial commands.
+///
+/// To restore factory defaults, remove the custom defaults, then activate the defaults.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Defaults {
+    /// Activates the custom defaults, or the factory defaults if none are saved.
+    Activate,
+    /// Removes the custom defaults, so that [Defaults::Activate] restores the factory defaults.
+    RemoveCustom,
+    /// Starts recording the following menu commands as custom defaults.
+    SetCustom,
+    /// Saves the recorded menu commands as custom defaults.
+    SaveCustom,
+}
+
+impl Defaults {
+    /// Creates a new [Defaults].
+    pub const fn new() -> Self {
+        Self::Activate
+    }
+
+    /// Gets the ASCII serial command code for [Defaults].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Activate => ACTIVATE,
+            Self::RemoveCustom => REMOVE_CUSTOM,
+            Self::SetCustom => SET_CUSTOM,
+            Self::SaveCustom => SAVE_CUSTOM,
+        }
+    }
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for Defaults {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(ACTIVATE) => Ok(Self::Activate),
+            v if v.contains(REMOVE_CUSTOM) => Ok(Self::RemoveCustom),
+            v if v.contains(SET_CUSTOM) => Ok(Self::SetCustom),
+            v if v.contains(SAVE_CUSTOM) => Ok(Self::SaveCustom),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            Defaults::Activate,
+            Defaults::RemoveCustom,
+            Defaults::SetCustom,
+            Defaults::SaveCustom,
+        ]
+        .into_iter()
+        .zip([ACTIVATE, REMOVE_CUSTOM, SET_CUSTOM, SAVE_CUSTOM])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(Defaults::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Defaults::try_from("DEFAULT"), Err(Error::InvalidVariant));
+    }
+}
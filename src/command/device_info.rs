@@ -0,0 +1,331 @@
+use alloc::{string::String, vec::Vec};
+use core::{cmp, fmt};
+
+use crate::result::{Error, Result};
+
+use super::{MenuReply, SoftwareRevision};
+
+/// Represents a firmware version that can be compared against other versions.
+///
+/// Parsed from the numeric components of a revision string, e.g. `31205.01` or `1.2.3`.
+/// Trailing zero components are ignored, so `1.2` and `1.2.0` compare as equal.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FirmwareVersion {
+    parts: Vec<u32>,
+}
+
+impl FirmwareVersion {
+    /// Creates a new [FirmwareVersion].
+    pub const fn new() -> Self {
+        Self { parts: Vec::new() }
+    }
+
+    /// Creates a new [FirmwareVersion] from its numeric components.
+    pub fn from_parts(parts: &[u32]) -> Self {
+        let len = parts.iter().rposition(|&p| p != 0).map_or(0, |i| i + 1);
+
+        Self {
+            parts: parts[..len].into(),
+        }
+    }
+
+    /// Gets the numeric components of the [FirmwareVersion].
+    pub fn parts(&self) -> &[u32] {
+        &self.parts
+    }
+
+    /// Gets the major (first) version component.
+    pub fn major(&self) -> u32 {
+        self.parts.first().copied().unwrap_or_default()
+    }
+
+    /// Gets the minor (second) version component.
+    pub fn minor(&self) -> u32 {
+        self.parts.get(1).copied().unwrap_or_default()
+    }
+
+    /// Attempts to parse a [FirmwareVersion] from a revision string.
+    ///
+    /// Only the leading version token is parsed, so build metadata following whitespace or a `|`
+    /// separator (e.g. `15214|/tags/BF000144BAB`) is ignored.
+    pub fn parse(val: &str) -> Result<Self> {
+        let token = val
+            .trim()
+            .split(|c: char| c.is_whitespace() || c == '|')
+            .next()
+            .unwrap_or_default();
+        let token = token.strip_prefix(['v', 'V']).unwrap_or(token);
+
+        if token.is_empty() {
+            return Err(Error::InvalidVariant);
+        }
+
+        let parts = token
+            .split(['.', '-', '_'])
+            .map(|p| p.parse::<u32>().map_err(|_| Error::InvalidVariant))
+            .collect::<Result<Vec<u32>>>()?;
+
+        Ok(Self::from_parts(&parts))
+    }
+}
+
+impl PartialOrd for FirmwareVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FirmwareVersion {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        // trailing zeros are trimmed on construction, so lexicographic order is numeric order
+        self.parts.cmp(&other.parts)
+    }
+}
+
+impl fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.parts.split_first() {
+            Some((first, rest)) => {
+                write!(f, "{first}")?;
+                rest.iter().try_for_each(|p| write!(f, ".{p}"))
+            }
+            None => write!(f, "0"),
+        }
+    }
+}
+
+impl TryFrom<&str> for FirmwareVersion {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        Self::parse(val)
+    }
+}
+
+/// Represents the device information reported by the [SoftwareRevision] command.
+///
+/// Fields are matched by name, ignoring case, spacing and punctuation, so minor differences in
+/// the report format between firmware releases are tolerated. Fields that are not recognized
+/// are preserved in [DeviceInfo::extra].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeviceInfo {
+    product_name: Option<String>,
+    boot_revision: Option<String>,
+    software_part_number: Option<String>,
+    software_revision: Option<String>,
+    serial_number: Option<String>,
+    supported_interfaces: Option<String>,
+    pcb_assembly_id: Option<String>,
+    engine: Vec<(String, String)>,
+    extra: Vec<(String, String)>,
+}
+
+impl DeviceInfo {
+    /// Creates a new [DeviceInfo].
+    pub const fn new() -> Self {
+        Self {
+            product_name: None,
+            boot_revision: None,
+            software_part_number: None,
+            software_revision: None,
+            serial_number: None,
+            supported_interfaces: None,
+            pcb_assembly_id: None,
+            engine: Vec::new(),
+            extra: Vec::new(),
+        }
+    }
+
+    /// Gets the product name.
+    pub fn product_name(&self) -> Option<&str> {
+        self.product_name.as_deref()
+    }
+
+    /// Gets the boot firmware revision.
+    pub fn boot_revision(&self) -> Option<&str> {
+        self.boot_revision.as_deref()
+    }
+
+    /// Gets the application firmware part number.
+    pub fn software_part_number(&self) -> Option<&str> {
+        self.software_part_number.as_deref()
+    }
+
+    /// Gets the raw application firmware revision.
+    pub fn software_revision(&self) -> Option<&str> {
+        self.software_revision.as_deref()
+    }
+
+    /// Gets the device serial number.
+    pub fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    /// Gets the supported host interfaces.
+    pub fn supported_interfaces(&self) -> Option<&str> {
+        self.supported_interfaces.as_deref()
+    }
+
+    /// Gets the PCB assembly ID.
+    pub fn pcb_assembly_id(&self) -> Option<&str> {
+        self.pcb_assembly_id.as_deref()
+    }
+
+    /// Gets the scan engine fields, e.g. `Engine ID` and `Engine Firmware Revision`.
+    pub fn engine(&self) -> &[(String, String)] {
+        &self.engine
+    }
+
+    /// Gets the fields that are not otherwise recognized.
+    pub fn extra(&self) -> &[(String, String)] {
+        &self.extra
+    }
+
+    /// Gets the [FirmwareVersion] parsed from the software revision, if valid.
+    pub fn firmware_version(&self) -> Option<FirmwareVersion> {
+        self.software_revision
+            .as_deref()
+            .and_then(|r| FirmwareVersion::parse(r).ok())
+    }
+
+    /// Attempts to parse a [DeviceInfo] from the text of a [SoftwareRevision] report.
+    ///
+    /// The echoed `REVINF` command, if present, is skipped. Returns an error if no known field
+    /// is found.
+    pub fn parse(val: &str) -> Result<Self> {
+        let mut info = Self::new();
+        let mut known = false;
+
+        for line in val.split(['\r', '\n']) {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let key = key
+                .strip_prefix(SoftwareRevision::new().command())
+                .unwrap_or(key)
+                .trim();
+            let field = match normalize(key).as_str() {
+                "productname" | "product" => &mut info.product_name,
+                "bootrevision" | "bootrev" => &mut info.boot_revision,
+                "softwarepartnumber" | "softwarepartnum" => &mut info.software_part_number,
+                "softwarerevision" | "softwarerev" | "firmwarerevision" => {
+                    &mut info.software_revision
+                }
+                "serialnumber" | "serialnum" => &mut info.serial_number,
+                "supportedif" | "supportedinterfaces" => &mut info.supported_interfaces,
+                "pcbassemblyid" | "pcbid" => &mut info.pcb_assembly_id,
+                k if k.starts_with("engine") => {
+                    info.engine.push((key.into(), value.into()));
+                    known = true;
+                    continue;
+                }
+                _ => {
+                    info.extra.push((key.into(), value.into()));
+                    continue;
+                }
+            };
+
+            *field = Some(value.into());
+            known = true;
+        }
+
+        if known {
+            Ok(info)
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+}
+
+// Normalizes a field name or value for matching, keeping only lowercase alphanumerics.
+pub(crate) fn normalize(key: &str) -> String {
+    key.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+impl TryFrom<&str> for DeviceInfo {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        Self::parse(val)
+    }
+}
+
+impl TryFrom<&MenuReply> for DeviceInfo {
+    type Error = Error;
+
+    fn try_from(val: &MenuReply) -> Result<Self> {
+        val.ensure_ack()?;
+
+        val.entries()
+            .iter()
+            .find_map(|e| Self::parse(e.data()).ok())
+            .ok_or(Error::InvalidVariant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let report = "REVINF\r\nProduct Name: Vuquest 3320g\r\nBoot Revision: BF000106BAA\r\n\
+            Software Part Number: BF000144BAB\r\nSoftware Revision: 15214|/tags/BF000144BAB\r\n\
+            Serial Number: 20059B0A2B\r\nSupported IF: Standard\r\nPCB Assembly ID: 0\r\n\
+            Engine Firmware Type: 3\r\nEngine ID: 0x0011\r\nLicense: ABC\r\n";
+        let info = DeviceInfo::parse(report).unwrap();
+
+        assert_eq!(info.product_name(), Some("Vuquest 3320g"));
+        assert_eq!(info.boot_revision(), Some("BF000106BAA"));
+        assert_eq!(info.software_part_number(), Some("BF000144BAB"));
+        assert_eq!(info.software_revision(), Some("15214|/tags/BF000144BAB"));
+        assert_eq!(info.serial_number(), Some("20059B0A2B"));
+        assert_eq!(info.supported_interfaces(), Some("Standard"));
+        assert_eq!(info.pcb_assembly_id(), Some("0"));
+        assert_eq!(info.engine().len(), 2);
+        assert_eq!(info.engine()[1], ("Engine ID".into(), "0x0011".into()));
+        assert_eq!(info.extra(), [("License".into(), "ABC".into())]);
+        assert_eq!(
+            info.firmware_version(),
+            Some(FirmwareVersion::from_parts(&[15214]))
+        );
+
+        // alternate field names and line endings
+        let info =
+            DeviceInfo::parse("Product: 3320g\nSoftware Rev:31205.01\nSerial Num: 1").unwrap();
+        assert_eq!(info.product_name(), Some("3320g"));
+        assert_eq!(info.serial_number(), Some("1"));
+        assert_eq!(format!("{}", info.firmware_version().unwrap()), "31205.1");
+
+        let reply = MenuReply::parse(b"REVINF\r\nSoftware Revision: 1.2\x06.").unwrap();
+        assert_eq!(
+            DeviceInfo::try_from(&reply).unwrap().software_revision(),
+            Some("1.2")
+        );
+
+        let v = |s| FirmwareVersion::parse(s).unwrap();
+        assert_eq!(v("1.2"), v("1.2.0"));
+        assert_eq!(v("v2.0"), FirmwareVersion::from_parts(&[2]));
+        assert!(v("1.10") > v("1.9"));
+        assert!(v("31205.01") < v("31205.2"));
+        assert!(v("2") > v("1.99.99"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(DeviceInfo::parse(""), Err(Error::InvalidVariant));
+        assert_eq!(DeviceInfo::parse("REVINF"), Err(Error::InvalidVariant));
+        assert_eq!(DeviceInfo::parse("Unknown: 1"), Err(Error::InvalidVariant));
+
+        ["", "abc", "1.x", "BF000144BAB"].into_iter().for_each(|v| {
+            assert_eq!(FirmwareVersion::parse(v), Err(Error::InvalidVariant));
+        });
+
+        let info = DeviceInfo::parse("Software Revision: unknown").unwrap();
+        assert_eq!(info.firmware_version(), None);
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::string::String;
+
+use crate::decode::CharacterSet;
+use crate::result::{Error, Result};
+use crate::serde_value;
+
+const ECI_OFF: &str = "ECIDEC0";
+const ECI_ON: &str = "ECIDEC1";
+const QR_CODE_PAGE: &str = "QRCDCP";
+const CODE_PAGE_DEFAULT: u8 = 3;
+/// Maximum device code page number for [QRCodePage].
+pub const MAX_CODE_PAGE: u8 = 99;
+
+/// Represents the `ECI Decoder` serial command.
+///
+/// When enabled, ECI designators are transmitted as `\nnnnnn` escape sequences, see
+/// [TextDecoder](crate::decode::TextDecoder) for decoding the transmitted data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum EciDecoder {
+    Off,
+    On,
+}
+
+impl EciDecoder {
+    /// Creates a new [EciDecoder].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [EciDecoder].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => ECI_OFF,
+            Self::On => ECI_ON,
+        }
+    }
+}
+
+impl Default for EciDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for EciDecoder {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(ECI_OFF) => Ok(Self::Off),
+            v if v.contains(ECI_ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+/// Represents the `QR Code Page` serial command.
+///
+/// Sets the device code page used to interpret QR Code data without ECI designators.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QRCodePage {
+    code_page: u8,
+}
+
+impl QRCodePage {
+    /// Creates a new [QRCodePage].
+    pub const fn new() -> Self {
+        Self {
+            code_page: CODE_PAGE_DEFAULT,
+        }
+    }
+
+    /// Gets the ASCII serial command code for [QRCodePage].
+    pub fn command(&self) -> String {
+        format!("{QR_CODE_PAGE}{}", self.code_page)
+    }
+
+    /// Gets the device code page number.
+    pub const fn code_page(&self) -> u8 {
+        self.code_page
+    }
+
+    /// Attempts to create a [QRCodePage] from a device code page number.
+    pub const fn try_from_code_page(code_page: u8) -> Result<Self> {
+        if code_page != 0 && code_page <= MAX_CODE_PAGE {
+            Ok(Self { code_page })
+        } else {
+            Err(Error::out_of_range(
+                code_page as usize,
+                1,
+                MAX_CODE_PAGE as usize,
+            ))
+        }
+    }
+
+    /// Gets the [CharacterSet] for the device code page, if supported for host decoding.
+    pub const fn charset(&self) -> Result<CharacterSet> {
+        match self.code_page {
+            3 => Ok(CharacterSet::Windows1252),
+            4 => Ok(CharacterSet::Windows1251),
+            5 => Ok(CharacterSet::Windows1250),
+            9 => Ok(CharacterSet::Windows1256),
+            12 => Ok(CharacterSet::Latin1),
+            13 => Ok(CharacterSet::Latin2),
+            16 => Ok(CharacterSet::Cyrillic),
+            17 => Ok(CharacterSet::Arabic),
+            18 => Ok(CharacterSet::Greek),
+            19 => Ok(CharacterSet::Hebrew),
+            25 => Ok(CharacterSet::Latin9),
+            cp => Err(Error::InvalidValue(cp as usize)),
+        }
+    }
+}
+
+impl Default for QRCodePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for QRCodePage {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(QR_CODE_PAGE).ok_or(Error::InvalidVariant)?;
+        let pos = i + QR_CODE_PAGE.len();
+
+        val[pos..]
+            .parse::<u8>()
+            .map_err(|_| Error::invalid_data(QR_CODE_PAGE, &val[pos..], pos))
+            .and_then(Self::try_from_code_page)
+            .map_err(|err| err.with_tag(QR_CODE_PAGE))
+    }
+}
+
+serde_value!(QRCodePage, u8, Self::try_from_code_page, Self::code_page);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [EciDecoder::Off, EciDecoder::On]
+            .into_iter()
+            .zip([ECI_OFF, ECI_ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(EciDecoder::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        (1..=MAX_CODE_PAGE).for_each(|code_page| {
+            let exp_cmd = QRCodePage { code_page };
+            let exp_ascii_cmd = format!("{QR_CODE_PAGE}{code_page}");
+
+            assert_eq!(QRCodePage::try_from_code_page(code_page), Ok(exp_cmd));
+            assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+            assert_eq!(QRCodePage::try_from(exp_ascii_cmd.as_str()), Ok(exp_cmd));
+        });
+
+        assert_eq!(QRCodePage::new().charset(), Ok(CharacterSet::Windows1252));
+    }
+
+    #[test]
+    fn test_invalid() {
+        [0].into_iter()
+            .chain((MAX_CODE_PAGE + 1)..=u8::MAX)
+            .for_each(|code_page| {
+                assert_eq!(
+                    QRCodePage::try_from_code_page(code_page),
+                    Err(Error::out_of_range(
+                        code_page as usize,
+                        1,
+                        MAX_CODE_PAGE as usize
+                    ))
+                );
+            });
+
+        assert_eq!(
+            QRCodePage::try_from_code_page(2).and_then(|c| c.charset()),
+            Err(Error::InvalidValue(2))
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Types related to good read audio and visual feedback configuration.
+//!
+//! These settings apply to every read, unlike the [Beeper](super::image_snap::Beeper) and
+//! [LED](super::image_snap::LED) modifiers that only affect image snapshots.
+
+mod beep_count;
+mod beep_duration;
+mod beeper_pitch;
+mod beeper_volume;
+mod good_read_beep;
+mod good_read_led;
+mod power_up_beep;
+
+pub use beep_count::*;
+pub use beep_duration::*;
+pub use beeper_pitch::*;
+pub use beeper_volume::*;
+pub use good_read_beep::*;
+pub use good_read_led::*;
+pub use power_up_beep::*;
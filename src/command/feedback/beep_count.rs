@@ -0,0 +1,131 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+use crate::serde_value;
+
+use super::super::QueryCommand;
+
+const BEEP_COUNT: &str = "BEPRPT";
+/// Minimum number of beeps for [BeepCount].
+pub const MIN_BEEP_COUNT: u8 = 1;
+/// Maximum number of beeps for [BeepCount].
+pub const MAX_BEEP_COUNT: u8 = 9;
+
+/// Represents the `Number of Beeps - Good Read` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BeepCount {
+    count: u8,
+}
+
+impl BeepCount {
+    /// Creates a new [BeepCount].
+    pub const fn new() -> Self {
+        Self {
+            count: MIN_BEEP_COUNT,
+        }
+    }
+
+    /// Gets the ASCII serial command code for [BeepCount].
+    pub fn command(&self) -> String {
+        format!("{BEEP_COUNT}{}", self.count)
+    }
+
+    /// Gets the ASCII serial command code to query the [BeepCount] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{BEEP_COUNT}{}", query.command())
+    }
+
+    /// Attempts to convert a [`u8`] number of beeps into a [BeepCount].
+    ///
+    /// **NOTE**: `count` must be in the range [MIN_BEEP_COUNT]-[MAX_BEEP_COUNT].
+    pub const fn try_from_count(count: u8) -> Result<Self> {
+        if count >= MIN_BEEP_COUNT && count <= MAX_BEEP_COUNT {
+            Ok(Self { count })
+        } else {
+            Err(Error::out_of_range(
+                count as usize,
+                MIN_BEEP_COUNT as usize,
+                MAX_BEEP_COUNT as usize,
+            ))
+        }
+    }
+
+    /// Converts a [BeepCount] into a [`u8`] number of beeps.
+    pub const fn into_count(self) -> u8 {
+        self.count
+    }
+}
+
+impl Default for BeepCount {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for BeepCount {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(BEEP_COUNT).ok_or(Error::InvalidVariant)?;
+        let pos = i + BEEP_COUNT.len();
+
+        let count = val[pos..]
+            .parse::<u32>()
+            .map_err(|_| Error::invalid_data(BEEP_COUNT, &val[pos..], pos))?;
+
+        u8::try_from(count)
+            .map_err(|_| {
+                Error::out_of_range(
+                    count as usize,
+                    MIN_BEEP_COUNT as usize,
+                    MAX_BEEP_COUNT as usize,
+                )
+            })
+            .and_then(Self::try_from_count)
+            .map_err(|err| err.with_tag(BEEP_COUNT))
+    }
+}
+
+serde_value!(BeepCount, u8, Self::try_from_count, |c| c.into_count());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        (MIN_BEEP_COUNT..=MAX_BEEP_COUNT).for_each(|count| {
+            let exp_cmd = BeepCount { count };
+            let exp_ascii_cmd = format!("{BEEP_COUNT}{count}");
+
+            assert_eq!(BeepCount::try_from_count(count), Ok(exp_cmd));
+            assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+            assert_eq!(BeepCount::try_from(exp_ascii_cmd.as_str()), Ok(exp_cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        let range =
+            |count| Error::out_of_range(count, MIN_BEEP_COUNT as usize, MAX_BEEP_COUNT as usize);
+
+        assert_eq!(BeepCount::try_from_count(0), Err(range(0)));
+        assert_eq!(BeepCount::try_from_count(10), Err(range(10)));
+        assert_eq!(
+            BeepCount::try_from("BEPRPT0"),
+            Err(range(0).with_tag("BEPRPT"))
+        );
+        assert_eq!(
+            BeepCount::try_from("BEPRPT12"),
+            Err(range(12).with_tag("BEPRPT"))
+        );
+        assert_eq!(
+            BeepCount::try_from("BEPRPT300"),
+            Err(range(300).with_tag("BEPRPT"))
+        );
+        assert_eq!(
+            BeepCount::try_from("BEPRPT1x"),
+            Err(Error::invalid_data("BEPRPT", "1x", 6))
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::super::QueryCommand;
+
+const BEEP_DURATION: &str = "BEPBIP";
+const NORMAL: &str = "BEPBIP0";
+const SHORT: &str = "BEPBIP1";
+
+/// Represents the `Beeper Duration - Good Read` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum BeepDuration {
+    /// Normal length beep.
+    Normal,
+    /// Short beep (for sound-sensitive environments).
+    Short,
+}
+
+impl BeepDuration {
+    /// Creates a new [BeepDuration].
+    pub const fn new() -> Self {
+        Self::Normal
+    }
+
+    /// Gets the ASCII serial command code for [BeepDuration].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Normal => NORMAL,
+            Self::Short => SHORT,
+        }
+    }
+
+    /// Gets the ASCII serial command code to query the [BeepDuration] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{BEEP_DURATION}{}", query.command())
+    }
+}
+
+impl Default for BeepDuration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for BeepDuration {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(NORMAL) => Ok(Self::Normal),
+            v if v.contains(SHORT) => Ok(Self::Short),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [BeepDuration::Normal, BeepDuration::Short]
+            .into_iter()
+            .zip([NORMAL, SHORT])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(BeepDuration::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        assert_eq!(BeepDuration::query(QueryCommand::CurrentValue), "BEPBIP?");
+    }
+}
@@ -0,0 +1,124 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::super::QueryCommand;
+
+const BEEPER_PITCH: &str = "BEPFQ1";
+const LOW_HZ: u16 = 1600;
+const MEDIUM_HZ: u16 = 3250;
+const HIGH_HZ: u16 = 4200;
+
+/// Represents the `Beeper Pitch - Good Read` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum BeeperPitch {
+    /// Low pitch (1600 Hz).
+    Low,
+    /// Medium pitch (3250 Hz).
+    Medium,
+    /// High pitch (4200 Hz).
+    High,
+}
+
+impl BeeperPitch {
+    /// Creates a new [BeeperPitch].
+    pub const fn new() -> Self {
+        Self::Medium
+    }
+
+    /// Gets the ASCII serial command code for [BeeperPitch].
+    pub fn command(&self) -> String {
+        format!("{BEEPER_PITCH}{}", self.hz())
+    }
+
+    /// Gets the ASCII serial command code to query the [BeeperPitch] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{BEEPER_PITCH}{}", query.command())
+    }
+
+    /// Gets the beeper frequency in Hz.
+    pub const fn hz(&self) -> u16 {
+        match self {
+            Self::Low => LOW_HZ,
+            Self::Medium => MEDIUM_HZ,
+            Self::High => HIGH_HZ,
+        }
+    }
+
+    /// Attempts to convert a frequency in Hz into a [BeeperPitch].
+    pub const fn try_from_hz(hz: u16) -> Result<Self> {
+        match hz {
+            LOW_HZ => Ok(Self::Low),
+            MEDIUM_HZ => Ok(Self::Medium),
+            HIGH_HZ => Ok(Self::High),
+            _ if hz < LOW_HZ || hz > HIGH_HZ => Err(Error::out_of_range(
+                hz as usize,
+                LOW_HZ as usize,
+                HIGH_HZ as usize,
+            )),
+            _ => Err(Error::InvalidValue(hz as usize)),
+        }
+    }
+}
+
+impl Default for BeeperPitch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for BeeperPitch {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(BEEPER_PITCH).ok_or(Error::InvalidVariant)?;
+        let pos = i + BEEPER_PITCH.len();
+        let rem = &val[pos..];
+        let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
+
+        rem[..end]
+            .parse::<u16>()
+            .map_err(|_| Error::invalid_data(BEEPER_PITCH, rem, pos))
+            .and_then(Self::try_from_hz)
+            .map_err(|err| err.with_tag(BEEPER_PITCH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [BeeperPitch::Low, BeeperPitch::Medium, BeeperPitch::High]
+            .into_iter()
+            .zip(["BEPFQ11600", "BEPFQ13250", "BEPFQ14200"])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(BeeperPitch::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        assert_eq!(BeeperPitch::query(QueryCommand::CurrentValue), "BEPFQ1?");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            BeeperPitch::try_from("BEPFQ11000"),
+            Err(Error::out_of_range(1000, 1600, 4200).with_tag("BEPFQ1"))
+        );
+        assert_eq!(
+            BeeperPitch::try_from("BEPFQ12000"),
+            Err(Error::InvalidValue(2000).with_tag("BEPFQ1"))
+        );
+        assert_eq!(
+            BeeperPitch::try_from("BEPFQ1"),
+            Err(Error::invalid_data("BEPFQ1", "", 6))
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::super::QueryCommand;
+
+const BEEPER_VOLUME: &str = "BEPLVL";
+const OFF: &str = "BEPLVL0";
+const LOW: &str = "BEPLVL1";
+const MEDIUM: &str = "BEPLVL2";
+const HIGH: &str = "BEPLVL3";
+
+/// Represents the `Beeper Volume` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum BeeperVolume {
+    /// Beeper off.
+    Off,
+    /// Low volume.
+    Low,
+    /// Medium volume.
+    Medium,
+    /// High volume.
+    High,
+}
+
+impl BeeperVolume {
+    /// Creates a new [BeeperVolume].
+    pub const fn new() -> Self {
+        Self::High
+    }
+
+    /// Gets the ASCII serial command code for [BeeperVolume].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::Low => LOW,
+            Self::Medium => MEDIUM,
+            Self::High => HIGH,
+        }
+    }
+
+    /// Gets the ASCII serial command code to query the [BeeperVolume] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{BEEPER_VOLUME}{}", query.command())
+    }
+}
+
+impl Default for BeeperVolume {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for BeeperVolume {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(LOW) => Ok(Self::Low),
+            v if v.contains(MEDIUM) => Ok(Self::Medium),
+            v if v.contains(HIGH) => Ok(Self::High),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            BeeperVolume::Off,
+            BeeperVolume::Low,
+            BeeperVolume::Medium,
+            BeeperVolume::High,
+        ]
+        .into_iter()
+        .zip([OFF, LOW, MEDIUM, HIGH])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(BeeperVolume::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+
+        assert_eq!(BeeperVolume::query(QueryCommand::DefaultValue), "BEPLVL^");
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::super::QueryCommand;
+
+const GOOD_READ_BEEP: &str = "BEPBEP";
+const OFF: &str = "BEPBEP0";
+const ON: &str = "BEPBEP1";
+
+/// Represents the `Beeper - Good Read` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum GoodReadBeep {
+    /// No beep on a good read.
+    Off,
+    /// Beep on a good read.
+    On,
+}
+
+impl GoodReadBeep {
+    /// Creates a new [GoodReadBeep].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [GoodReadBeep].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+
+    /// Gets the ASCII serial command code to query the [GoodReadBeep] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{GOOD_READ_BEEP}{}", query.command())
+    }
+}
+
+impl Default for GoodReadBeep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for GoodReadBeep {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [GoodReadBeep::Off, GoodReadBeep::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(GoodReadBeep::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        assert_eq!(GoodReadBeep::query(QueryCommand::CurrentValue), "BEPBEP?");
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::super::QueryCommand;
+
+const GOOD_READ_LED: &str = "BEPLED";
+const OFF: &str = "BEPLED0";
+const ON: &str = "BEPLED1";
+
+/// Represents the `LED - Good Read` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum GoodReadLed {
+    /// Good read LED does not light.
+    Off,
+    /// Good read LED lights on a good read.
+    On,
+}
+
+impl GoodReadLed {
+    /// Creates a new [GoodReadLed].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [GoodReadLed].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+
+    /// Gets the ASCII serial command code to query the [GoodReadLed] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{GOOD_READ_LED}{}", query.command())
+    }
+}
+
+impl Default for GoodReadLed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for GoodReadLed {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [GoodReadLed::Off, GoodReadLed::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(GoodReadLed::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        assert_eq!(GoodReadLed::query(QueryCommand::CurrentValue), "BEPLED?");
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::super::QueryCommand;
+
+const POWER_UP_BEEP: &str = "BEPPWR";
+const OFF: &str = "BEPPWR0";
+const ON: &str = "BEPPWR1";
+
+/// Represents the `Power Up Beep` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PowerUpBeep {
+    /// No beep on power up.
+    Off,
+    /// Beep on power up.
+    On,
+}
+
+impl PowerUpBeep {
+    /// Creates a new [PowerUpBeep].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [PowerUpBeep].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+
+    /// Gets the ASCII serial command code to query the [PowerUpBeep] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{POWER_UP_BEEP}{}", query.command())
+    }
+}
+
+impl Default for PowerUpBeep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for PowerUpBeep {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [PowerUpBeep::Off, PowerUpBeep::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(PowerUpBeep::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        assert_eq!(PowerUpBeep::query(QueryCommand::CurrentValue), "BEPPWR?");
+    }
+}
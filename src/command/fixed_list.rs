@@ -0,0 +1,199 @@
+use core::{fmt, ops::Deref};
+
+use crate::result::{Error, Result};
+
+/// Represents a list of up to `N` items, stored inline so commands holding lists stay [Copy].
+#[derive(Clone, Copy)]
+pub struct FixedList<T: Copy, const N: usize> {
+    items: [T; N],
+    len: u8,
+}
+
+impl<T: Copy, const N: usize> FixedList<T, N> {
+    /// Gets the maximum number of items in the [FixedList].
+    pub const CAPACITY: usize = {
+        assert!(N <= u8::MAX as usize);
+        N
+    };
+
+    /// Creates a new, empty [FixedList].
+    pub fn new() -> Self
+    where
+        T: Default,
+    {
+        Self::with_fill(T::default())
+    }
+
+    // Creates an empty list, using `fill` for the unused slots.
+    pub(crate) const fn with_fill(fill: T) -> Self {
+        let _ = Self::CAPACITY;
+
+        Self {
+            items: [fill; N],
+            len: 0,
+        }
+    }
+
+    /// Attempts to create a [FixedList] from a slice, using `fill` for the unused slots.
+    pub(crate) fn try_with_fill(fill: T, val: &[T]) -> Result<Self> {
+        let mut list = Self::with_fill(fill);
+        list.try_extend(val)?;
+        Ok(list)
+    }
+
+    /// Gets the items of the [FixedList].
+    pub fn as_slice(&self) -> &[T] {
+        &self.items[..self.len as usize]
+    }
+
+    /// Attempts to append an item to the [FixedList].
+    ///
+    /// Returns an [OutOfRange](Error::OutOfRange) error if the [FixedList] is full.
+    pub fn try_push(&mut self, val: T) -> Result<()> {
+        let len = self.len as usize;
+
+        if len < N {
+            self.items[len] = val;
+            self.len += 1;
+            Ok(())
+        } else {
+            Err(Error::OutOfRange {
+                value: len + 1,
+                min: 0,
+                max: N,
+            })
+        }
+    }
+
+    /// Attempts to append all items of a slice to the [FixedList].
+    pub fn try_extend(&mut self, val: &[T]) -> Result<()> {
+        let len = self.len as usize + val.len();
+
+        if len <= N {
+            val.iter().try_for_each(|&v| self.try_push(v))
+        } else {
+            Err(Error::OutOfRange {
+                value: len,
+                min: 0,
+                max: N,
+            })
+        }
+    }
+
+    /// Removes all items from the [FixedList].
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Default for FixedList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const N: usize> Deref for FixedList<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> PartialEq for FixedList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for FixedList<T, N> {}
+
+impl<T: Copy + fmt::Debug, const N: usize> fmt::Debug for FixedList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: Copy + Default, const N: usize> TryFrom<&[T]> for FixedList<T, N> {
+    type Error = Error;
+
+    fn try_from(val: &[T]) -> Result<Self> {
+        Self::try_with_fill(T::default(), val)
+    }
+}
+
+impl<T: Copy + Default, const M: usize, const N: usize> TryFrom<&[T; M]> for FixedList<T, N> {
+    type Error = Error;
+
+    fn try_from(val: &[T; M]) -> Result<Self> {
+        Self::try_from(val.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy + serde::Serialize, const N: usize> serde::Serialize for FixedList<T, N> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize> serde::Deserialize<'de> for FixedList<T, N>
+where
+    T: Copy + Default + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let items = <alloc::vec::Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(items.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let mut list = FixedList::<u8, 3>::try_from(b"ab").unwrap();
+
+        assert_eq!(list.as_slice(), b"ab");
+        assert_eq!(list.try_push(b'c'), Ok(()));
+        assert_eq!(&*list, b"abc");
+
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list, FixedList::new());
+
+        // stale items past the length are ignored
+        let mut other = FixedList::<u8, 3>::try_from(b"xy").unwrap();
+        other.clear();
+        assert_eq!(list, other);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut list = FixedList::<u8, 2>::try_from(b"ab").unwrap();
+
+        assert_eq!(
+            list.try_push(b'c'),
+            Err(Error::OutOfRange {
+                value: 3,
+                min: 0,
+                max: 2
+            })
+        );
+        assert_eq!(
+            FixedList::<u8, 2>::try_from(b"abcd"),
+            Err(Error::OutOfRange {
+                value: 4,
+                min: 0,
+                max: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Types for `Host ACK` handshaking of transmitted barcode data.
+
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+use crate::serde_value;
+
+use super::QueryCommand;
+
+const HOST_ACK_OFF: &str = "HSTACK0";
+const HOST_ACK_ON: &str = "HSTACK1";
+const HOST_ACK_TIMEOUT: &str = "HSTDLY";
+/// Maximum timeout (in milliseconds) for [HostAckTimeout].
+pub const MAX_HOST_ACK_TIMEOUT: u32 = 30_000;
+
+/// Represents the `Host ACK Selection` serial command.
+///
+/// When enabled, the host must reply with `ACK` to each transmitted barcode within the
+/// [HostAckTimeout]. On a `NAK` the device retransmits the data, and on a timeout the device
+/// signals an error beep and discards the read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum HostAck {
+    /// Barcodes are transmitted without waiting for the host.
+    Off,
+    /// The device waits for the host `ACK` after each barcode.
+    On,
+}
+
+impl HostAck {
+    /// Creates a new [HostAck].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [HostAck].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => HOST_ACK_OFF,
+            Self::On => HOST_ACK_ON,
+        }
+    }
+}
+
+impl Default for HostAck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for HostAck {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(HOST_ACK_OFF) => Ok(Self::Off),
+            v if v.contains(HOST_ACK_ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+/// Represents the `Host ACK Timeout` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HostAckTimeout {
+    ms: u32,
+}
+
+impl HostAckTimeout {
+    /// Creates a new [HostAckTimeout].
+    pub const fn new() -> Self {
+        Self { ms: 5_000 }
+    }
+
+    /// Gets the ASCII serial command code for [HostAckTimeout].
+    pub fn command(&self) -> String {
+        format!("{HOST_ACK_TIMEOUT}{}", self.ms)
+    }
+
+    /// Gets the ASCII serial command code to query the [HostAckTimeout] setting.
+    pub fn query(query: QueryCommand) -> String {
+        format!("{HOST_ACK_TIMEOUT}{}", query.command())
+    }
+
+    /// Attempts to convert a [`u32`] number of timeout milliseconds into a [HostAckTimeout].
+    ///
+    /// **NOTE**: `ms` must be below [MAX_HOST_ACK_TIMEOUT] number of milliseconds.
+    pub const fn try_from_ms(ms: u32) -> Result<Self> {
+        if ms <= MAX_HOST_ACK_TIMEOUT {
+            Ok(Self { ms })
+        } else {
+            Err(Error::out_of_range(
+                ms as usize,
+                0,
+                MAX_HOST_ACK_TIMEOUT as usize,
+            ))
+        }
+    }
+
+    /// Converts a [HostAckTimeout] into a [`u32`] number of milliseconds.
+    pub const fn into_ms(self) -> u32 {
+        self.ms
+    }
+}
+
+impl Default for HostAckTimeout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for HostAckTimeout {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(HOST_ACK_TIMEOUT).ok_or(Error::InvalidVariant)?;
+        let pos = i + HOST_ACK_TIMEOUT.len();
+
+        val[pos..]
+            .parse::<u32>()
+            .map_err(|_| Error::invalid_data(HOST_ACK_TIMEOUT, &val[pos..], pos))
+            .and_then(Self::try_from_ms)
+            .map_err(|err| err.with_tag(HOST_ACK_TIMEOUT))
+    }
+}
+
+serde_value!(HostAckTimeout, u32, Self::try_from_ms, |c| c.into_ms());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [HostAck::Off, HostAck::On]
+            .into_iter()
+            .zip([HOST_ACK_OFF, HOST_ACK_ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(HostAck::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+
+        (0..=MAX_HOST_ACK_TIMEOUT).step_by(100).for_each(|ms| {
+            let exp_cmd = HostAckTimeout { ms };
+            let exp_ascii_cmd = format!("{HOST_ACK_TIMEOUT}{ms}");
+
+            assert_eq!(HostAckTimeout::try_from_ms(ms), Ok(exp_cmd));
+            assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+            assert_eq!(
+                HostAckTimeout::try_from(exp_ascii_cmd.as_str()),
+                Ok(exp_cmd)
+            );
+        });
+
+        assert_eq!(HostAckTimeout::query(QueryCommand::CurrentValue), "HSTDLY?");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            HostAckTimeout::try_from_ms(MAX_HOST_ACK_TIMEOUT + 1),
+            Err(Error::out_of_range(
+                MAX_HOST_ACK_TIMEOUT as usize + 1,
+                0,
+                MAX_HOST_ACK_TIMEOUT as usize
+            ))
+        );
+        assert_eq!(
+            HostAckTimeout::try_from("HSTDLY30001"),
+            Err(Error::out_of_range(30_001, 0, MAX_HOST_ACK_TIMEOUT as usize).with_tag("HSTDLY"))
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::result::{Error, Result};
+
+const LIGHTS_OFF: &str = "SCNLED0";
+const LIGHTS_ON: &str = "SCNLED1";
+
+/// Represents the `Illumination Lights` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Illumination {
+    /// Illumination LEDs off, only the aimer is used (e.g. for phone screens or reflective labels).
+    Off,
+    /// Illumination LEDs and aimer on.
+    On,
+}
+
+impl Illumination {
+    /// Creates a new [Illumination].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [Illumination].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => LIGHTS_OFF,
+            Self::On => LIGHTS_ON,
+        }
+    }
+}
+
+impl Default for Illumination {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for Illumination {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(LIGHTS_OFF) => Ok(Self::Off),
+            v if v.contains(LIGHTS_ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [Illumination::Off, Illumination::On]
+            .into_iter()
+            .zip([LIGHTS_OFF, LIGHTS_ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(Illumination::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -9,7 +9,8 @@ mod histogram_ship;
 mod histogram_stretch;
 mod image_rotate;
 mod infinity_filter;
-mod invert_image;
+mod invert_image_x;
+mod invert_image_y;
 mod jpeg_image_quality;
 mod noise_reduction;
 mod pixel_depth;
@@ -25,7 +26,8 @@ pub use histogram_ship::*;
 pub use histogram_stretch::*;
 pub use image_rotate::*;
 pub use infinity_filter::*;
-pub use invert_image::*;
+pub use invert_image_x::*;
+pub use invert_image_y::*;
 pub use jpeg_image_quality::*;
 pub use noise_reduction::*;
 pub use pixel_depth::*;
@@ -35,21 +37,22 @@ pub use protocol::*;
 modifier_command! {
     /// Configure all barcode `Image Ship` encodings.
     ImageShip: "IMGSHP" {
-        infinity_filter: InfinityFilter,
-        compensation: Compensation,
-        pixel_depth: PixelDepth,
-        edge_sharpen: EdgeSharpen,
-        histogram_stretch: HistogramStretch,
-        invert_image: InvertImage,
-        noise_reduction: NoiseReduction,
-        image_rotate: ImageRotate,
-        jpeg_image_quality: JpegImageQuality,
-        gamma_correction: GammaCorrection,
-        protocol: Protocol,
-        pixel_ship: PixelShip,
-        document_filter: DocumentFilter,
-        blur_image: BlurImage,
-        histogram_ship: HistogramShip,
+        infinity_filter: InfinityFilter = "A",
+        compensation: Compensation = "C",
+        pixel_depth: PixelDepth = "D",
+        edge_sharpen: EdgeSharpen = "E",
+        histogram_stretch: HistogramStretch = "H",
+        invert_image_x: InvertImageX = "ix",
+        invert_image_y: InvertImageY = "iy",
+        noise_reduction: NoiseReduction = "if",
+        image_rotate: ImageRotate = "ir",
+        jpeg_image_quality: JpegImageQuality = "J",
+        gamma_correction: GammaCorrection = "K",
+        protocol: Protocol = "P",
+        pixel_ship: PixelShip = "S",
+        document_filter: DocumentFilter = "U",
+        blur_image: BlurImage = "V",
+        histogram_ship: HistogramShip = "W",
     }
 }
 
@@ -61,7 +64,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -82,7 +86,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -103,7 +108,8 @@ modifier_field! {
         compensation,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -124,7 +130,8 @@ modifier_field! {
         compensation,
         pixel_depth,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -145,7 +152,8 @@ modifier_field! {
         compensation,
         pixel_depth,
         edge_sharpen,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -160,13 +168,36 @@ modifier_field! {
 
 modifier_field! {
     ImageShip,
-    invert_image: InvertImage,
+    invert_image_x: InvertImageX,
     [
         infinity_filter,
         compensation,
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
+        invert_image_y,
+        noise_reduction,
+        image_rotate,
+        jpeg_image_quality,
+        gamma_correction,
+        protocol,
+        pixel_ship,
+        document_filter,
+        blur_image,
+        histogram_ship,
+    ],
+}
+
+modifier_field! {
+    ImageShip,
+    invert_image_y: InvertImageY,
+    [
+        infinity_filter,
+        compensation,
+        pixel_depth,
+        edge_sharpen,
+        histogram_stretch,
+        invert_image_x,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -188,7 +219,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         image_rotate,
         jpeg_image_quality,
         gamma_correction,
@@ -209,7 +241,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         jpeg_image_quality,
         gamma_correction,
@@ -230,7 +263,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         gamma_correction,
@@ -251,7 +285,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -272,7 +307,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -293,7 +329,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -314,7 +351,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -335,7 +373,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -356,7 +395,8 @@ modifier_field! {
         pixel_depth,
         edge_sharpen,
         histogram_stretch,
-        invert_image,
+        invert_image_x,
+        invert_image_y,
         noise_reduction,
         image_rotate,
         jpeg_image_quality,
@@ -371,6 +411,7 @@ modifier_field! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::result::Error;
 
     macro_rules! test_image_ship_field {
         ($img:ident, $field:ident, $field_val:expr) => {
@@ -392,7 +433,8 @@ mod tests {
         let exp_compensation = Compensation::new();
         let exp_pixel_depth = PixelDepth::new();
         let exp_histogram_stretch = HistogramStretch::new();
-        let exp_invert_image = InvertImage::new();
+        let exp_invert_image_x = InvertImageX::On;
+        let exp_invert_image_y = InvertImageY::On;
         let exp_noise_reduction = NoiseReduction::new();
         let exp_image_rotate = ImageRotate::new();
         let exp_jpeg_image_quality = JpegImageQuality::new();
@@ -405,8 +447,8 @@ mod tests {
         let prefix = ImageShip::prefix();
 
         [
-            "", "0A", "0C", "8D", "0H", "1ix", "0if", "0ir", "50J", "0K", "0P", "1S", "0U", "0V",
-            "0W",
+            "", "0A", "0C", "8D", "0H", "1ix", "1iy", "0if", "0ir", "50J", "0K", "0P", "1S", "0U",
+            "0V", "0W",
         ]
         .into_iter()
         .map(|s| format!("{prefix}{s}"))
@@ -416,7 +458,8 @@ mod tests {
             ImageShip::new().with_compensation(exp_compensation),
             ImageShip::new().with_pixel_depth(exp_pixel_depth),
             ImageShip::new().with_histogram_stretch(exp_histogram_stretch),
-            ImageShip::new().with_invert_image(exp_invert_image),
+            ImageShip::new().with_invert_image_x(exp_invert_image_x),
+            ImageShip::new().with_invert_image_y(exp_invert_image_y),
             ImageShip::new().with_noise_reduction(exp_noise_reduction),
             ImageShip::new().with_image_rotate(exp_image_rotate),
             ImageShip::new().with_jpeg_image_quality(exp_jpeg_image_quality),
@@ -429,6 +472,10 @@ mod tests {
         ])
         .for_each(|(img_str, exp_img_ship)| {
             assert_eq!(ImageShip::try_from(img_str.as_str()), Ok(exp_img_ship));
+            assert_eq!(
+                ImageShip::try_from_strict(img_str.as_str()),
+                Ok(exp_img_ship)
+            );
             assert_eq!(exp_img_ship.command(), img_str);
         });
 
@@ -438,7 +485,8 @@ mod tests {
         test_image_ship_field!(img, compensation, exp_compensation);
         test_image_ship_field!(img, pixel_depth, exp_pixel_depth);
         test_image_ship_field!(img, histogram_stretch, exp_histogram_stretch);
-        test_image_ship_field!(img, invert_image, exp_invert_image);
+        test_image_ship_field!(img, invert_image_x, exp_invert_image_x);
+        test_image_ship_field!(img, invert_image_y, exp_invert_image_y);
         test_image_ship_field!(img, noise_reduction, exp_noise_reduction);
         test_image_ship_field!(img, image_rotate, exp_image_rotate);
         test_image_ship_field!(img, jpeg_image_quality, exp_jpeg_image_quality);
@@ -448,5 +496,109 @@ mod tests {
         test_image_ship_field!(img, document_filter, exp_document_filter);
         test_image_ship_field!(img, blur_image, exp_blur_image);
         test_image_ship_field!(img, histogram_ship, exp_histogram_ship);
+
+        // captured commands re-encode byte-for-byte, with the last modifier of a field in effect
+        let captured = "IMGSHP1ix50J8D0ix75J";
+        let seq = ImageShipSequence::try_from(captured).unwrap();
+
+        assert_eq!(seq.command(), captured);
+        assert_eq!(seq.modifiers().len(), 5);
+        assert_eq!(
+            seq.modifiers()[2],
+            ImageShipModifier::PixelDepth(PixelDepth::Bit8)
+        );
+        assert_eq!(
+            seq.to_command(),
+            ImageShip::new()
+                .with_invert_image_x(InvertImageX::Off)
+                .with_jpeg_image_quality(JpegImageQuality::try_from_quality(75).unwrap())
+                .with_pixel_depth(PixelDepth::Bit8)
+        );
+        assert_eq!(
+            ImageShipSequence::new()
+                .with_modifier(InvertImageX::On)
+                .and_then(|s| s.with_modifier(JpegImageQuality::try_from_quality(50).unwrap()))
+                .and_then(|s| s.with_modifier(PixelDepth::Bit8))
+                .and_then(|s| s.with_modifier(InvertImageX::Off))
+                .and_then(|s| s.with_modifier(JpegImageQuality::try_from_quality(75).unwrap())),
+            Ok(seq)
+        );
+
+        // zero-padded numbers are kept verbatim
+        let padded = "IMGSHP050J0008D";
+        let seq = ImageShipSequence::try_from(padded).unwrap();
+
+        assert_eq!(seq.command(), padded);
+        assert_eq!(
+            seq.to_command(),
+            ImageShip::new()
+                .with_jpeg_image_quality(JpegImageQuality::try_from_quality(50).unwrap())
+                .with_pixel_depth(PixelDepth::Bit8)
+        );
+        assert_ne!(seq, ImageShipSequence::from(&seq.to_command()));
+
+        let img = ImageShip::new()
+            .with_pixel_depth(PixelDepth::Bit8)
+            .with_infinity_filter(InfinityFilter::On);
+        assert_eq!(ImageShipSequence::from(&img).command(), img.command());
+        assert_eq!(ImageShip::from(&ImageShipSequence::from(&img)), img);
+
+        // both axes can be inverted on the same image
+        let img = ImageShip::new()
+            .with_invert_image_x(InvertImageX::On)
+            .with_invert_image_y(InvertImageY::On);
+        assert_eq!(img.command(), "IMGSHP1ix1iy");
+        assert_eq!(ImageShip::try_from_strict("IMGSHP1ix1iy"), Ok(img));
+        assert_eq!(
+            ImageShipSequence::try_from("IMGSHP1ix1iy").map(|s| s.to_command()),
+            Ok(img)
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            ImageShip::try_from_strict("IMGSNP"),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            ImageShip::try_from_strict("IMGSHP50J5000K"),
+            Err(Error::out_of_range(5000, 0, 1000)
+                .with_token("5000K", 9)
+                .with_tag("IMGSHP"))
+        );
+        assert_eq!(
+            ImageShip::try_from_strict("IMGSHP50JxK"),
+            Err(Error::invalid_data("IMGSHP", "xK", 9))
+        );
+        assert_eq!(
+            ImageShip::try_from("IMGSHP50JxK"),
+            Err(Error::invalid_data("IMGSHP", "K", 10))
+        );
+        // `0P` is a protocol for image ship, and an imaging style for image snap
+        assert_eq!(
+            ImageShip::try_from_strict("IMGSHP0P1P"),
+            Err(Error::invalid_data("IMGSHP", "1P", 8))
+        );
+        assert_eq!(
+            ImageShip::try_from_strict("IMGSHP1ix0ix"),
+            Err(Error::invalid_data("IMGSHP", "0ix", 9))
+        );
+        assert_eq!(
+            ImageShipSequence::try_from("IMGSHP1ix9Z"),
+            Err(Error::invalid_data("IMGSHP", "9Z", 9))
+        );
+
+        let padded = format!("IMGSHP{}1ix", "0".repeat(256));
+        assert_eq!(
+            ImageShipSequence::try_from(padded.as_str()),
+            Err(Error::invalid_data("IMGSHP", &padded[6..], 6))
+        );
+
+        // lenient parsing ignores unknown modifiers
+        assert_eq!(
+            ImageShip::try_from("IMGSHP1ix9Z"),
+            Ok(ImageShip::new().with_invert_image_x(InvertImageX::On))
+        );
     }
 }
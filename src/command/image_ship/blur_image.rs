@@ -5,6 +5,11 @@ const BLUR_ON: &str = "1V";
 
 /// Sets the image ship blur image.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum BlurImage {
     /// Blur image off.
     Off,
@@ -5,6 +5,11 @@ const COMPENSATION_ENABLED: &str = "1C";
 
 /// Sets the image ship compensation.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Compensation {
     /// Compensation disabled.
     Disabled,
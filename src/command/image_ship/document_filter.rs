@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const DOCUMENT_SUFFIX: &str = "U";
 const DOCUMENT_DEFAULT: u8 = 0;
@@ -59,12 +60,25 @@ impl TryFrom<&str> for DocumentFilter {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + DOCUMENT_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, u8::MAX as usize))
+            })
             .map(Self::from_threshold)
-            .map_err(|_| Error::InvalidVariant)
     }
 }
 
+serde_value!(
+    DocumentFilter,
+    u8,
+    |v| Ok(Self::from_threshold(v)),
+    Self::threshold
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const EDGE_SUFFIX: &str = "E";
 const EDGE_DEFAULT: u8 = 0;
@@ -25,11 +26,16 @@ impl EdgeSharpen {
         self.strength
     }
 
+    /// Gets the maximum [EdgeSharpen] strength setting.
+    pub const fn max_strength() -> u8 {
+        EDGE_MAX
+    }
+
     /// Creates a [EdgeSharpen] from a strength parameter.
     pub const fn try_from_strength(strength: u8) -> Result<Self> {
         match strength {
             s if s <= EDGE_MAX => Ok(Self { strength }),
-            _ => Err(Error::InvalidValue(strength as usize)),
+            _ => Err(Error::out_of_range(strength as usize, 0, EDGE_MAX as usize)),
         }
     }
 
@@ -65,12 +71,20 @@ impl TryFrom<&str> for EdgeSharpen {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
-            .map_err(|_| Error::InvalidVariant)
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + EDGE_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, EDGE_MAX as usize))
+            })
             .and_then(Self::try_from_strength)
     }
 }
 
+serde_value!(EdgeSharpen, u8, Self::try_from_strength, Self::strength);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,10 +102,15 @@ mod tests {
     #[test]
     fn test_invalid() {
         ((EDGE_MAX + 1)..=u8::MAX).for_each(|strength| {
-            let err = Error::InvalidValue(strength as usize);
+            let err = Error::out_of_range(strength as usize, 0, EDGE_MAX as usize);
 
-            assert_eq!(EdgeSharpen::try_from_strength(strength), Err(err));
+            assert_eq!(EdgeSharpen::try_from_strength(strength), Err(err.clone()));
             assert_eq!(EdgeSharpen::try_from(strength), Err(err));
         });
+
+        assert_eq!(
+            EdgeSharpen::try_from("300E"),
+            Err(Error::out_of_range(300, 0, EDGE_MAX as usize))
+        );
     }
 }
@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const GAMMA_SUFFIX: &str = "K";
 const GAMMA_DEFAULT: u16 = 0;
@@ -25,11 +26,16 @@ impl GammaCorrection {
         self.factor
     }
 
+    /// Gets the maximum [GammaCorrection] factor setting.
+    pub const fn max_factor() -> u16 {
+        GAMMA_MAX
+    }
+
     /// Creates a [GammaCorrection] from a factor parameter.
     pub const fn try_from_factor(factor: u16) -> Result<Self> {
         match factor {
             s if s <= GAMMA_MAX => Ok(Self { factor }),
-            _ => Err(Error::InvalidValue(factor as usize)),
+            _ => Err(Error::out_of_range(factor as usize, 0, GAMMA_MAX as usize)),
         }
     }
 
@@ -65,12 +71,20 @@ impl TryFrom<&str> for GammaCorrection {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u16>()
-            .map_err(|_| Error::InvalidVariant)
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + GAMMA_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u16::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, GAMMA_MAX as usize))
+            })
             .and_then(Self::try_from_factor)
     }
 }
 
+serde_value!(GammaCorrection, u16, Self::try_from_factor, Self::factor);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,10 +102,15 @@ mod tests {
     #[test]
     fn test_invalid() {
         ((GAMMA_MAX + 1)..=u16::MAX).for_each(|factor| {
-            let err = Error::InvalidValue(factor as usize);
+            let err = Error::out_of_range(factor as usize, 0, GAMMA_MAX as usize);
 
-            assert_eq!(GammaCorrection::try_from_factor(factor), Err(err));
+            assert_eq!(GammaCorrection::try_from_factor(factor), Err(err.clone()));
             assert_eq!(GammaCorrection::try_from(factor), Err(err));
         });
+
+        assert_eq!(
+            GammaCorrection::try_from("70000K"),
+            Err(Error::out_of_range(70000, 0, GAMMA_MAX as usize))
+        );
     }
 }
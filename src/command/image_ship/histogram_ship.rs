@@ -5,6 +5,11 @@ const HISTO_SHIP: &str = "1W";
 
 /// Sets the image ship histogram ship.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum HistogramShip {
     /// Histogram don't ship.
     Off,
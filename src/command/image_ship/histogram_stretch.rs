@@ -5,6 +5,11 @@ const HISTO_ON: &str = "1H";
 
 /// Sets the image ship histogram stretch.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum HistogramStretch {
     /// HistogramStretch disabled.
     Off,
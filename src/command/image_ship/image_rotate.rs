@@ -7,6 +7,11 @@ const ROTATE_270: &str = "3ir";
 
 /// Sets the image ship image rotate.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum ImageRotate {
     /// Rotate 0 degrees (image snapped).
     Degrees0,
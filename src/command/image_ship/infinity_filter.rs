@@ -5,6 +5,11 @@ const FILTER_ON: &str = "1A";
 
 /// Sets the image ship infinity filter.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum InfinityFilter {
     /// Infinity filter off.
     Off,
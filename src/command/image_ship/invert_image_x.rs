@@ -0,0 +1,69 @@
+use crate::result::{Error, Result};
+
+const INVERT_OFF: &str = "0ix";
+const INVERT_ON: &str = "1ix";
+
+/// Sets the image ship image inversion around the X-axis.
+///
+/// Set independently of [InvertImageY](super::InvertImageY), so both axes can be inverted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum InvertImageX {
+    /// Image not inverted around the X-axis.
+    Off,
+    /// Image inverted around the X-axis.
+    On,
+}
+
+impl InvertImageX {
+    /// Creates a new [InvertImageX].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [InvertImageX].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => INVERT_OFF,
+            Self::On => INVERT_ON,
+        }
+    }
+}
+
+impl Default for InvertImageX {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for InvertImageX {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(INVERT_OFF) => Ok(Self::Off),
+            v if v.contains(INVERT_ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [InvertImageX::Off, InvertImageX::On]
+            .into_iter()
+            .zip([INVERT_OFF, INVERT_ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(InvertImageX::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -0,0 +1,69 @@
+use crate::result::{Error, Result};
+
+const INVERT_OFF: &str = "0iy";
+const INVERT_ON: &str = "1iy";
+
+/// Sets the image ship image inversion around the Y-axis.
+///
+/// Set independently of [InvertImageX](super::InvertImageX), so both axes can be inverted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum InvertImageY {
+    /// Image not inverted around the Y-axis.
+    Off,
+    /// Image inverted around the Y-axis.
+    On,
+}
+
+impl InvertImageY {
+    /// Creates a new [InvertImageY].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [InvertImageY].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => INVERT_OFF,
+            Self::On => INVERT_ON,
+        }
+    }
+}
+
+impl Default for InvertImageY {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for InvertImageY {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(INVERT_OFF) => Ok(Self::Off),
+            v if v.contains(INVERT_ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [InvertImageY::Off, InvertImageY::On]
+            .into_iter()
+            .zip([INVERT_OFF, INVERT_ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(InvertImageY::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const JPEG_SUFFIX: &str = "J";
 const JPEG_DEFAULT: u8 = 50;
@@ -25,11 +26,16 @@ impl JpegImageQuality {
         self.quality
     }
 
+    /// Gets the maximum [JpegImageQuality] quality setting.
+    pub const fn max_quality() -> u8 {
+        JPEG_MAX
+    }
+
     /// Creates a [JpegImageQuality] from a quality parameter.
     pub const fn try_from_quality(quality: u8) -> Result<Self> {
         match quality {
             s if s <= JPEG_MAX => Ok(Self { quality }),
-            _ => Err(Error::InvalidValue(quality as usize)),
+            _ => Err(Error::out_of_range(quality as usize, 0, JPEG_MAX as usize)),
         }
     }
 
@@ -65,12 +71,20 @@ impl TryFrom<&str> for JpegImageQuality {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
-            .map_err(|_| Error::InvalidVariant)
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + JPEG_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, JPEG_MAX as usize))
+            })
             .and_then(Self::try_from_quality)
     }
 }
 
+serde_value!(JpegImageQuality, u8, Self::try_from_quality, Self::quality);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,9 +102,12 @@ mod tests {
     #[test]
     fn test_invalid() {
         ((JPEG_MAX + 1)..=u8::MAX).for_each(|quality| {
-            let err = Error::InvalidValue(quality as usize);
+            let err = Error::out_of_range(quality as usize, 0, JPEG_MAX as usize);
 
-            assert_eq!(JpegImageQuality::try_from_quality(quality), Err(err));
+            assert_eq!(
+                JpegImageQuality::try_from_quality(quality),
+                Err(err.clone())
+            );
             assert_eq!(JpegImageQuality::try_from(quality), Err(err));
         });
     }
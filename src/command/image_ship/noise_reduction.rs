@@ -5,6 +5,11 @@ const NOISE_ON: &str = "1if";
 
 /// Sets the image ship noise reduction.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum NoiseReduction {
     /// Noise reduction off.
     Off,
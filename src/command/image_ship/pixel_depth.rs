@@ -5,6 +5,11 @@ const DEPTH_1BIT: &str = "1D";
 
 /// Sets the image ship pixel depth.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PixelDepth {
     /// 8 bits per pixel, grayscale.
     Bit8,
@@ -6,6 +6,11 @@ const PIXEL_SHIP3: &str = "3S";
 
 /// Sets the image ship pixel ship.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PixelShip {
     /// Pixel Ship skip 1 pixel evey 1 line.
     Skip1,
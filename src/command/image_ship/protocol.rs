@@ -7,6 +7,11 @@ const PROTOCOL_HMODEM: &str = "4P";
 
 /// Sets the image ship protocol.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Protocol {
     /// Protocol none, raw data.
     Raw,
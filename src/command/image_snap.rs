@@ -1,7 +1,11 @@
 //! Types and algorithms related to `Image Snap` configuration.
 
+use crate::result::Result;
 use crate::{modifier_command, modifier_field};
 
+use super::capabilities::check_range;
+use super::Capabilities;
+
 mod beeper;
 mod delta_for_acceptance;
 mod exposure;
@@ -27,16 +31,35 @@ pub use wait_for_trigger::*;
 modifier_command! {
     /// Configure all barcode `Image Snap` encodings.
     ImageSnap: "IMGSNP" {
-        imaging_style: ImagingStyle,
-        beeper: Beeper,
-        wait_for_trigger: WaitForTrigger,
-        led: LED,
-        exposure: Exposure,
-        gain: Gain,
-        target_white_value: TargetWhiteValue,
-        delta_for_acceptance: DeltaForAcceptance,
-        update_tries: UpdateTries,
-        target_set_point: TargetSetPoint,
+        imaging_style: ImagingStyle = "P",
+        beeper: Beeper = "B",
+        wait_for_trigger: WaitForTrigger = "T",
+        led: LED = "L",
+        #[cfg_attr(feature = "serde", serde(rename = "exposure_ms"))]
+        exposure: Exposure = "E",
+        gain: Gain = "G",
+        target_white_value: TargetWhiteValue = "W",
+        delta_for_acceptance: DeltaForAcceptance = "D",
+        update_tries: UpdateTries = "U",
+        target_set_point: TargetSetPoint = "%",
+    }
+}
+
+impl ImageSnap {
+    /// Validates the [ImageSnap] settings against the device [Capabilities].
+    pub fn validate(&self, caps: &Capabilities) -> Result<()> {
+        self.exposure
+            .map_or(Ok(()), |e| check_range(e.units(), 1, caps.max_exposure()))
+            .map_err(|err| err.with_tag(Self::prefix()))
+    }
+}
+
+impl ImageSnapSequence {
+    /// Validates each modifier of the [ImageSnapSequence] against the device [Capabilities].
+    pub fn validate(&self, caps: &Capabilities) -> Result<()> {
+        self.modifiers()
+            .iter()
+            .try_for_each(|&modifier| ImageSnap::from(modifier).validate(caps))
     }
 }
 
@@ -203,6 +226,7 @@ modifier_field! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::result::Error;
 
     macro_rules! test_image_snap_field {
         ($img:ident, $field:ident, $field_val:expr) => {
@@ -274,6 +298,10 @@ mod tests {
         ])
         .for_each(|(img_str, exp_img_snap)| {
             assert_eq!(ImageSnap::try_from(img_str.as_str()), Ok(exp_img_snap));
+            assert_eq!(
+                ImageSnap::try_from_strict(img_str.as_str()),
+                Ok(exp_img_snap)
+            );
             assert_eq!(exp_img_snap.command(), img_str);
         });
 
@@ -290,4 +318,43 @@ mod tests {
         test_image_snap_field!(img, update_tries, exp_update_tries);
         test_image_snap_field!(img, target_set_point, exp_target_set_point);
     }
+
+    #[test]
+    fn test_invalid() {
+        [
+            // unknown modifier
+            ("IMGSNP1P9Z", "9Z", 8),
+            // duplicate modifier
+            ("IMGSNP1P0P", "0P", 8),
+            // leftover number
+            ("IMGSNP1P9", "9", 8),
+            // invalid modifier value
+            ("IMGSNP5P", "5P", 6),
+        ]
+        .into_iter()
+        .for_each(|(val, data, position)| {
+            assert_eq!(
+                ImageSnap::try_from_strict(val),
+                Err(Error::invalid_data("IMGSNP", data, position)),
+                "{val}"
+            );
+        });
+
+        assert_eq!(
+            ImageSnap::try_from_strict("IMGSHP1P"),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            ImageSnap::try_from_strict("IMGSNP11U"),
+            Err(Error::out_of_range(11, 0, 10)
+                .with_token("11U", 6)
+                .with_tag("IMGSNP"))
+        );
+
+        // lenient parsing ignores unknown modifiers
+        assert_eq!(
+            ImageSnap::try_from("IMGSNP1P9Z"),
+            Ok(ImageSnap::new().with_imaging_style(ImagingStyle::Photo))
+        );
+    }
 }
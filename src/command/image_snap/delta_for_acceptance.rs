@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const DFA_SUFFIX: &str = "D";
 const DFA_DEFAULT_GEN6: u8 = 25;
@@ -67,12 +68,25 @@ impl TryFrom<&str> for DeltaForAcceptance {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + DFA_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, u8::MAX as usize))
+            })
             .map(Self::from_delta)
-            .map_err(|_| Error::InvalidVariant)
     }
 }
 
+serde_value!(
+    DeltaForAcceptance,
+    u8,
+    |v| Ok(Self::from_delta(v)),
+    Self::delta
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
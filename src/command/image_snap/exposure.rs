@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const EXP_MIN: u32 = 1;
 const EXP_MIN_MS: u32 = 127;
@@ -52,13 +53,38 @@ impl Exposure {
         EXP_MAX_MS_GEN7
     }
 
+    /// Gets the maximum number of units for an [Exposure] (GEN 6).
+    pub const fn max_units_gen6() -> u32 {
+        EXP_MAX_GEN6
+    }
+
+    /// Gets the maximum number of units for an [Exposure] (GEN 7).
+    pub const fn max_units_gen7() -> u32 {
+        EXP_MAX_GEN7
+    }
+
     /// Attempts to create an [Exposure] from milliseconds parameter.
     pub const fn try_from_ms(ms: u32) -> Result<Self> {
         match ms {
             m if m >= EXP_MIN_MS && m <= EXP_MAX_MS_GEN7 => Ok(Self {
                 units: m.saturating_div(MS_PER_UNIT),
             }),
-            _ => Err(Error::InvalidVariant),
+            _ => Err(Error::out_of_range(
+                ms as usize,
+                EXP_MIN_MS as usize,
+                EXP_MAX_MS_GEN7 as usize,
+            )),
+        }
+    }
+
+    /// Attempts to create an [Exposure] from milliseconds parameter, rejecting values that are
+    /// not a multiple of the 127 ms unit, rather than rounding down.
+    pub const fn try_from_exact_ms(ms: u32) -> Result<Self> {
+        match ms {
+            m if m >= EXP_MIN_MS && m <= EXP_MAX_MS_GEN7 && m % MS_PER_UNIT != 0 => {
+                Err(Error::InvalidValue(m as usize))
+            }
+            m => Self::try_from_ms(m),
         }
     }
 
@@ -66,7 +92,11 @@ impl Exposure {
     pub const fn try_from_unit(units: u32) -> Result<Self> {
         match units {
             u if u >= EXP_MIN && u <= EXP_MAX_GEN7 => Ok(Self { units }),
-            _ => Err(Error::InvalidVariant),
+            _ => Err(Error::out_of_range(
+                units as usize,
+                EXP_MIN as usize,
+                EXP_MAX_GEN7 as usize,
+            )),
         }
     }
 
@@ -103,11 +133,16 @@ impl TryFrom<&str> for Exposure {
 
         val[exp_start..pos]
             .parse::<u32>()
-            .map_err(|_| Error::InvalidVariant)
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + EXP_SUFFIX.len()].into(),
+                position: exp_start,
+            })
             .and_then(Self::try_from_unit)
     }
 }
 
+serde_value!(Exposure, u32, Self::try_from_exact_ms, Self::ms);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,6 +156,7 @@ mod tests {
 
                 assert_eq!(Exposure::try_from_unit(units), Ok(exp_exposure));
                 assert_eq!(Exposure::try_from_ms(ms), Ok(exp_exposure));
+                assert_eq!(Exposure::try_from_exact_ms(ms), Ok(exp_exposure));
                 assert_eq!(exp_exposure.units(), units);
                 assert_eq!(exp_exposure.ms(), ms);
             });
@@ -132,11 +168,36 @@ mod tests {
             .chain((EXP_MAX_GEN7 + 1)..=(EXP_MAX_GEN7 + 1024))
             .chain([u32::MAX])
             .for_each(|units| {
-                assert_eq!(Exposure::try_from_unit(units), Err(Error::InvalidVariant));
+                let ms = units.saturating_mul(MS_PER_UNIT);
+
                 assert_eq!(
-                    Exposure::try_from_ms(units.saturating_mul(MS_PER_UNIT)),
-                    Err(Error::InvalidVariant)
+                    Exposure::try_from_unit(units),
+                    Err(Error::out_of_range(
+                        units as usize,
+                        EXP_MIN as usize,
+                        EXP_MAX_GEN7 as usize
+                    ))
                 );
+                assert_eq!(
+                    Exposure::try_from_ms(ms),
+                    Err(Error::out_of_range(
+                        ms as usize,
+                        EXP_MIN_MS as usize,
+                        EXP_MAX_MS_GEN7 as usize
+                    ))
+                );
+                assert_eq!(Exposure::try_from_exact_ms(ms), Exposure::try_from_ms(ms));
             });
+
+        [128, 1000, EXP_MAX_MS_GEN7 - 1].into_iter().for_each(|ms| {
+            assert_eq!(
+                Exposure::try_from_exact_ms(ms),
+                Err(Error::InvalidValue(ms as usize))
+            );
+            assert_eq!(
+                Exposure::try_from_ms(ms),
+                Exposure::try_from_unit(ms / MS_PER_UNIT)
+            );
+        });
     }
 }
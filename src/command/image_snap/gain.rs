@@ -7,6 +7,11 @@ const GAIN_MAX: &str = "8G";
 
 /// Sets the image snap gain.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Gain {
     /// No gain.
     Off,
@@ -6,6 +6,11 @@ const MANUAL_STYLE: &str = "2P";
 
 /// Sets the image snap style.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum ImagingStyle {
     /// Allows a few frames of exposure before taking the photo.
     Decoding,
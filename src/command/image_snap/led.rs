@@ -5,6 +5,11 @@ const LED_ON: &str = "1L";
 
 /// Sets the image snap beeper.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum LED {
     /// LEDs off.
     Off,
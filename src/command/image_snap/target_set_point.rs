@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const TARGET_SUFFIX: &str = "%";
 const TARGET_DEFAULT: u8 = 50;
@@ -30,7 +31,11 @@ impl TargetSetPoint {
     pub const fn try_from_percentage(percentage: u8) -> Result<Self> {
         match percentage {
             p if p >= TARGET_MIN && p <= TARGET_MAX => Ok(Self { percentage }),
-            _ => Err(Error::InvalidVariant),
+            _ => Err(Error::out_of_range(
+                percentage as usize,
+                TARGET_MIN as usize,
+                TARGET_MAX as usize,
+            )),
         }
     }
 
@@ -66,12 +71,27 @@ impl TryFrom<&str> for TargetSetPoint {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
-            .map_err(|_| Error::InvalidVariant)
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + TARGET_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| {
+                    Error::out_of_range(v as usize, TARGET_MIN as usize, TARGET_MAX as usize)
+                })
+            })
             .and_then(Self::try_from_percentage)
     }
 }
 
+serde_value!(
+    TargetSetPoint,
+    u8,
+    Self::try_from_percentage,
+    Self::percentage
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,7 +116,11 @@ mod tests {
             .for_each(|percentage| {
                 assert_eq!(
                     TargetSetPoint::try_from_percentage(percentage),
-                    Err(Error::InvalidVariant)
+                    Err(Error::out_of_range(
+                        percentage as usize,
+                        TARGET_MIN as usize,
+                        TARGET_MAX as usize
+                    ))
                 );
             });
     }
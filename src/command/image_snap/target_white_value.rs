@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const TWV_SUFFIX: &str = "W";
 const TWV_DEFAULT: u8 = 125;
@@ -57,12 +58,25 @@ impl TryFrom<&str> for TargetWhiteValue {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + TWV_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, u8::MAX as usize))
+            })
             .map(Self::from_value)
-            .map_err(|_| Error::InvalidVariant)
     }
 }
 
+serde_value!(
+    TargetWhiteValue,
+    u8,
+    |v| Ok(Self::from_value(v)),
+    Self::value
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
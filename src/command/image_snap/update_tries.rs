@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::result::{Error, Result};
+use crate::serde_value;
 
 const TRIES_SUFFIX: &str = "U";
 const TRIES_DEFAULT: u8 = 6;
@@ -30,7 +31,7 @@ impl UpdateTries {
         if tries <= TRIES_MAX {
             Ok(Self { tries })
         } else {
-            Err(Error::InvalidVariant)
+            Err(Error::out_of_range(tries as usize, 0, TRIES_MAX as usize))
         }
     }
 
@@ -66,12 +67,20 @@ impl TryFrom<&str> for UpdateTries {
             .unwrap_or(0);
 
         val[exp_start..pos]
-            .parse::<u8>()
-            .map_err(|_| Error::InvalidVariant)
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidData {
+                data: val[exp_start..pos + TRIES_SUFFIX.len()].into(),
+                position: exp_start,
+            })
+            .and_then(|v| {
+                u8::try_from(v).map_err(|_| Error::out_of_range(v as usize, 0, TRIES_MAX as usize))
+            })
             .and_then(Self::try_from_tries)
     }
 }
 
+serde_value!(UpdateTries, u8, Self::try_from_tries, Self::tries);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,8 +100,13 @@ mod tests {
         ((TRIES_MAX + 1)..=u8::MAX).for_each(|tries| {
             assert_eq!(
                 UpdateTries::try_from_tries(tries),
-                Err(Error::InvalidVariant)
+                Err(Error::out_of_range(tries as usize, 0, TRIES_MAX as usize))
             );
         });
+
+        assert_eq!(
+            UpdateTries::try_from("300U"),
+            Err(Error::out_of_range(300, 0, TRIES_MAX as usize))
+        );
     }
 }
@@ -5,6 +5,11 @@ const WAIT_ON: &str = "1T";
 
 /// Sets the image snap wait for trigger.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum WaitForTrigger {
     /// No beep.
     NoWait,
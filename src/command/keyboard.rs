@@ -0,0 +1,11 @@
+//! Types related to keyboard wedge configuration, for the USB keyboard interfaces.
+
+mod control_ascii;
+mod country;
+mod function_code;
+mod style;
+
+pub use control_ascii::*;
+pub use country::*;
+pub use function_code::*;
+pub use style::*;
@@ -0,0 +1,78 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "KBDCAS0";
+const DOS_MODE: &str = "KBDCAS1";
+const WINDOWS_MODE: &str = "KBDCAS2";
+
+/// Represents the `Control + ASCII Mode` serial command.
+///
+/// Controls how ASCII control characters (`0x00`-`0x1F`) are sent as keystrokes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ControlAscii {
+    /// Control characters are sent as their keyboard equivalents (e.g. `CR` as Enter).
+    Off,
+    /// Control characters are sent as `Ctrl` + key sequences, for DOS applications.
+    DosMode,
+    /// Control characters are sent as `Ctrl` + key sequences, for Windows applications.
+    WindowsMode,
+}
+
+impl ControlAscii {
+    /// Creates a new [ControlAscii].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [ControlAscii].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::DosMode => DOS_MODE,
+            Self::WindowsMode => WINDOWS_MODE,
+        }
+    }
+}
+
+impl Default for ControlAscii {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for ControlAscii {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(DOS_MODE) => Ok(Self::DosMode),
+            v if v.contains(WINDOWS_MODE) => Ok(Self::WindowsMode),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            ControlAscii::Off,
+            ControlAscii::DosMode,
+            ControlAscii::WindowsMode,
+        ]
+        .into_iter()
+        .zip([OFF, DOS_MODE, WINDOWS_MODE])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(ControlAscii::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+    }
+}
@@ -0,0 +1,130 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+const KEYBOARD_COUNTRY: &str = "KBDCTY";
+
+/// Represents the `Keyboard Country Layout` serial command.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum KeyboardCountry {
+    /// United States.
+    UnitedStates = 0,
+    /// Belgium.
+    Belgium = 1,
+    /// Finland.
+    Finland = 2,
+    /// France.
+    France = 3,
+    /// Germany.
+    Germany = 4,
+    /// Italy.
+    Italy = 5,
+    /// Switzerland (German).
+    SwitzerlandGerman = 6,
+    /// United Kingdom.
+    UnitedKingdom = 7,
+    /// Denmark.
+    Denmark = 8,
+    /// Norway.
+    Norway = 9,
+    /// Spain.
+    Spain = 10,
+}
+
+impl KeyboardCountry {
+    /// Creates a new [KeyboardCountry].
+    pub const fn new() -> Self {
+        Self::UnitedStates
+    }
+
+    /// Gets the ASCII serial command code for [KeyboardCountry].
+    pub fn command(&self) -> String {
+        format!("{KEYBOARD_COUNTRY}{}", *self as u8)
+    }
+
+    /// Attempts to convert a [`u8`] into a [KeyboardCountry].
+    pub const fn try_from_u8(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::UnitedStates),
+            1 => Ok(Self::Belgium),
+            2 => Ok(Self::Finland),
+            3 => Ok(Self::France),
+            4 => Ok(Self::Germany),
+            5 => Ok(Self::Italy),
+            6 => Ok(Self::SwitzerlandGerman),
+            7 => Ok(Self::UnitedKingdom),
+            8 => Ok(Self::Denmark),
+            9 => Ok(Self::Norway),
+            10 => Ok(Self::Spain),
+            _ => Err(Error::out_of_range(val as usize, 0, 10)),
+        }
+    }
+}
+
+impl Default for KeyboardCountry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<u8> for KeyboardCountry {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        Self::try_from_u8(val)
+    }
+}
+
+impl TryFrom<&str> for KeyboardCountry {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(KEYBOARD_COUNTRY).ok_or(Error::InvalidVariant)?;
+        let pos = i + KEYBOARD_COUNTRY.len();
+        let rem = &val[pos..];
+        let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
+
+        rem[..end]
+            .parse::<u8>()
+            .map_err(|_| Error::invalid_data(KEYBOARD_COUNTRY, rem, pos))
+            .and_then(Self::try_from_u8)
+            .map_err(|err| err.with_tag(KEYBOARD_COUNTRY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+            .into_iter()
+            .for_each(|v| {
+                let cmd = KeyboardCountry::try_from_u8(v).unwrap();
+                let exp_ascii_cmd = format!("{KEYBOARD_COUNTRY}{v}");
+
+                assert_eq!(cmd as u8, v);
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(KeyboardCountry::try_from(exp_ascii_cmd.as_str()), Ok(cmd));
+            });
+    }
+
+    #[test]
+    fn test_invalid() {
+        (0..=u8::MAX)
+            .filter(|v| ![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10].contains(v))
+            .for_each(|v| {
+                assert_eq!(
+                    KeyboardCountry::try_from_u8(v),
+                    Err(Error::out_of_range(v as usize, 0, 10))
+                );
+            });
+    }
+}
@@ -0,0 +1,70 @@
+use crate::result::{Error, Result};
+
+const TRANSMIT: &str = "RMVFNC0";
+const DONT_TRANSMIT: &str = "RMVFNC1";
+
+/// Represents the `Function Code Transmit` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FunctionCodeTransmit {
+    /// Function codes in the data are transmitted as keystrokes.
+    Transmit,
+    /// Function codes are removed from the data.
+    DontTransmit,
+}
+
+impl FunctionCodeTransmit {
+    /// Creates a new [FunctionCodeTransmit].
+    pub const fn new() -> Self {
+        Self::Transmit
+    }
+
+    /// Gets the ASCII serial command code for [FunctionCodeTransmit].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Transmit => TRANSMIT,
+            Self::DontTransmit => DONT_TRANSMIT,
+        }
+    }
+}
+
+impl Default for FunctionCodeTransmit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for FunctionCodeTransmit {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(TRANSMIT) => Ok(Self::Transmit),
+            v if v.contains(DONT_TRANSMIT) => Ok(Self::DontTransmit),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            FunctionCodeTransmit::Transmit,
+            FunctionCodeTransmit::DontTransmit,
+        ]
+        .into_iter()
+        .zip([TRANSMIT, DONT_TRANSMIT])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(FunctionCodeTransmit::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+    }
+}
@@ -0,0 +1,117 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+const KEYBOARD_STYLE: &str = "KBDSTY";
+
+/// Represents the `Keyboard Style` serial command.
+///
+/// Selects how the Caps Lock and Shift Lock state is handled when sending keystrokes.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum KeyboardStyle {
+    /// Caps Lock is normally off.
+    Regular = 0,
+    /// Caps Lock is normally on.
+    CapsLock = 1,
+    /// Shift Lock is normally on.
+    ShiftLock = 2,
+    /// Caps Lock state is detected from the host keyboard LEDs.
+    AutomaticCapsLock = 6,
+    /// Emulates an external keyboard, for hosts without one attached.
+    EmulateExternalKeyboard = 7,
+}
+
+impl KeyboardStyle {
+    /// Creates a new [KeyboardStyle].
+    pub const fn new() -> Self {
+        Self::Regular
+    }
+
+    /// Gets the ASCII serial command code for [KeyboardStyle].
+    pub fn command(&self) -> String {
+        format!("{KEYBOARD_STYLE}{}", *self as u8)
+    }
+
+    /// Attempts to convert a [`u8`] into a [KeyboardStyle].
+    pub const fn try_from_u8(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::Regular),
+            1 => Ok(Self::CapsLock),
+            2 => Ok(Self::ShiftLock),
+            6 => Ok(Self::AutomaticCapsLock),
+            7 => Ok(Self::EmulateExternalKeyboard),
+            3..=5 => Err(Error::InvalidValue(val as usize)),
+            _ => Err(Error::out_of_range(val as usize, 0, 7)),
+        }
+    }
+}
+
+impl Default for KeyboardStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<u8> for KeyboardStyle {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        Self::try_from_u8(val)
+    }
+}
+
+impl TryFrom<&str> for KeyboardStyle {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(KEYBOARD_STYLE).ok_or(Error::InvalidVariant)?;
+        let pos = i + KEYBOARD_STYLE.len();
+        let rem = &val[pos..];
+        let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
+
+        rem[..end]
+            .parse::<u8>()
+            .map_err(|_| Error::invalid_data(KEYBOARD_STYLE, rem, pos))
+            .and_then(Self::try_from_u8)
+            .map_err(|err| err.with_tag(KEYBOARD_STYLE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [0, 1, 2, 6, 7].into_iter().for_each(|v| {
+            let cmd = KeyboardStyle::try_from_u8(v).unwrap();
+            let exp_ascii_cmd = format!("{KEYBOARD_STYLE}{v}");
+
+            assert_eq!(cmd as u8, v);
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(KeyboardStyle::try_from(exp_ascii_cmd.as_str()), Ok(cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        (0..=u8::MAX).filter(|v| *v > 7).for_each(|v| {
+            assert_eq!(
+                KeyboardStyle::try_from_u8(v),
+                Err(Error::out_of_range(v as usize, 0, 7))
+            );
+        });
+        (3..=5).for_each(|v| {
+            assert_eq!(
+                KeyboardStyle::try_from_u8(v),
+                Err(Error::InvalidValue(v as usize))
+            );
+        });
+    }
+}
@@ -0,0 +1,72 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "PWRLDC0";
+const LOW: &str = "PWRLDC50";
+const HIGH: &str = "PWRLDC150";
+
+/// Represents the `LED Power Level` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum LedPowerLevel {
+    /// Illumination LEDs off.
+    Off,
+    /// Low illumination power, reduces glare on reflective surfaces.
+    Low,
+    /// High illumination power.
+    High,
+}
+
+impl LedPowerLevel {
+    /// Creates a new [LedPowerLevel].
+    pub const fn new() -> Self {
+        Self::High
+    }
+
+    /// Gets the ASCII serial command code for [LedPowerLevel].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::Low => LOW,
+            Self::High => HIGH,
+        }
+    }
+}
+
+impl Default for LedPowerLevel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for LedPowerLevel {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(LOW) => Ok(Self::Low),
+            v if v.contains(HIGH) => Ok(Self::High),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [LedPowerLevel::Off, LedPowerLevel::Low, LedPowerLevel::High]
+            .into_iter()
+            .zip([OFF, LOW, HIGH])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(LedPowerLevel::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -2,12 +2,52 @@
 macro_rules! modifier_command {
     (
         $(#[$doc:meta])+
-        $cmd:ident: $prefix:literal { $($field:ident: $field_ty:ident$(,)?)+ }$(,)?) => {
+        $cmd:ident: $prefix:literal {
+            $($(#[$field_attr:meta])* $field:ident: $field_ty:ident = $($suffix:literal)|+$(,)?)+
+        }$(,)?) => {
         paste::paste! {
             $(#[$doc])+
             #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            #[cfg_attr(
+                feature = "serde",
+                derive(serde::Serialize, serde::Deserialize),
+                serde(deny_unknown_fields)
+            )]
             pub struct $cmd {
-                $($field: Option<$field_ty>,)+
+                $(
+                    $(#[$field_attr])*
+                    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+                    $field: Option<$field_ty>,
+                )+
+            }
+
+            #[doc = "Represents a single modifier of an [" $cmd "] command."]
+            #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            #[cfg_attr(
+                feature = "serde",
+                derive(serde::Serialize, serde::Deserialize),
+                serde(rename_all = "snake_case")
+            )]
+            pub enum [<$cmd Modifier>] {
+                $(
+                    $(#[$field_attr])*
+                    [<$field:camel>]($field_ty),
+                )+
+            }
+
+            #[doc = "Represents an [" $cmd "] command with ordered, and possibly repeated, modifiers."]
+            ///
+            /// The device applies modifiers in command order, so a sequence re-encodes a command
+            /// captured from other tooling byte-for-byte, including zero-padded numbers. Holds up to
+            /// [MAX_MODIFIERS](crate::command::MAX_MODIFIERS) modifiers.
+            #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            pub struct [<$cmd Sequence>] {
+                modifiers: $crate::command::FixedList<
+                    [<$cmd Modifier>],
+                    { $crate::command::MAX_MODIFIERS },
+                >,
+                // leading zeros of each modifier number, as captured
+                padding: $crate::command::FixedList<u8, { $crate::command::MAX_MODIFIERS }>,
             }
 
             impl $cmd {
@@ -27,6 +67,50 @@ macro_rules! modifier_command {
                 pub const fn prefix() -> &'static str {
                     $prefix
                 }
+
+                #[doc = "Gets the list of set [" $cmd Modifier "]s, in field order."]
+                pub fn modifiers(&self) -> alloc::vec::Vec<[<$cmd Modifier>]> {
+                    [$(self.$field.map([<$cmd Modifier>]::[<$field:camel>]),)+]
+                        .into_iter()
+                        .flatten()
+                        .collect()
+                }
+
+                #[doc = "Sets the field of the [" $cmd Modifier "]."]
+                ///
+                /// Returns the previous modifier of the field, if any.
+                pub fn replace_modifier(
+                    &mut self,
+                    val: [<$cmd Modifier>],
+                ) -> Option<[<$cmd Modifier>]> {
+                    match val {
+                        $(
+                            [<$cmd Modifier>]::[<$field:camel>](v) => self
+                                .$field
+                                .replace(v)
+                                .map([<$cmd Modifier>]::[<$field:camel>]),
+                        )+
+                    }
+                }
+
+                #[doc = "Parses a [" $cmd "], failing on unknown, duplicate or malformed modifiers."]
+                ///
+                /// Each modifier is parsed as a [ModifierToken](crate::command::ModifierToken).
+                pub fn try_from_strict(val: &str) -> $crate::result::Result<Self> {
+                    let mut cmd = Self::new();
+
+                    for (token, modifier) in [<$cmd Modifier>]::tokenize(val)? {
+                        if cmd.replace_modifier(modifier).is_some() {
+                            return Err($crate::result::Error::invalid_data(
+                                $prefix,
+                                token.as_str(),
+                                token.position(),
+                            ));
+                        }
+                    }
+
+                    Ok(cmd)
+                }
             }
 
             impl Default for $cmd {
@@ -35,14 +119,46 @@ macro_rules! modifier_command {
                 }
             }
 
+            impl From<[<$cmd Modifier>]> for $cmd {
+                fn from(val: [<$cmd Modifier>]) -> Self {
+                    let mut cmd = Self::new();
+                    cmd.replace_modifier(val);
+                    cmd
+                }
+            }
+
+            impl From<&[<$cmd Sequence>]> for $cmd {
+                fn from(val: &[<$cmd Sequence>]) -> Self {
+                    val.to_command()
+                }
+            }
+
             impl TryFrom<&str> for $cmd {
                 type Error = $crate::result::Error;
 
+                /// Parses each field from the first match of its modifier anywhere in the
+                /// command, ignoring unknown modifiers.
+                ///
+                /// Use `try_from_strict` to also reject unknown or duplicate modifiers.
                 fn try_from(val: &str) -> $crate::result::Result<Self> {
-                    let rem = val.strip_prefix($prefix).ok_or($crate::result::Error::InvalidVariant)?;
+                    use $crate::result::Error;
 
+                    let rem = val.strip_prefix($prefix).ok_or(Error::InvalidVariant)?;
+
+                    // missing modifiers are left unset, invalid modifiers fail the command
                     Ok(Self {
-                        $($field: $field_ty::try_from(rem).ok(),)+
+                        $($field: match $field_ty::try_from(rem) {
+                            Ok(v) => Some(v),
+                            Err(Error::InvalidVariant) => None,
+                            Err(Error::InvalidData { data, position }) => {
+                                return Err(Error::InvalidData {
+                                    data,
+                                    position: position + $prefix.len(),
+                                }
+                                .with_tag($prefix))
+                            }
+                            Err(err) => return Err(err.with_tag($prefix)),
+                        },)+
                     })
                 }
             }
@@ -56,6 +172,207 @@ macro_rules! modifier_command {
                     Ok(())
                 }
             }
+
+            impl [<$cmd Modifier>] {
+                #[doc = "Gets the ASCII serial command code for the [" $cmd Modifier "]."]
+                pub fn command(&self) -> alloc::string::String {
+                    match self {
+                        $(Self::[<$field:camel>](v) => alloc::string::String::from(v.command()),)+
+                    }
+                }
+
+                // Splits the modifiers of a command, failing on unknown or malformed modifiers.
+                fn tokenize(
+                    val: &str,
+                ) -> $crate::result::Result<
+                    alloc::vec::Vec<($crate::command::ModifierToken<'_>, Self)>,
+                > {
+                    use $crate::command::ModifierToken;
+                    use $crate::result::Error;
+
+                    if !val.starts_with($prefix) {
+                        return Err(Error::InvalidVariant);
+                    }
+
+                    ModifierToken::tokenize(val, $prefix.len())
+                        .map_err(|err| err.with_tag($prefix))?
+                        .into_iter()
+                        .map(|token| {
+                            let invalid = |err: Error| match err {
+                                Error::InvalidVariant | Error::InvalidData { .. } => {
+                                    Error::invalid_data($prefix, token.as_str(), token.position())
+                                }
+                                err => err
+                                    .with_token(token.as_str(), token.position())
+                                    .with_tag($prefix),
+                            };
+
+                            let modifier = match token.suffix() {
+                                $(
+                                    $($suffix)|+ => $field_ty::try_from(token.as_str())
+                                        .map(Self::[<$field:camel>])
+                                        .map_err(invalid)?,
+                                )+
+                                _ => return Err(invalid(Error::InvalidVariant)),
+                            };
+
+                            Ok((token, modifier))
+                        })
+                        .collect()
+                }
+            }
+
+            impl core::fmt::Display for [<$cmd Modifier>] {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{}", self.command())
+                }
+            }
+
+            $(
+                impl From<$field_ty> for [<$cmd Modifier>] {
+                    fn from(val: $field_ty) -> Self {
+                        Self::[<$field:camel>](val)
+                    }
+                }
+            )+
+
+            impl [<$cmd Sequence>] {
+                #[doc = "Creates a new, empty [" $cmd Sequence "]."]
+                pub const fn new() -> Self {
+                    // unused slots are filled with the first modifier field
+                    let fill = [$([<$cmd Modifier>]::[<$field:camel>]($field_ty::new()),)+][0];
+
+                    Self {
+                        modifiers: $crate::command::FixedList::with_fill(fill),
+                        padding: $crate::command::FixedList::with_fill(0),
+                    }
+                }
+
+                #[doc = "Gets the ASCII serial command code for [" $cmd Sequence "]."]
+                pub fn command(&self) -> alloc::string::String {
+                    format!("{self}")
+                }
+
+                #[doc = "Gets the list of [" $cmd Modifier "]s, in command order."]
+                pub fn modifiers(&self) -> &[[<$cmd Modifier>]] {
+                    &self.modifiers
+                }
+
+                #[doc = "Builder function that appends a modifier to the [" $cmd Sequence "]."]
+                ///
+                /// Returns an [OutOfRange](crate::result::Error::OutOfRange) error if the sequence
+                /// is full.
+                pub fn with_modifier<M: Into<[<$cmd Modifier>]>>(
+                    mut self,
+                    val: M,
+                ) -> $crate::result::Result<Self> {
+                    self.push(val.into(), 0)?;
+                    Ok(self)
+                }
+
+                // Appends a modifier, written with `padding` leading zeros.
+                fn push(
+                    &mut self,
+                    modifier: [<$cmd Modifier>],
+                    padding: u8,
+                ) -> $crate::result::Result<()> {
+                    self.modifiers.try_push(modifier)?;
+                    self.padding.try_push(padding)
+                }
+
+                #[doc = "Converts the [" $cmd Sequence "] into the effective [" $cmd "] settings."]
+                ///
+                /// Later modifiers of a field override earlier ones, as applied by the device.
+                pub fn to_command(&self) -> $cmd {
+                    self.modifiers.iter().fold($cmd::new(), |mut cmd, &modifier| {
+                        cmd.replace_modifier(modifier);
+                        cmd
+                    })
+                }
+            }
+
+            impl Default for [<$cmd Sequence>] {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl From<&$cmd> for [<$cmd Sequence>] {
+                fn from(val: &$cmd) -> Self {
+                    // a command has at most one modifier per field, within the sequence capacity
+                    val.modifiers()
+                        .into_iter()
+                        .fold(Self::new(), |mut seq, modifier| {
+                            let _ = seq.push(modifier, 0);
+                            seq
+                        })
+                }
+            }
+
+            impl TryFrom<&str> for [<$cmd Sequence>] {
+                type Error = $crate::result::Error;
+
+                /// Parses each modifier as a [ModifierToken](crate::command::ModifierToken), in
+                /// command order, failing on unknown or malformed modifiers.
+                ///
+                /// Leading zeros of modifier numbers are kept, e.g. `08E` re-encodes as `08E`.
+                fn try_from(val: &str) -> $crate::result::Result<Self> {
+                    [<$cmd Modifier>]::tokenize(val)?
+                        .into_iter()
+                        .try_fold(Self::new(), |mut seq, (token, modifier)| {
+                            let invalid = || {
+                                $crate::result::Error::invalid_data(
+                                    $prefix,
+                                    token.as_str(),
+                                    token.position(),
+                                )
+                            };
+                            let value = token.value();
+                            let padding = value.len() - value.trim_start_matches('0').len().max(1);
+
+                            seq.push(modifier, u8::try_from(padding).map_err(|_| invalid())?)
+                                .map_err(|_| invalid())?;
+                            Ok(seq)
+                        })
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for [<$cmd Sequence>] {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> core::result::Result<S::Ok, S::Error> {
+                    // leading zeros are dropped, as for modifiers added with `with_modifier`
+                    serializer.collect_seq(self.modifiers())
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de> serde::Deserialize<'de> for [<$cmd Sequence>] {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    <alloc::vec::Vec<[<$cmd Modifier>]> as serde::Deserialize>::deserialize(
+                        deserializer,
+                    )?
+                    .into_iter()
+                    .try_fold(Self::new(), Self::with_modifier)
+                    .map_err(serde::de::Error::custom)
+                }
+            }
+
+            impl core::fmt::Display for [<$cmd Sequence>] {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{}", $prefix)?;
+                    self.modifiers
+                        .iter()
+                        .zip(self.padding.iter())
+                        .try_for_each(|(modifier, &padding)| {
+                            write!(f, "{:0>1$}{modifier}", "", padding as usize)
+                        })
+                }
+            }
         }
     }
 }
@@ -92,3 +409,33 @@ macro_rules! modifier_field {
         }
     };
 }
+
+/// Helper macro to implement `serde` for commands represented by a single validated value.
+///
+/// Values are deserialized through the fallible constructor, so invalid settings are rejected.
+#[macro_export]
+macro_rules! serde_value {
+    ($cmd:ident, $ty:ty, $de:expr, $ser:expr$(,)?) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $cmd {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                let ser: fn(&Self) -> $ty = $ser;
+                serde::Serialize::serialize(&ser(self), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $cmd {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<Self, D::Error> {
+                let de: fn($ty) -> $crate::result::Result<Self> = $de;
+                <$ty as serde::Deserialize>::deserialize(deserializer)
+                    .and_then(|v| de(v).map_err(serde::de::Error::custom))
+            }
+        }
+    };
+}
@@ -5,6 +5,11 @@ const ENHANCED: &str = "PAPHHS";
 
 /// Represents the `Manual Trigger Mode` serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum ManualTriggerMode {
     Normal,
     Enhanced,
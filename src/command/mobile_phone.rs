@@ -5,6 +5,11 @@ const STREAMING_PRESENTATION: &str = "PAPSPC";
 
 /// Represents the `Mobile Phone Read Mode` serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum MobilePhoneReadMode {
     HandheldScanning,
     StreamingPresentation,
@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+
+use crate::result::{Error, Result};
+
+/// Maximum number of modifiers in an [ImageSnapSequence](super::ImageSnapSequence) or
+/// [ImageShipSequence](super::ImageShipSequence).
+///
+/// Twice the number of [ImageShip](super::ImageShip) fields, so captured commands repeating
+/// modifiers still fit.
+pub const MAX_MODIFIERS: usize = 32;
+
+// Suffixes starting with `i` are two characters long, e.g. `ir` (image rotate).
+const EXTENDED_SUFFIX: char = 'i';
+
+/// Represents a single modifier of an [ImageSnap](super::ImageSnap) or
+/// [ImageShip](super::ImageShip) command: a number followed by a suffix, e.g. `50J`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ModifierToken<'a> {
+    token: &'a str,
+    suffix: usize,
+    position: usize,
+}
+
+impl<'a> ModifierToken<'a> {
+    /// Splits the modifiers of a command into [ModifierToken]s, starting at `start`.
+    ///
+    /// Returns an [InvalidData](Error::InvalidData) error for a modifier without a number or
+    /// suffix, e.g. leftover characters at the end of the command.
+    pub fn tokenize(val: &'a str, start: usize) -> Result<Vec<Self>> {
+        let mut tokens = Vec::new();
+        let mut position = start;
+
+        while position < val.len() {
+            let rem = &val[position..];
+            let invalid = || Error::InvalidData {
+                data: rem.into(),
+                position,
+            };
+
+            let suffix = rem
+                .find(|c: char| !c.is_ascii_digit())
+                .filter(|&s| s > 0)
+                .ok_or_else(invalid)?;
+            let suffix_len = match rem[suffix..].chars().next() {
+                Some(EXTENDED_SUFFIX) => rem[suffix + 1..]
+                    .chars()
+                    .next()
+                    .map(|c| 1 + c.len_utf8())
+                    .ok_or_else(invalid)?,
+                Some(c) => c.len_utf8(),
+                None => return Err(invalid()),
+            };
+            let len = suffix + suffix_len;
+
+            tokens.push(Self {
+                token: &rem[..len],
+                suffix,
+                position,
+            });
+            position += len;
+        }
+
+        Ok(tokens)
+    }
+
+    /// Gets the modifier, verbatim.
+    pub const fn as_str(&self) -> &'a str {
+        self.token
+    }
+
+    /// Gets the number of the modifier.
+    pub fn value(&self) -> &'a str {
+        &self.token[..self.suffix]
+    }
+
+    /// Gets the suffix of the modifier, identifying the modifier field.
+    pub fn suffix(&self) -> &'a str {
+        &self.token[self.suffix..]
+    }
+
+    /// Gets the position of the modifier in the command.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let tokens = ModifierToken::tokenize("IMGSHP50J1ir24E", 6).unwrap();
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|t| (t.value(), t.suffix(), t.position()))
+                .collect::<Vec<_>>(),
+            [("50", "J", 6), ("1", "ir", 9), ("24", "E", 12)]
+        );
+        assert_eq!(tokens[1].as_str(), "1ir");
+        assert_eq!(ModifierToken::tokenize("IMGSNP", 6), Ok(Vec::new()));
+        assert_eq!(
+            ModifierToken::tokenize("IMGSNP50%", 6).unwrap()[0].suffix(),
+            "%"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        [
+            ("IMGSNP1P9", 8, "9"),
+            ("IMGSNPP", 6, "P"),
+            ("IMGSHP1i", 6, "1i"),
+        ]
+        .into_iter()
+        .for_each(|(val, position, data)| {
+            assert_eq!(
+                ModifierToken::tokenize(val, 6),
+                Err(Error::InvalidData {
+                    data: data.into(),
+                    position,
+                }),
+                "{val}"
+            );
+        });
+    }
+}
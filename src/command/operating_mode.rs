@@ -0,0 +1,434 @@
+//! Types and algorithms related to trigger and operating mode configuration.
+
+use alloc::vec::Vec;
+
+use crate::result::{Error, Result};
+
+use super::{ManualTriggerMode, MobilePhoneReadMode, SerialCommand};
+
+mod code_gate;
+mod hands_free_timeout;
+mod presentation_centering;
+mod presentation_led;
+
+pub use code_gate::*;
+pub use hands_free_timeout::*;
+pub use presentation_centering::*;
+pub use presentation_led::*;
+
+const MANUAL_NORMAL: &str = "PAPHHF";
+const MANUAL_ENHANCED: &str = "PAPHHS";
+const PRESENTATION: &str = "PAPPST";
+const STREAMING_PRESENTATION_NORMAL: &str = "PAPSPN";
+const STREAMING_PRESENTATION_ENHANCED: &str = "PAPSPE";
+const MOBILE_PHONE_HANDHELD: &str = "PAPHHC";
+const MOBILE_PHONE_STREAMING: &str = "PAPSPC";
+const SCAN_STAND: &str = "PAPSSM";
+
+/// Represents the device operating (trigger) mode serial commands.
+///
+/// Only one operating mode can be active on the device at a time. Includes the modes covered by
+/// [ManualTriggerMode] and [MobilePhoneReadMode].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum OperatingMode {
+    /// Manual trigger, normal.
+    ManualNormal,
+    /// Manual trigger, enhanced (scans until the trigger is released).
+    ManualEnhanced,
+    /// Presentation mode, scanning when a barcode enters the field of view.
+    Presentation,
+    /// Streaming presentation, normal.
+    StreamingPresentationNormal,
+    /// Streaming presentation, enhanced.
+    StreamingPresentationEnhanced,
+    /// Manual trigger optimized for mobile phone screens.
+    MobilePhoneHandheld,
+    /// Streaming presentation optimized for mobile phone screens.
+    MobilePhoneStreaming,
+    /// Scan stand mode, scanning when the stand symbol is obscured.
+    ScanStand,
+}
+
+impl OperatingMode {
+    /// Creates a new [OperatingMode].
+    pub const fn new() -> Self {
+        Self::ManualNormal
+    }
+
+    /// Gets the ASCII serial command code for [OperatingMode].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::ManualNormal => MANUAL_NORMAL,
+            Self::ManualEnhanced => MANUAL_ENHANCED,
+            Self::Presentation => PRESENTATION,
+            Self::StreamingPresentationNormal => STREAMING_PRESENTATION_NORMAL,
+            Self::StreamingPresentationEnhanced => STREAMING_PRESENTATION_ENHANCED,
+            Self::MobilePhoneHandheld => MOBILE_PHONE_HANDHELD,
+            Self::MobilePhoneStreaming => MOBILE_PHONE_STREAMING,
+            Self::ScanStand => SCAN_STAND,
+        }
+    }
+
+    /// Gets whether the [OperatingMode] is a handheld (manual trigger) mode.
+    pub const fn handheld(&self) -> bool {
+        matches!(
+            self,
+            Self::ManualNormal | Self::ManualEnhanced | Self::MobilePhoneHandheld
+        )
+    }
+
+    /// Gets whether the [OperatingMode] is a hands-free (presentation or stand) mode.
+    pub const fn hands_free(&self) -> bool {
+        !self.handheld()
+    }
+}
+
+impl Default for OperatingMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ManualTriggerMode> for OperatingMode {
+    fn from(val: ManualTriggerMode) -> Self {
+        match val {
+            ManualTriggerMode::Normal => Self::ManualNormal,
+            ManualTriggerMode::Enhanced => Self::ManualEnhanced,
+        }
+    }
+}
+
+impl From<MobilePhoneReadMode> for OperatingMode {
+    fn from(val: MobilePhoneReadMode) -> Self {
+        match val {
+            MobilePhoneReadMode::HandheldScanning => Self::MobilePhoneHandheld,
+            MobilePhoneReadMode::StreamingPresentation => Self::MobilePhoneStreaming,
+        }
+    }
+}
+
+impl TryFrom<&str> for OperatingMode {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(MANUAL_NORMAL) => Ok(Self::ManualNormal),
+            v if v.contains(MANUAL_ENHANCED) => Ok(Self::ManualEnhanced),
+            v if v.contains(PRESENTATION) => Ok(Self::Presentation),
+            v if v.contains(STREAMING_PRESENTATION_NORMAL) => Ok(Self::StreamingPresentationNormal),
+            v if v.contains(STREAMING_PRESENTATION_ENHANCED) => {
+                Ok(Self::StreamingPresentationEnhanced)
+            }
+            v if v.contains(MOBILE_PHONE_HANDHELD) => Ok(Self::MobilePhoneHandheld),
+            v if v.contains(MOBILE_PHONE_STREAMING) => Ok(Self::MobilePhoneStreaming),
+            v if v.contains(SCAN_STAND) => Ok(Self::ScanStand),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+/// Represents a validated set of operating mode settings.
+///
+/// [CodeGate] is only accepted for handheld modes, the presentation settings and
+/// [HandsFreeTimeout] only for hands-free modes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "OperatingModeConfigFields")
+)]
+pub struct OperatingModeConfig {
+    mode: OperatingMode,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    code_gate: Option<CodeGate>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    centering: Option<PresentationCentering>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    led: Option<PresentationLed>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "hands_free_timeout_ms",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    hands_free_timeout: Option<HandsFreeTimeout>,
+}
+
+impl OperatingModeConfig {
+    /// Creates a new [OperatingModeConfig].
+    pub const fn new() -> Self {
+        Self::from_mode(OperatingMode::new())
+    }
+
+    /// Creates a new [OperatingModeConfig] for the provided [OperatingMode].
+    pub const fn from_mode(mode: OperatingMode) -> Self {
+        Self {
+            mode,
+            code_gate: None,
+            centering: None,
+            led: None,
+            hands_free_timeout: None,
+        }
+    }
+
+    /// Creates a new [OperatingModeConfig] for handheld use (manual trigger, with [CodeGate]).
+    pub const fn handheld() -> Self {
+        Self {
+            code_gate: Some(CodeGate::On),
+            ..Self::from_mode(OperatingMode::ManualNormal)
+        }
+    }
+
+    /// Creates a new [OperatingModeConfig] for stand use (streaming presentation).
+    pub const fn stand() -> Self {
+        Self::from_mode(OperatingMode::StreamingPresentationNormal)
+    }
+
+    /// Gets the [OperatingMode] for [OperatingModeConfig].
+    pub const fn mode(&self) -> OperatingMode {
+        self.mode
+    }
+
+    /// Gets the [CodeGate] for [OperatingModeConfig].
+    pub const fn code_gate(&self) -> Option<CodeGate> {
+        self.code_gate
+    }
+
+    /// Gets the [PresentationCentering] for [OperatingModeConfig].
+    pub const fn centering(&self) -> Option<PresentationCentering> {
+        self.centering
+    }
+
+    /// Gets the [PresentationLed] for [OperatingModeConfig].
+    pub const fn led(&self) -> Option<PresentationLed> {
+        self.led
+    }
+
+    /// Gets the [HandsFreeTimeout] for [OperatingModeConfig].
+    pub const fn hands_free_timeout(&self) -> Option<HandsFreeTimeout> {
+        self.hands_free_timeout
+    }
+
+    /// Builder function that sets the [CodeGate] for [OperatingModeConfig].
+    ///
+    /// Returns an error if the [OperatingMode] is not a handheld mode.
+    pub const fn with_code_gate(self, val: CodeGate) -> Result<Self> {
+        if self.mode.handheld() {
+            Ok(Self {
+                code_gate: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Builder function that sets the [PresentationCentering] for [OperatingModeConfig].
+    ///
+    /// Returns an error if the [OperatingMode] is not a hands-free mode.
+    pub const fn with_centering(self, val: PresentationCentering) -> Result<Self> {
+        if self.mode.hands_free() {
+            Ok(Self {
+                centering: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Builder function that sets the [PresentationLed] for [OperatingModeConfig].
+    ///
+    /// Returns an error if the [OperatingMode] is not a hands-free mode.
+    pub const fn with_led(self, val: PresentationLed) -> Result<Self> {
+        if self.mode.hands_free() {
+            Ok(Self {
+                led: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Builder function that sets the [HandsFreeTimeout] for [OperatingModeConfig].
+    ///
+    /// Returns an error if the [OperatingMode] is not a hands-free mode.
+    pub const fn with_hands_free_timeout(self, val: HandsFreeTimeout) -> Result<Self> {
+        if self.mode.hands_free() {
+            Ok(Self {
+                hands_free_timeout: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Gets the list of [SerialCommand]s for the [OperatingModeConfig].
+    ///
+    /// The [OperatingMode] is always sent first, so that the device accepts the dependent
+    /// settings.
+    pub fn serial_commands(&self) -> Vec<SerialCommand> {
+        let mut cmds = vec![SerialCommand::OperatingMode(self.mode)];
+
+        cmds.extend(self.code_gate.map(SerialCommand::CodeGate));
+        cmds.extend(self.centering.map(SerialCommand::PresentationCentering));
+        cmds.extend(self.led.map(SerialCommand::PresentationLed));
+        cmds.extend(self.hands_free_timeout.map(SerialCommand::HandsFreeTimeout));
+
+        cmds
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OperatingModeConfigFields {
+    mode: OperatingMode,
+    code_gate: Option<CodeGate>,
+    centering: Option<PresentationCentering>,
+    led: Option<PresentationLed>,
+    #[serde(rename = "hands_free_timeout_ms")]
+    hands_free_timeout: Option<HandsFreeTimeout>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<OperatingModeConfigFields> for OperatingModeConfig {
+    type Error = Error;
+
+    fn try_from(val: OperatingModeConfigFields) -> Result<Self> {
+        let mut cfg = Self::from_mode(val.mode);
+
+        if let Some(v) = val.code_gate {
+            cfg = cfg.with_code_gate(v)?;
+        }
+        if let Some(v) = val.centering {
+            cfg = cfg.with_centering(v)?;
+        }
+        if let Some(v) = val.led {
+            cfg = cfg.with_led(v)?;
+        }
+        if let Some(v) = val.hands_free_timeout {
+            cfg = cfg.with_hands_free_timeout(v)?;
+        }
+
+        Ok(cfg)
+    }
+}
+
+impl Default for OperatingModeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<OperatingMode> for OperatingModeConfig {
+    fn from(val: OperatingMode) -> Self {
+        Self::from_mode(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::*;
+
+    fn commands(cfg: &OperatingModeConfig) -> Vec<String> {
+        cfg.serial_commands()
+            .iter()
+            .map(SerialCommand::command)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid() {
+        [
+            OperatingMode::ManualNormal,
+            OperatingMode::ManualEnhanced,
+            OperatingMode::Presentation,
+            OperatingMode::StreamingPresentationNormal,
+            OperatingMode::StreamingPresentationEnhanced,
+            OperatingMode::MobilePhoneHandheld,
+            OperatingMode::MobilePhoneStreaming,
+            OperatingMode::ScanStand,
+        ]
+        .into_iter()
+        .zip([
+            MANUAL_NORMAL,
+            MANUAL_ENHANCED,
+            PRESENTATION,
+            STREAMING_PRESENTATION_NORMAL,
+            STREAMING_PRESENTATION_ENHANCED,
+            MOBILE_PHONE_HANDHELD,
+            MOBILE_PHONE_STREAMING,
+            SCAN_STAND,
+        ])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(OperatingMode::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+
+        [ManualTriggerMode::Normal, ManualTriggerMode::Enhanced]
+            .into_iter()
+            .for_each(|cmd| assert_eq!(OperatingMode::from(cmd).command(), cmd.command()));
+        [
+            MobilePhoneReadMode::HandheldScanning,
+            MobilePhoneReadMode::StreamingPresentation,
+        ]
+        .into_iter()
+        .for_each(|cmd| assert_eq!(OperatingMode::from(cmd).command(), cmd.command()));
+
+        assert_eq!(
+            commands(&OperatingModeConfig::handheld()),
+            [MANUAL_NORMAL, "AOSCGD1"]
+        );
+
+        let stand = OperatingModeConfig::stand()
+            .with_centering(PresentationCentering::On)
+            .and_then(|c| c.with_led(PresentationLed::Off))
+            .and_then(|c| c.with_hands_free_timeout(HandsFreeTimeout::try_from_ms(5_000)?))
+            .unwrap();
+
+        assert_eq!(
+            commands(&stand),
+            [
+                STREAMING_PRESENTATION_NORMAL,
+                "PDCWIN1",
+                "PDCLED0",
+                "TRGPTO5000"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            OperatingModeConfig::stand().with_code_gate(CodeGate::On),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            OperatingModeConfig::handheld().with_centering(PresentationCentering::On),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            OperatingModeConfig::new().with_led(PresentationLed::Off),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            OperatingModeConfig::new().with_hands_free_timeout(HandsFreeTimeout::new()),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            OperatingMode::try_from("PAPXXX"),
+            Err(Error::InvalidVariant)
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "AOSCGD0";
+const ON: &str = "AOSCGD1";
+
+/// Represents the `CodeGate` serial command.
+///
+/// Allows aiming at one barcode among several before transmitting it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CodeGate {
+    /// Barcodes are transmitted as soon as they are decoded.
+    Off,
+    /// Barcodes are only transmitted while the trigger is pressed.
+    On,
+}
+
+impl CodeGate {
+    /// Creates a new [CodeGate].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [CodeGate].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+}
+
+impl Default for CodeGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for CodeGate {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [CodeGate::Off, CodeGate::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(CodeGate::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
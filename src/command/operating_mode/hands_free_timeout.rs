@@ -0,0 +1,119 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+const HANDS_FREE_TIMEOUT: &str = "TRGPTO";
+/// Maximum timeout (in milliseconds) for [HandsFreeTimeout].
+pub const MAX_HANDS_FREE_TIMEOUT: u32 = 300_000;
+
+/// Represents the `Hands Free Time-Out` serial command.
+///
+/// Time (in milliseconds) the device stays in manual trigger mode after the trigger is pressed
+/// in a hands-free mode, before returning to the hands-free mode (`0` stays in manual mode).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HandsFreeTimeout {
+    ms: u32,
+}
+
+impl HandsFreeTimeout {
+    /// Creates a new [HandsFreeTimeout].
+    pub const fn new() -> Self {
+        Self { ms: 0 }
+    }
+
+    /// Gets the ASCII serial command code for [HandsFreeTimeout].
+    pub fn command(&self) -> String {
+        format!("{HANDS_FREE_TIMEOUT}{}", self.ms)
+    }
+
+    /// Attempts to convert a [`u32`] number of timeout milliseconds into a [HandsFreeTimeout].
+    ///
+    /// **NOTE**: `ms` must be below [MAX_HANDS_FREE_TIMEOUT] number of milliseconds.
+    pub const fn try_from_ms(ms: u32) -> Result<Self> {
+        if ms <= MAX_HANDS_FREE_TIMEOUT {
+            Ok(Self { ms })
+        } else {
+            Err(Error::out_of_range(
+                ms as usize,
+                0,
+                MAX_HANDS_FREE_TIMEOUT as usize,
+            ))
+        }
+    }
+
+    /// Converts a [HandsFreeTimeout] into a [`u32`] number of milliseconds.
+    pub const fn into_ms(self) -> u32 {
+        self.ms
+    }
+}
+
+impl Default for HandsFreeTimeout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for HandsFreeTimeout {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(HANDS_FREE_TIMEOUT).ok_or(Error::InvalidVariant)?;
+        let pos = i + HANDS_FREE_TIMEOUT.len();
+
+        let ms = val[pos..]
+            .parse::<u32>()
+            .map_err(|_| Error::invalid_data(HANDS_FREE_TIMEOUT, &val[pos..], pos))?;
+
+        Self::try_from_ms(ms).map_err(|err| err.with_tag(HANDS_FREE_TIMEOUT))
+    }
+}
+
+impl TryFrom<String> for HandsFreeTimeout {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<Self> {
+        val.as_str().try_into()
+    }
+}
+
+serde_value!(HandsFreeTimeout, u32, Self::try_from_ms, |c| c.into_ms());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        (0..=MAX_HANDS_FREE_TIMEOUT).for_each(|ms| {
+            let exp_cmd = HandsFreeTimeout { ms };
+            let exp_ascii_cmd = format!("{HANDS_FREE_TIMEOUT}{ms}");
+
+            assert_eq!(HandsFreeTimeout::try_from_ms(ms), Ok(exp_cmd));
+            assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+            assert_eq!(HandsFreeTimeout::try_from(exp_ascii_cmd), Ok(exp_cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            HandsFreeTimeout::try_from_ms(MAX_HANDS_FREE_TIMEOUT + 1),
+            Err(Error::out_of_range(
+                MAX_HANDS_FREE_TIMEOUT as usize + 1,
+                0,
+                MAX_HANDS_FREE_TIMEOUT as usize
+            ))
+        );
+        assert_eq!(
+            HandsFreeTimeout::try_from("TRGPTO999999"),
+            Err(
+                Error::out_of_range(999999, 0, MAX_HANDS_FREE_TIMEOUT as usize)
+                    .with_tag(HANDS_FREE_TIMEOUT)
+            )
+        );
+        assert_eq!(
+            HandsFreeTimeout::try_from("TRGPTO1s"),
+            Err(Error::invalid_data(HANDS_FREE_TIMEOUT, "1s", 6))
+        );
+    }
+}
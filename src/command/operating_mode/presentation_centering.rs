@@ -0,0 +1,67 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "PDCWIN0";
+const ON: &str = "PDCWIN1";
+
+/// Represents the `Presentation Centering` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PresentationCentering {
+    /// Barcodes anywhere in the field of view are decoded.
+    Off,
+    /// Only barcodes intersecting the centering window are decoded.
+    On,
+}
+
+impl PresentationCentering {
+    /// Creates a new [PresentationCentering].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [PresentationCentering].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+}
+
+impl Default for PresentationCentering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for PresentationCentering {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [PresentationCentering::Off, PresentationCentering::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(PresentationCentering::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
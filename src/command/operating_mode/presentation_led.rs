@@ -0,0 +1,67 @@
+use crate::result::{Error, Result};
+
+const LEDS_ON: &str = "PDCLED1";
+const LEDS_OFF: &str = "PDCLED0";
+
+/// Represents the `Presentation LED Behavior after Decode` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PresentationLed {
+    /// Illumination LEDs stay on after a decode.
+    On,
+    /// Illumination LEDs turn off after a decode, until the next read.
+    Off,
+}
+
+impl PresentationLed {
+    /// Creates a new [PresentationLed].
+    pub const fn new() -> Self {
+        Self::On
+    }
+
+    /// Gets the ASCII serial command code for [PresentationLed].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::On => LEDS_ON,
+            Self::Off => LEDS_OFF,
+        }
+    }
+}
+
+impl Default for PresentationLed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for PresentationLed {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(LEDS_ON) => Ok(Self::On),
+            v if v.contains(LEDS_OFF) => Ok(Self::Off),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [PresentationLed::On, PresentationLed::Off]
+            .into_iter()
+            .zip([LEDS_ON, LEDS_OFF])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(PresentationLed::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
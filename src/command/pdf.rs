@@ -6,6 +6,11 @@ const PDF_ON: &str = "PDFENA1";
 
 /// Represents the `PDF417` serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PDF417 {
     DefaultSettings,
     Off,
@@ -0,0 +1,462 @@
+//! Types and algorithms related to `Postal` symbology configuration.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::result::{Error, Result};
+
+use super::SerialCommand;
+
+mod australian_post_interpretation;
+mod planet_check_digit;
+mod postnet_check_digit;
+
+pub use australian_post_interpretation::*;
+pub use planet_check_digit::*;
+pub use postnet_check_digit::*;
+
+const POSTAL: &str = "POSTAL";
+
+/// Represents the `2D Postal Codes` serial command.
+///
+/// Only one postal group can be active on the device at a time, so combinations of postal codes
+/// are represented as their own variants.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Postal {
+    /// All postal codes off.
+    Off = 0,
+    /// Australian Post on.
+    AustralianPost = 1,
+    /// Japanese Post on.
+    JapanPost = 3,
+    /// KIX Post on.
+    Kix = 4,
+    /// Planet Code on.
+    Planet = 5,
+    /// Postnet on.
+    Postnet = 6,
+    /// British Post (Royal Mail) on.
+    BritishPost = 7,
+    /// Canadian Post on.
+    CanadianPost = 8,
+    /// Postal-4i on.
+    Postal4i = 9,
+    /// Intelligent Mail Barcode on.
+    IntelligentMail = 10,
+    /// Postnet with B and B' fields on.
+    PostnetBB = 11,
+    /// Planet Code and Postnet on.
+    PlanetPostnet = 12,
+    /// Planet Code and Postnet with B and B' fields on.
+    PlanetPostnetBB = 13,
+    /// InfoMail on.
+    InfoMail = 14,
+    /// InfoMail and British Post on.
+    InfoMailBritishPost = 15,
+    /// Postnet and Postal-4i on.
+    PostnetPostal4i = 16,
+    /// Postnet and Intelligent Mail Barcode on.
+    PostnetIntelligentMail = 17,
+    /// Postal-4i and Intelligent Mail Barcode on.
+    Postal4iIntelligentMail = 18,
+    /// Intelligent Mail Barcode and Postnet with B and B' fields on.
+    IntelligentMailPostnetBB = 20,
+    /// Postal-4i and Postnet with B and B' fields on.
+    Postal4iPostnetBB = 21,
+    /// Planet Code and Postal-4i on.
+    PlanetPostal4i = 22,
+    /// Planet Code and Intelligent Mail Barcode on.
+    PlanetIntelligentMail = 23,
+    /// Planet Code, Postnet and Postal-4i on.
+    PlanetPostnetPostal4i = 24,
+    /// Planet Code, Postnet and Intelligent Mail Barcode on.
+    PlanetPostnetIntelligentMail = 25,
+    /// Planet Code, Postal-4i and Intelligent Mail Barcode on.
+    PlanetPostal4iIntelligentMail = 26,
+    /// Postnet, Postal-4i and Intelligent Mail Barcode on.
+    PostnetPostal4iIntelligentMail = 27,
+    /// Planet Code, Postal-4i and Postnet with B and B' fields on.
+    PlanetPostal4iPostnetBB = 28,
+    /// Planet Code, Intelligent Mail Barcode and Postnet with B and B' fields on.
+    PlanetIntelligentMailPostnetBB = 29,
+    /// Postal-4i, Intelligent Mail Barcode and Postnet with B and B' fields on.
+    Postal4iIntelligentMailPostnetBB = 30,
+    /// Planet Code, Postal-4i, Intelligent Mail Barcode and Postnet on.
+    PlanetPostal4iIntelligentMailPostnet = 31,
+    /// Planet Code, Postal-4i, Intelligent Mail Barcode and Postnet with B and B' fields on.
+    PlanetPostal4iIntelligentMailPostnetBB = 32,
+}
+
+impl Postal {
+    /// Creates a new [Postal].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [Postal].
+    pub fn command(&self) -> String {
+        format!("{POSTAL}{}", *self as u8)
+    }
+
+    /// Attempts to convert a [`u8`] into a [Postal].
+    pub const fn try_from_u8(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::AustralianPost),
+            3 => Ok(Self::JapanPost),
+            4 => Ok(Self::Kix),
+            5 => Ok(Self::Planet),
+            6 => Ok(Self::Postnet),
+            7 => Ok(Self::BritishPost),
+            8 => Ok(Self::CanadianPost),
+            9 => Ok(Self::Postal4i),
+            10 => Ok(Self::IntelligentMail),
+            11 => Ok(Self::PostnetBB),
+            12 => Ok(Self::PlanetPostnet),
+            13 => Ok(Self::PlanetPostnetBB),
+            14 => Ok(Self::InfoMail),
+            15 => Ok(Self::InfoMailBritishPost),
+            16 => Ok(Self::PostnetPostal4i),
+            17 => Ok(Self::PostnetIntelligentMail),
+            18 => Ok(Self::Postal4iIntelligentMail),
+            20 => Ok(Self::IntelligentMailPostnetBB),
+            21 => Ok(Self::Postal4iPostnetBB),
+            22 => Ok(Self::PlanetPostal4i),
+            23 => Ok(Self::PlanetIntelligentMail),
+            24 => Ok(Self::PlanetPostnetPostal4i),
+            25 => Ok(Self::PlanetPostnetIntelligentMail),
+            26 => Ok(Self::PlanetPostal4iIntelligentMail),
+            27 => Ok(Self::PostnetPostal4iIntelligentMail),
+            28 => Ok(Self::PlanetPostal4iPostnetBB),
+            29 => Ok(Self::PlanetIntelligentMailPostnetBB),
+            30 => Ok(Self::Postal4iIntelligentMailPostnetBB),
+            31 => Ok(Self::PlanetPostal4iIntelligentMailPostnet),
+            32 => Ok(Self::PlanetPostal4iIntelligentMailPostnetBB),
+            2 | 19 => Err(Error::InvalidValue(val as usize)),
+            _ => Err(Error::out_of_range(val as usize, 0, 32)),
+        }
+    }
+
+    /// Gets whether the [Postal] group enables Planet Code.
+    pub const fn planet(&self) -> bool {
+        matches!(
+            self,
+            Self::Planet
+                | Self::PlanetPostnet
+                | Self::PlanetPostnetBB
+                | Self::PlanetPostal4i
+                | Self::PlanetIntelligentMail
+                | Self::PlanetPostnetPostal4i
+                | Self::PlanetPostnetIntelligentMail
+                | Self::PlanetPostal4iIntelligentMail
+                | Self::PlanetPostal4iPostnetBB
+                | Self::PlanetIntelligentMailPostnetBB
+                | Self::PlanetPostal4iIntelligentMailPostnet
+                | Self::PlanetPostal4iIntelligentMailPostnetBB
+        )
+    }
+
+    /// Gets whether the [Postal] group enables Postnet (with or without B and B' fields).
+    pub const fn postnet(&self) -> bool {
+        matches!(
+            self,
+            Self::Postnet
+                | Self::PostnetBB
+                | Self::PlanetPostnet
+                | Self::PlanetPostnetBB
+                | Self::PostnetPostal4i
+                | Self::PostnetIntelligentMail
+                | Self::IntelligentMailPostnetBB
+                | Self::Postal4iPostnetBB
+                | Self::PlanetPostnetPostal4i
+                | Self::PlanetPostnetIntelligentMail
+                | Self::PostnetPostal4iIntelligentMail
+                | Self::PlanetPostal4iPostnetBB
+                | Self::PlanetIntelligentMailPostnetBB
+                | Self::Postal4iIntelligentMailPostnetBB
+                | Self::PlanetPostal4iIntelligentMailPostnet
+                | Self::PlanetPostal4iIntelligentMailPostnetBB
+        )
+    }
+
+    /// Gets whether the [Postal] group enables Australian Post.
+    pub const fn australian_post(&self) -> bool {
+        matches!(self, Self::AustralianPost)
+    }
+}
+
+impl Default for Postal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<u8> for Postal {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        Self::try_from_u8(val)
+    }
+}
+
+impl TryFrom<&str> for Postal {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(POSTAL).ok_or(Error::InvalidVariant)?;
+        let pos = i + POSTAL.len();
+        let rem = &val[pos..];
+        let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
+
+        rem[..end]
+            .parse::<u8>()
+            .map_err(|_| Error::invalid_data(POSTAL, rem, pos))
+            .and_then(Self::try_from_u8)
+            .map_err(|err| err.with_tag(POSTAL))
+    }
+}
+
+/// Represents a validated set of `Postal` symbology settings.
+///
+/// Check digit and interpretation options are only accepted if the selected [Postal] group
+/// enables the corresponding postal code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "PostalConfigFields")
+)]
+pub struct PostalConfig {
+    postal: Postal,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    planet_check_digit: Option<PlanetCheckDigit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    postnet_check_digit: Option<PostnetCheckDigit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    australian_post_interpretation: Option<AustralianPostInterpretation>,
+}
+
+impl PostalConfig {
+    /// Creates a new [PostalConfig].
+    pub const fn new() -> Self {
+        Self {
+            postal: Postal::new(),
+            planet_check_digit: None,
+            postnet_check_digit: None,
+            australian_post_interpretation: None,
+        }
+    }
+
+    /// Creates a new [PostalConfig] for the provided [Postal] group.
+    pub const fn from_postal(postal: Postal) -> Self {
+        Self {
+            postal,
+            planet_check_digit: None,
+            postnet_check_digit: None,
+            australian_post_interpretation: None,
+        }
+    }
+
+    /// Gets the [Postal] group for [PostalConfig].
+    pub const fn postal(&self) -> Postal {
+        self.postal
+    }
+
+    /// Gets the [PlanetCheckDigit] for [PostalConfig].
+    pub const fn planet_check_digit(&self) -> Option<PlanetCheckDigit> {
+        self.planet_check_digit
+    }
+
+    /// Gets the [PostnetCheckDigit] for [PostalConfig].
+    pub const fn postnet_check_digit(&self) -> Option<PostnetCheckDigit> {
+        self.postnet_check_digit
+    }
+
+    /// Gets the [AustralianPostInterpretation] for [PostalConfig].
+    pub const fn australian_post_interpretation(&self) -> Option<AustralianPostInterpretation> {
+        self.australian_post_interpretation
+    }
+
+    /// Builder function that sets the [PlanetCheckDigit] for [PostalConfig].
+    ///
+    /// Returns an error if the [Postal] group does not enable Planet Code.
+    pub const fn with_planet_check_digit(self, val: PlanetCheckDigit) -> Result<Self> {
+        if self.postal.planet() {
+            Ok(Self {
+                planet_check_digit: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Builder function that sets the [PostnetCheckDigit] for [PostalConfig].
+    ///
+    /// Returns an error if the [Postal] group does not enable Postnet.
+    pub const fn with_postnet_check_digit(self, val: PostnetCheckDigit) -> Result<Self> {
+        if self.postal.postnet() {
+            Ok(Self {
+                postnet_check_digit: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Builder function that sets the [AustralianPostInterpretation] for [PostalConfig].
+    ///
+    /// Returns an error if the [Postal] group does not enable Australian Post.
+    pub const fn with_australian_post_interpretation(
+        self,
+        val: AustralianPostInterpretation,
+    ) -> Result<Self> {
+        if self.postal.australian_post() {
+            Ok(Self {
+                australian_post_interpretation: Some(val),
+                ..self
+            })
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Gets the list of [SerialCommand]s for the [PostalConfig].
+    ///
+    /// The [Postal] group is always sent first, so that the device accepts the dependent
+    /// settings.
+    pub fn serial_commands(&self) -> Vec<SerialCommand> {
+        let mut cmds = vec![SerialCommand::Postal(self.postal)];
+
+        cmds.extend(self.planet_check_digit.map(SerialCommand::PlanetCheckDigit));
+        cmds.extend(
+            self.postnet_check_digit
+                .map(SerialCommand::PostnetCheckDigit),
+        );
+        cmds.extend(
+            self.australian_post_interpretation
+                .map(SerialCommand::AustralianPostInterpretation),
+        );
+
+        cmds
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PostalConfigFields {
+    postal: Postal,
+    planet_check_digit: Option<PlanetCheckDigit>,
+    postnet_check_digit: Option<PostnetCheckDigit>,
+    australian_post_interpretation: Option<AustralianPostInterpretation>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<PostalConfigFields> for PostalConfig {
+    type Error = Error;
+
+    fn try_from(val: PostalConfigFields) -> Result<Self> {
+        let mut cfg = Self::from_postal(val.postal);
+
+        if let Some(v) = val.planet_check_digit {
+            cfg = cfg.with_planet_check_digit(v)?;
+        }
+        if let Some(v) = val.postnet_check_digit {
+            cfg = cfg.with_postnet_check_digit(v)?;
+        }
+        if let Some(v) = val.australian_post_interpretation {
+            cfg = cfg.with_australian_post_interpretation(v)?;
+        }
+
+        Ok(cfg)
+    }
+}
+
+impl Default for PostalConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(cfg: &PostalConfig) -> Vec<String> {
+        cfg.serial_commands()
+            .iter()
+            .map(SerialCommand::command)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid() {
+        (0..=u8::MAX)
+            .filter_map(|v| Postal::try_from_u8(v).ok().map(|p| (v, p)))
+            .for_each(|(v, cmd)| {
+                let exp_ascii_cmd = format!("{POSTAL}{v}");
+
+                assert_eq!(cmd as u8, v);
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(Postal::try_from(exp_ascii_cmd.as_str()), Ok(cmd));
+            });
+    }
+
+    #[test]
+    fn test_invalid() {
+        [2, 19].into_iter().for_each(|v| {
+            assert_eq!(Postal::try_from_u8(v), Err(Error::InvalidValue(v as usize)));
+        });
+        (33..=u8::MAX).for_each(|v| {
+            assert_eq!(
+                Postal::try_from_u8(v),
+                Err(Error::out_of_range(v as usize, 0, 32))
+            );
+        });
+
+        assert_eq!(
+            Postal::try_from("POSTAL"),
+            Err(Error::invalid_data("POSTAL", "", 6))
+        );
+        assert_eq!(Postal::try_from("PLNCKX1"), Err(Error::InvalidVariant));
+    }
+
+    #[test]
+    fn test_config() {
+        let cfg = PostalConfig::from_postal(Postal::PlanetPostnet)
+            .with_planet_check_digit(PlanetCheckDigit::Transmit)
+            .and_then(|c| c.with_postnet_check_digit(PostnetCheckDigit::DontTransmit))
+            .unwrap();
+
+        assert_eq!(commands(&cfg), ["POSTAL12", "PLNCKX1", "NETCKX0"]);
+
+        assert_eq!(
+            PostalConfig::from_postal(Postal::IntelligentMail)
+                .with_planet_check_digit(PlanetCheckDigit::Transmit),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            PostalConfig::from_postal(Postal::Planet)
+                .with_postnet_check_digit(PostnetCheckDigit::Transmit),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            PostalConfig::from_postal(Postal::Kix)
+                .with_australian_post_interpretation(AustralianPostInterpretation::BarOutput),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            PostalConfig::from_postal(Postal::AustralianPost)
+                .with_australian_post_interpretation(AustralianPostInterpretation::Combination)
+                .map(|c| commands(&c)),
+            Ok(vec![String::from("POSTAL1"), String::from("AUSINT3")])
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::result::{Error, Result};
+
+const BAR_OUTPUT: &str = "AUSINT0";
+const NUMERIC_N: &str = "AUSINT1";
+const ALPHANUMERIC_C: &str = "AUSINT2";
+const COMBINATION: &str = "AUSINT3";
+
+/// Represents the `Australian Post Interpretation` serial command.
+///
+/// Controls how the customer information field of Australian Post barcodes is decoded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AustralianPostInterpretation {
+    /// Transmit the raw bar values.
+    BarOutput,
+    /// Decode using the numeric `N` table.
+    NumericN,
+    /// Decode using the alphanumeric `C` table.
+    AlphanumericC,
+    /// Decode using a combination of the `N` and `C` tables.
+    Combination,
+}
+
+impl AustralianPostInterpretation {
+    /// Creates a new [AustralianPostInterpretation].
+    pub const fn new() -> Self {
+        Self::BarOutput
+    }
+
+    /// Gets the ASCII serial command code for [AustralianPostInterpretation].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::BarOutput => BAR_OUTPUT,
+            Self::NumericN => NUMERIC_N,
+            Self::AlphanumericC => ALPHANUMERIC_C,
+            Self::Combination => COMBINATION,
+        }
+    }
+}
+
+impl Default for AustralianPostInterpretation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for AustralianPostInterpretation {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(BAR_OUTPUT) => Ok(Self::BarOutput),
+            v if v.contains(NUMERIC_N) => Ok(Self::NumericN),
+            v if v.contains(ALPHANUMERIC_C) => Ok(Self::AlphanumericC),
+            v if v.contains(COMBINATION) => Ok(Self::Combination),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            AustralianPostInterpretation::BarOutput,
+            AustralianPostInterpretation::NumericN,
+            AustralianPostInterpretation::AlphanumericC,
+            AustralianPostInterpretation::Combination,
+        ]
+        .into_iter()
+        .zip([BAR_OUTPUT, NUMERIC_N, ALPHANUMERIC_C, COMBINATION])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(
+                AustralianPostInterpretation::try_from(exp_ascii_cmd),
+                Ok(cmd)
+            );
+        });
+    }
+}
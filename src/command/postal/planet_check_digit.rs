@@ -0,0 +1,67 @@
+use crate::result::{Error, Result};
+
+const DONT_TRANSMIT: &str = "PLNCKX0";
+const TRANSMIT: &str = "PLNCKX1";
+
+/// Represents the `Planet Code Check Digit` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PlanetCheckDigit {
+    /// Check digit is validated, but not transmitted.
+    DontTransmit,
+    /// Check digit is validated and transmitted.
+    Transmit,
+}
+
+impl PlanetCheckDigit {
+    /// Creates a new [PlanetCheckDigit].
+    pub const fn new() -> Self {
+        Self::DontTransmit
+    }
+
+    /// Gets the ASCII serial command code for [PlanetCheckDigit].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::DontTransmit => DONT_TRANSMIT,
+            Self::Transmit => TRANSMIT,
+        }
+    }
+}
+
+impl Default for PlanetCheckDigit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for PlanetCheckDigit {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(DONT_TRANSMIT) => Ok(Self::DontTransmit),
+            v if v.contains(TRANSMIT) => Ok(Self::Transmit),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [PlanetCheckDigit::DontTransmit, PlanetCheckDigit::Transmit]
+            .into_iter()
+            .zip([DONT_TRANSMIT, TRANSMIT])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(PlanetCheckDigit::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -6,6 +6,11 @@ const QR_ON: &str = "QRCENA1";
 
 /// Represents the `QR Code` serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum QRCode {
     DefaultSettings,
     Off,
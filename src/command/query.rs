@@ -1,6 +1,11 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
 const DEFAULT_VALUE: &str = "^";
 const CURRENT_VALUE: &str = "?";
 const RANGE_VALUE: &str = "*";
+const GROUP_LEN: usize = 3;
 
 /// Represents special characters used to modify other serial commands to query command values.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -35,6 +40,58 @@ impl Default for QueryCommand {
     }
 }
 
+/// Represents the set of menu commands covered by a wildcard query.
+///
+/// A [QueryCommand] in place of the sub-tag queries every command in a tag group (e.g. `BEP?`),
+/// and in place of the tag queries the entire menu (e.g. `?`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum QueryScope {
+    /// Queries every command in the three-character tag group.
+    Group(String),
+    /// Queries every command in the menu.
+    #[default]
+    Menu,
+}
+
+impl QueryScope {
+    /// Creates a new [QueryScope].
+    pub const fn new() -> Self {
+        Self::Menu
+    }
+
+    /// Creates a [QueryScope] for a tag group, e.g. `BEP` or `232`.
+    pub fn try_group(group: &str) -> Result<Self> {
+        if group.len() == GROUP_LEN
+            && group
+                .bytes()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == b'_')
+        {
+            Ok(Self::Group(group.into()))
+        } else {
+            Err(Error::InvalidVariant)
+        }
+    }
+
+    /// Gets the ASCII-encoded serial command for the [QueryScope] with a [QueryCommand].
+    pub fn command(&self, query: QueryCommand) -> String {
+        match self {
+            Self::Group(group) => format!("{group}{}", query.command()),
+            Self::Menu => query.command().into(),
+        }
+    }
+}
+
+impl TryFrom<&str> for QueryScope {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            "" => Ok(Self::Menu),
+            v => Self::try_group(v),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,5 +108,21 @@ mod tests {
         .for_each(|(cmd, exp_ascii)| {
             assert_eq!(cmd.command(), exp_ascii);
         });
+
+        let group = QueryScope::try_group("BEP").unwrap();
+        assert_eq!(group.command(QueryCommand::CurrentValue), "BEP?");
+        assert_eq!(
+            QueryScope::try_from("232"),
+            Ok(QueryScope::Group("232".into()))
+        );
+        assert_eq!(QueryScope::new().command(QueryCommand::DefaultValue), "^");
+        assert_eq!(QueryScope::try_from(""), Ok(QueryScope::Menu));
+    }
+
+    #[test]
+    fn test_invalid() {
+        ["BE", "BEPB", "bep", "BE?"].into_iter().for_each(|val| {
+            assert_eq!(QueryScope::try_from(val), Err(Error::InvalidVariant));
+        });
     }
 }
@@ -0,0 +1,234 @@
+//! Types for `Read Timing` and transmit delay serial commands.
+
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::QueryCommand;
+
+const GOOD_READ_DELAY: &str = "DLYGRD";
+const REREAD_DELAY: &str = "DLYRRD";
+const SAME_CODE_DELAY: &str = "DLYSCD";
+const INTERCHARACTER_DELAY: &str = "DLYCHR";
+const INTERFUNCTION_DELAY: &str = "DLYFNC";
+const INTERMESSAGE_DELAY: &str = "DLY_MG";
+
+/// Maximum delay (in milliseconds) for [GoodReadDelay].
+pub const MAX_GOOD_READ_DELAY: u32 = 30_000;
+/// Maximum delay (in milliseconds) for [RereadDelay].
+pub const MAX_REREAD_DELAY: u32 = 30_000;
+/// Maximum delay (in milliseconds) for [SameCodeDelay].
+pub const MAX_SAME_CODE_DELAY: u32 = 30_000;
+/// Step size (in milliseconds) of the read delays.
+pub const READ_DELAY_STEP: u32 = 1;
+/// Step size (in milliseconds) of the transmit delays.
+pub const TRANSMIT_DELAY_STEP: u32 = 5;
+/// Maximum delay (in milliseconds) for [IntercharacterDelay], [InterfunctionDelay] and
+/// [IntermessageDelay].
+pub const MAX_TRANSMIT_DELAY: u32 = 495;
+
+macro_rules! delay_command {
+    (
+        $(#[$doc:meta])+
+        $cmd:ident: $tag:ident, max: $max:ident, step: $step:ident, default: $default:literal$(,)?
+    ) => {
+        paste::paste! {
+            $(#[$doc])+
+            #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            pub struct $cmd {
+                ms: u32,
+            }
+
+            impl $cmd {
+                #[doc = "Creates a new [" $cmd "] with the device default delay."]
+                pub const fn new() -> Self {
+                    Self { ms: $default }
+                }
+
+                #[doc = "Gets the ASCII serial command code for [" $cmd "]."]
+                pub fn command(&self) -> String {
+                    format!("{}{}", $tag, self.ms / $step)
+                }
+
+                #[doc = "Gets the ASCII serial command code to query the [" $cmd "] settings."]
+                pub fn query(query: QueryCommand) -> String {
+                    format!("{}{}", $tag, query.command())
+                }
+
+                #[doc = "Attempts to convert a [`u32`] number of milliseconds into a [" $cmd "]."]
+                ///
+                #[doc = "**NOTE**: `ms` must be below [" $max "] number of milliseconds, and a multiple of the delay step."]
+                pub const fn try_from_ms(ms: u32) -> Result<Self> {
+                    if ms > $max {
+                        Err(Error::out_of_range(ms as usize, 0, $max as usize))
+                    } else if !ms.is_multiple_of($step) {
+                        Err(Error::InvalidValue(ms as usize))
+                    } else {
+                        Ok(Self { ms })
+                    }
+                }
+
+                #[doc = "Converts a [" $cmd "] into a [`u32`] number of milliseconds."]
+                pub const fn into_ms(self) -> u32 {
+                    self.ms
+                }
+            }
+
+            impl Default for $cmd {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl TryFrom<&str> for $cmd {
+                type Error = Error;
+
+                fn try_from(val: &str) -> Result<Self> {
+                    let i = val.find($tag).ok_or(Error::InvalidVariant)?;
+                    let pos = i + $tag.len();
+
+                    let steps = val
+                        [pos..]
+                        .parse::<u32>()
+                        .map_err(|_| Error::invalid_data($tag, &val[pos..], pos))?;
+
+                    Self::try_from_ms(steps.saturating_mul($step)).map_err(|err| err.with_tag($tag))
+                }
+            }
+
+            impl TryFrom<String> for $cmd {
+                type Error = Error;
+
+                fn try_from(val: String) -> Result<Self> {
+                    val.as_str().try_into()
+                }
+            }
+
+            serde_value!($cmd, u32, Self::try_from_ms, |c| c.into_ms());
+        }
+    };
+}
+
+delay_command! {
+    /// Represents the `Good Read Delay` serial command.
+    ///
+    /// Delay after a good read before another read can be triggered.
+    GoodReadDelay: GOOD_READ_DELAY, max: MAX_GOOD_READ_DELAY, step: READ_DELAY_STEP, default: 0,
+}
+
+delay_command! {
+    /// Represents the `Reread Delay` serial command.
+    ///
+    /// Delay before the same barcode can be read again in presentation modes.
+    RereadDelay: REREAD_DELAY, max: MAX_REREAD_DELAY, step: READ_DELAY_STEP, default: 750,
+}
+
+delay_command! {
+    /// Represents the `Same Code Delay` serial command.
+    ///
+    /// Delay before an identical barcode is transmitted again, even after a different read.
+    SameCodeDelay: SAME_CODE_DELAY, max: MAX_SAME_CODE_DELAY, step: READ_DELAY_STEP, default: 750,
+}
+
+delay_command! {
+    /// Represents the `Intercharacter Delay` serial command.
+    ///
+    /// Delay between each transmitted character, in 5 ms steps.
+    IntercharacterDelay: INTERCHARACTER_DELAY,
+    max: MAX_TRANSMIT_DELAY, step: TRANSMIT_DELAY_STEP, default: 0,
+}
+
+delay_command! {
+    /// Represents the `Interfunction Delay` serial command.
+    ///
+    /// Delay between the segments of a message (e.g. around function codes), in 5 ms steps.
+    InterfunctionDelay: INTERFUNCTION_DELAY,
+    max: MAX_TRANSMIT_DELAY, step: TRANSMIT_DELAY_STEP, default: 0,
+}
+
+delay_command! {
+    /// Represents the `Intermessage Delay` serial command.
+    ///
+    /// Delay between transmitted messages, in 5 ms steps.
+    IntermessageDelay: INTERMESSAGE_DELAY,
+    max: MAX_TRANSMIT_DELAY, step: TRANSMIT_DELAY_STEP, default: 0,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        (0..=MAX_GOOD_READ_DELAY).step_by(250).for_each(|ms| {
+            let exp_cmd = GoodReadDelay { ms };
+            let exp_ascii_cmd = format!("{GOOD_READ_DELAY}{ms}");
+
+            assert_eq!(GoodReadDelay::try_from_ms(ms), Ok(exp_cmd));
+            assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+            assert_eq!(GoodReadDelay::try_from(exp_ascii_cmd), Ok(exp_cmd));
+        });
+
+        (0..=MAX_TRANSMIT_DELAY)
+            .step_by(TRANSMIT_DELAY_STEP as usize)
+            .for_each(|ms| {
+                let exp_cmd = IntercharacterDelay { ms };
+                let exp_ascii_cmd = format!("{INTERCHARACTER_DELAY}{}", ms / TRANSMIT_DELAY_STEP);
+
+                assert_eq!(IntercharacterDelay::try_from_ms(ms), Ok(exp_cmd));
+                assert_eq!(exp_cmd.command(), exp_ascii_cmd);
+                assert_eq!(IntercharacterDelay::try_from(exp_ascii_cmd), Ok(exp_cmd));
+            });
+
+        assert_eq!(RereadDelay::new().command(), "DLYRRD750");
+        assert_eq!(
+            SameCodeDelay::try_from("DLYSCD1000").map(SameCodeDelay::into_ms),
+            Ok(1000)
+        );
+        assert_eq!(
+            InterfunctionDelay::try_from("DLYFNC20").map(InterfunctionDelay::into_ms),
+            Ok(100)
+        );
+        assert_eq!(
+            IntermessageDelay::try_from_ms(50).map(|c| c.command()),
+            Ok("DLY_MG10".into())
+        );
+        assert_eq!(RereadDelay::query(QueryCommand::CurrentValue), "DLYRRD?");
+        assert_eq!(
+            IntermessageDelay::query(QueryCommand::RangeValue),
+            "DLY_MG*"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            GoodReadDelay::try_from_ms(MAX_GOOD_READ_DELAY + 1),
+            Err(Error::out_of_range(
+                MAX_GOOD_READ_DELAY as usize + 1,
+                0,
+                MAX_GOOD_READ_DELAY as usize
+            ))
+        );
+        assert_eq!(
+            IntercharacterDelay::try_from_ms(7),
+            Err(Error::InvalidValue(7))
+        );
+        assert_eq!(
+            IntercharacterDelay::try_from_ms(500),
+            Err(Error::out_of_range(500, 0, MAX_TRANSMIT_DELAY as usize))
+        );
+        assert_eq!(
+            IntermessageDelay::try_from("DLY_MG100"),
+            Err(Error::out_of_range(500, 0, MAX_TRANSMIT_DELAY as usize).with_tag("DLY_MG"))
+        );
+        assert_eq!(
+            RereadDelay::try_from("DLYRRD"),
+            Err(Error::invalid_data("DLYRRD", "", 6))
+        );
+        assert_eq!(
+            RereadDelay::try_from("DLYGRD100"),
+            Err(Error::InvalidVariant)
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::result::{Error, Result};
+
+use super::{Storage, MENU_PREFIX};
+
+/// Acknowledges a valid menu command.
+pub const ACK: u8 = 0x06;
+/// Rejects a menu command with a valid tag and sub-tag, but an invalid data field.
+pub const ENQ: u8 = 0x05;
+/// Rejects a menu command with an invalid tag or sub-tag.
+pub const NAK: u8 = 0x15;
+
+/// Represents the status byte the device appends to each menu command in a reply.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplyStatus {
+    Ack,
+    Enq,
+    Nak,
+}
+
+impl ReplyStatus {
+    /// Creates a new [ReplyStatus].
+    pub const fn new() -> Self {
+        Self::Ack
+    }
+
+    /// Gets the ASCII control character for the [ReplyStatus].
+    pub const fn byte(&self) -> u8 {
+        match self {
+            Self::Ack => ACK,
+            Self::Enq => ENQ,
+            Self::Nak => NAK,
+        }
+    }
+
+    /// Gets the [ReplyStatus] for an ASCII control character, if valid.
+    pub const fn from_byte(val: u8) -> Option<Self> {
+        match val {
+            ACK => Some(Self::Ack),
+            ENQ => Some(Self::Enq),
+            NAK => Some(Self::Nak),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ReplyStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents the reply to a single command in a [MenuReply].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReplyEntry {
+    data: String,
+    status: ReplyStatus,
+}
+
+impl ReplyEntry {
+    /// Gets the echoed command, including any queried value.
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    /// Gets the [ReplyStatus] of the command.
+    pub const fn status(&self) -> ReplyStatus {
+        self.status
+    }
+}
+
+/// Represents the device reply to a menu command frame.
+///
+/// The device echoes each command, followed by a status byte, and terminates the reply with the
+/// [Storage] terminator of the request.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MenuReply {
+    entries: Vec<ReplyEntry>,
+    storage: Storage,
+}
+
+impl MenuReply {
+    /// Gets the length of a complete reply at the start of `val`, if any.
+    ///
+    /// A reply is complete once a status byte is followed by a [Storage] terminator.
+    pub fn complete_len(val: &[u8]) -> Option<usize> {
+        val.windows(2)
+            .position(|w| {
+                ReplyStatus::from_byte(w[0]).is_some() && Storage::from_terminator(w[1]).is_some()
+            })
+            .map(|i| i + 2)
+    }
+
+    /// Parses a [MenuReply] from the bytes received from the device.
+    pub fn parse(val: &[u8]) -> Result<Self> {
+        let val = val.strip_prefix(MENU_PREFIX.as_bytes()).unwrap_or(val);
+        let len = Self::complete_len(val).ok_or(Error::InvalidVariant)?;
+        let storage = Storage::from_terminator(val[len - 1]).ok_or(Error::InvalidVariant)?;
+
+        let mut entries = Vec::new();
+        let mut start = 0;
+
+        for (i, &c) in val[..len - 1].iter().enumerate() {
+            if let Some(status) = ReplyStatus::from_byte(c) {
+                // commands in a multi-command reply are separated by `;` or `,`
+                let data = val[start..i]
+                    .strip_prefix(b";")
+                    .or_else(|| val[start..i].strip_prefix(b","))
+                    .unwrap_or(&val[start..i]);
+
+                entries.push(ReplyEntry {
+                    data: String::from_utf8_lossy(data).into_owned(),
+                    status,
+                });
+                start = i + 1;
+            }
+        }
+
+        Ok(Self { entries, storage })
+    }
+
+    /// Gets the list of [ReplyEntry] items.
+    pub fn entries(&self) -> &[ReplyEntry] {
+        &self.entries
+    }
+
+    /// Gets the [Storage] terminator of the reply.
+    pub const fn storage(&self) -> Storage {
+        self.storage
+    }
+
+    /// Gets the overall [ReplyStatus], the most severe status of all entries.
+    pub fn status(&self) -> ReplyStatus {
+        self.entries
+            .iter()
+            .map(ReplyEntry::status)
+            .fold(ReplyStatus::Ack, |acc, s| match (acc, s) {
+                (ReplyStatus::Nak, _) | (_, ReplyStatus::Nak) => ReplyStatus::Nak,
+                (ReplyStatus::Enq, _) | (_, ReplyStatus::Enq) => ReplyStatus::Enq,
+                _ => ReplyStatus::Ack,
+            })
+    }
+
+    /// Gets whether every command in the reply was acknowledged.
+    pub fn is_ack(&self) -> bool {
+        self.status() == ReplyStatus::Ack
+    }
+
+    /// Checks that every command in the reply was acknowledged.
+    ///
+    /// Returns a [Rejected](Error::Rejected) error for the first command that was not.
+    pub fn ensure_ack(&self) -> Result<()> {
+        match self.entries.iter().find(|e| e.status != ReplyStatus::Ack) {
+            Some(entry) => Err(Error::Rejected {
+                command: entry.data.clone(),
+                status: entry.status,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for MenuReply {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        Self::parse(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let reply = MenuReply::parse(b"232BAD5\x06.").unwrap();
+
+        assert!(reply.is_ack());
+        assert_eq!(reply.ensure_ack(), Ok(()));
+        assert_eq!(reply.storage(), Storage::Permanent);
+        assert_eq!(reply.entries()[0].data(), "232BAD5");
+
+        let reply = MenuReply::parse(b"\x16M\rAIMENA1\x06;PDFENA9\x05;XYZABC\x15!").unwrap();
+
+        assert_eq!(reply.status(), ReplyStatus::Nak);
+        assert_eq!(reply.storage(), Storage::Temporary);
+        assert_eq!(
+            reply
+                .entries()
+                .iter()
+                .map(|e| (e.data(), e.status()))
+                .collect::<Vec<_>>(),
+            [
+                ("AIMENA1", ReplyStatus::Ack),
+                ("PDFENA9", ReplyStatus::Enq),
+                ("XYZABC", ReplyStatus::Nak),
+            ]
+        );
+        assert_eq!(
+            reply.ensure_ack(),
+            Err(Error::Rejected {
+                command: "PDFENA9".into(),
+                status: ReplyStatus::Enq,
+            })
+        );
+
+        // `.` in the reply data does not terminate the reply
+        let data = b"REVINF\r\nSoftware Revision: 31205.01\x06.trailing";
+        assert_eq!(MenuReply::complete_len(data), Some(data.len() - 8));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(MenuReply::parse(b""), Err(Error::InvalidVariant));
+        assert_eq!(MenuReply::parse(b"232BAD5."), Err(Error::InvalidVariant));
+        assert_eq!(MenuReply::parse(b"232BAD5\x06"), Err(Error::InvalidVariant));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::result::{Error, Result};
+
+const RESET: &str = "RESET";
+
+/// Represents the `Reset` serial command.
+///
+/// The device reboots after acknowledging the command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reset;
+
+impl Reset {
+    /// Creates a new [Reset].
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Gets the ASCII serial command code for [Reset].
+    pub const fn command(&self) -> &str {
+        RESET
+    }
+}
+
+impl Default for Reset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for Reset {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(RESET) => Ok(Self::new()),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let cmd = Reset::new();
+        let exp_ascii_cmd = RESET;
+
+        assert_eq!(cmd.command(), exp_ascii_cmd);
+        assert_eq!(Reset::try_from(exp_ascii_cmd), Ok(cmd));
+    }
+}
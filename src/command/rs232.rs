@@ -0,0 +1,13 @@
+//! Types related to the RS232 interface configuration.
+
+mod ack_nak;
+mod baud_rate;
+mod rts_cts;
+mod word_length;
+mod xon_xoff;
+
+pub use ack_nak::*;
+pub use baud_rate::*;
+pub use rts_cts::*;
+pub use word_length::*;
+pub use xon_xoff::*;
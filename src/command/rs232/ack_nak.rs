@@ -0,0 +1,67 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "232ACK0";
+const ON: &str = "232ACK1";
+
+/// Represents the `RS232 ACK/NAK` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AckNak {
+    /// Data is transmitted without waiting for a host response.
+    Off,
+    /// The device waits for an `ACK` or `NAK` from the host after each transmission.
+    On,
+}
+
+impl AckNak {
+    /// Creates a new [AckNak].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [AckNak].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+}
+
+impl Default for AckNak {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for AckNak {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [AckNak::Off, AckNak::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(AckNak::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
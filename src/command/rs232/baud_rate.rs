@@ -0,0 +1,151 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+const BAUD_RATE: &str = "232BAD";
+
+/// Represents the `RS232 Baud Rate` serial command.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BaudRate {
+    Baud300 = 0,
+    Baud600 = 1,
+    Baud1200 = 2,
+    Baud2400 = 3,
+    Baud4800 = 4,
+    Baud9600 = 5,
+    Baud19200 = 6,
+    Baud38400 = 7,
+    Baud57600 = 8,
+    Baud115200 = 9,
+}
+
+impl BaudRate {
+    /// Creates a new [BaudRate].
+    pub const fn new() -> Self {
+        Self::Baud115200
+    }
+
+    /// Gets the ASCII serial command code for [BaudRate].
+    pub fn command(&self) -> String {
+        format!("{BAUD_RATE}{}", *self as u8)
+    }
+
+    /// Gets the baud rate in bits per second.
+    pub const fn baud(&self) -> u32 {
+        match self {
+            Self::Baud300 => 300,
+            Self::Baud600 => 600,
+            Self::Baud1200 => 1200,
+            Self::Baud2400 => 2400,
+            Self::Baud4800 => 4800,
+            Self::Baud9600 => 9600,
+            Self::Baud19200 => 19200,
+            Self::Baud38400 => 38400,
+            Self::Baud57600 => 57600,
+            Self::Baud115200 => 115200,
+        }
+    }
+
+    /// Attempts to convert a baud rate in bits per second into a [BaudRate].
+    pub const fn try_from_baud(baud: u32) -> Result<Self> {
+        match baud {
+            300 => Ok(Self::Baud300),
+            600 => Ok(Self::Baud600),
+            1200 => Ok(Self::Baud1200),
+            2400 => Ok(Self::Baud2400),
+            4800 => Ok(Self::Baud4800),
+            9600 => Ok(Self::Baud9600),
+            19200 => Ok(Self::Baud19200),
+            38400 => Ok(Self::Baud38400),
+            57600 => Ok(Self::Baud57600),
+            115200 => Ok(Self::Baud115200),
+            _ if baud < 300 || baud > 115200 => {
+                Err(Error::out_of_range(baud as usize, 300, 115200))
+            }
+            _ => Err(Error::InvalidValue(baud as usize)),
+        }
+    }
+
+    /// Attempts to convert a [`u8`] menu setting into a [BaudRate].
+    pub const fn try_from_u8(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::Baud300),
+            1 => Ok(Self::Baud600),
+            2 => Ok(Self::Baud1200),
+            3 => Ok(Self::Baud2400),
+            4 => Ok(Self::Baud4800),
+            5 => Ok(Self::Baud9600),
+            6 => Ok(Self::Baud19200),
+            7 => Ok(Self::Baud38400),
+            8 => Ok(Self::Baud57600),
+            9 => Ok(Self::Baud115200),
+            _ => Err(Error::out_of_range(val as usize, 0, 9)),
+        }
+    }
+}
+
+impl Default for BaudRate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for BaudRate {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(BAUD_RATE).ok_or(Error::InvalidVariant)?;
+        let pos = i + BAUD_RATE.len();
+
+        val.get(pos..pos + 1)
+            .ok_or_else(|| Error::invalid_data(BAUD_RATE, &val[pos..], pos))?
+            .parse::<u8>()
+            .map_err(|_| Error::invalid_data(BAUD_RATE, &val[pos..], pos))
+            .and_then(Self::try_from_u8)
+            .map_err(|err| err.with_tag(BAUD_RATE))
+    }
+}
+
+serde_value!(BaudRate, u32, Self::try_from_baud, Self::baud);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        (0..=9u8).for_each(|v| {
+            let cmd = BaudRate::try_from_u8(v).unwrap();
+            let exp_ascii_cmd = format!("{BAUD_RATE}{v}");
+
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(BaudRate::try_from(exp_ascii_cmd.as_str()), Ok(cmd));
+            assert_eq!(BaudRate::try_from_baud(cmd.baud()), Ok(cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            BaudRate::try_from_u8(10),
+            Err(Error::out_of_range(10, 0, 9))
+        );
+        assert_eq!(
+            BaudRate::try_from_baud(230400),
+            Err(Error::out_of_range(230400, 300, 115200))
+        );
+        assert_eq!(
+            BaudRate::try_from_baud(14400),
+            Err(Error::InvalidValue(14400))
+        );
+        assert_eq!(
+            BaudRate::try_from("232BAD"),
+            Err(Error::invalid_data("232BAD", "", 6))
+        );
+        assert_eq!(
+            BaudRate::try_from("232BADx"),
+            Err(Error::invalid_data("232BAD", "x", 6))
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "232CTS0";
+const FLOW_CONTROL: &str = "232CTS1";
+const TWO_DIRECTION: &str = "232CTS2";
+const FLOW_CONTROL_TIMEOUT: &str = "232CTS3";
+const RTS_ACTIVE: &str = "232CTS4";
+
+/// Represents the `RS232 Handshaking: RTS/CTS` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum RtsCts {
+    /// Hardware flow control off.
+    Off,
+    /// Device asserts RTS and waits for CTS before transmitting, with no timeout.
+    FlowControl,
+    /// Two-direction flow control, the device also signals when it can receive.
+    TwoDirection,
+    /// Device asserts RTS and waits for CTS before transmitting, with a timeout.
+    FlowControlTimeout,
+    /// RTS is held active.
+    RtsActive,
+}
+
+impl RtsCts {
+    /// Creates a new [RtsCts].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [RtsCts].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::FlowControl => FLOW_CONTROL,
+            Self::TwoDirection => TWO_DIRECTION,
+            Self::FlowControlTimeout => FLOW_CONTROL_TIMEOUT,
+            Self::RtsActive => RTS_ACTIVE,
+        }
+    }
+}
+
+impl Default for RtsCts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for RtsCts {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(FLOW_CONTROL) => Ok(Self::FlowControl),
+            v if v.contains(TWO_DIRECTION) => Ok(Self::TwoDirection),
+            v if v.contains(FLOW_CONTROL_TIMEOUT) => Ok(Self::FlowControlTimeout),
+            v if v.contains(RTS_ACTIVE) => Ok(Self::RtsActive),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            RtsCts::Off,
+            RtsCts::FlowControl,
+            RtsCts::TwoDirection,
+            RtsCts::FlowControlTimeout,
+            RtsCts::RtsActive,
+        ]
+        .into_iter()
+        .zip([
+            OFF,
+            FLOW_CONTROL,
+            TWO_DIRECTION,
+            FLOW_CONTROL_TIMEOUT,
+            RTS_ACTIVE,
+        ])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(RtsCts::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+const WORD_LENGTH: &str = "232WRD";
+
+/// Represents the number of data bits in an RS232 character.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum DataBits {
+    Seven,
+    Eight,
+}
+
+/// Represents the number of stop bits in an RS232 character.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum StopBits {
+    One,
+    Two,
+}
+
+/// Represents the parity of an RS232 character.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Parity {
+    None,
+    Even,
+    Odd,
+    Mark,
+}
+
+/// Represents the `RS232 Word Length: Data Bits, Stop Bits, and Parity` serial command.
+///
+/// The device only supports a subset of combinations, see [WordLength::try_new].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "WordLengthFields")
+)]
+pub struct WordLength {
+    data_bits: DataBits,
+    stop_bits: StopBits,
+    parity: Parity,
+}
+
+impl WordLength {
+    /// Creates a new [WordLength] (8 data bits, 1 stop bit, no parity).
+    pub const fn new() -> Self {
+        Self {
+            data_bits: DataBits::Eight,
+            stop_bits: StopBits::One,
+            parity: Parity::None,
+        }
+    }
+
+    /// Attempts to create a new [WordLength].
+    ///
+    /// Mark parity is only supported with 8 data bits and 1 stop bit, and 2 stop bits are only
+    /// supported with 7 data bits.
+    pub const fn try_new(data_bits: DataBits, stop_bits: StopBits, parity: Parity) -> Result<Self> {
+        let cmd = Self {
+            data_bits,
+            stop_bits,
+            parity,
+        };
+
+        match cmd.to_u8() {
+            Some(_) => Ok(cmd),
+            None => Err(Error::InvalidVariant),
+        }
+    }
+
+    /// Gets the [DataBits] for [WordLength].
+    pub const fn data_bits(&self) -> DataBits {
+        self.data_bits
+    }
+
+    /// Gets the [StopBits] for [WordLength].
+    pub const fn stop_bits(&self) -> StopBits {
+        self.stop_bits
+    }
+
+    /// Gets the [Parity] for [WordLength].
+    pub const fn parity(&self) -> Parity {
+        self.parity
+    }
+
+    /// Gets the ASCII serial command code for [WordLength].
+    pub fn command(&self) -> String {
+        // combination is validated on construction
+        format!("{WORD_LENGTH}{}", self.to_u8().unwrap_or_default())
+    }
+
+    /// Attempts to convert a [`u8`] menu setting into a [WordLength].
+    pub const fn try_from_u8(val: u8) -> Result<Self> {
+        let (data_bits, stop_bits, parity) = match val {
+            0 => (DataBits::Seven, StopBits::One, Parity::Even),
+            1 => (DataBits::Seven, StopBits::One, Parity::None),
+            2 => (DataBits::Seven, StopBits::One, Parity::Odd),
+            3 => (DataBits::Seven, StopBits::Two, Parity::Even),
+            4 => (DataBits::Seven, StopBits::Two, Parity::None),
+            5 => (DataBits::Seven, StopBits::Two, Parity::Odd),
+            6 => (DataBits::Eight, StopBits::One, Parity::Even),
+            7 => (DataBits::Eight, StopBits::One, Parity::None),
+            8 => (DataBits::Eight, StopBits::One, Parity::Odd),
+            14 => (DataBits::Eight, StopBits::One, Parity::Mark),
+            9..=13 => return Err(Error::InvalidValue(val as usize)),
+            _ => return Err(Error::out_of_range(val as usize, 0, 14)),
+        };
+
+        Ok(Self {
+            data_bits,
+            stop_bits,
+            parity,
+        })
+    }
+
+    const fn to_u8(self) -> Option<u8> {
+        let base = match (self.data_bits, self.stop_bits) {
+            (DataBits::Seven, StopBits::One) => 0,
+            (DataBits::Seven, StopBits::Two) => 3,
+            (DataBits::Eight, StopBits::One) => 6,
+            (DataBits::Eight, StopBits::Two) => return None,
+        };
+
+        match (self.parity, base) {
+            (Parity::Even, _) => Some(base),
+            (Parity::None, _) => Some(base + 1),
+            (Parity::Odd, _) => Some(base + 2),
+            (Parity::Mark, 6) => Some(14),
+            (Parity::Mark, _) => None,
+        }
+    }
+}
+
+impl Default for WordLength {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WordLengthFields {
+    data_bits: DataBits,
+    stop_bits: StopBits,
+    parity: Parity,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<WordLengthFields> for WordLength {
+    type Error = Error;
+
+    fn try_from(val: WordLengthFields) -> Result<Self> {
+        Self::try_new(val.data_bits, val.stop_bits, val.parity)
+    }
+}
+
+impl TryFrom<&str> for WordLength {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        let i = val.find(WORD_LENGTH).ok_or(Error::InvalidVariant)?;
+        let pos = i + WORD_LENGTH.len();
+        let rem = &val[pos..];
+        let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
+
+        rem[..end]
+            .parse::<u8>()
+            .map_err(|_| Error::invalid_data(WORD_LENGTH, rem, pos))
+            .and_then(Self::try_from_u8)
+            .map_err(|err| err.with_tag(WORD_LENGTH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        (0..=8u8).chain([14]).for_each(|v| {
+            let cmd = WordLength::try_from_u8(v).unwrap();
+            let exp_ascii_cmd = format!("{WORD_LENGTH}{v}");
+
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(WordLength::try_from(exp_ascii_cmd.as_str()), Ok(cmd));
+            assert_eq!(
+                WordLength::try_new(cmd.data_bits(), cmd.stop_bits(), cmd.parity()),
+                Ok(cmd)
+            );
+        });
+
+        assert_eq!(WordLength::new().command(), "232WRD7");
+    }
+
+    #[test]
+    fn test_invalid() {
+        (9..=13u8).for_each(|v| {
+            assert_eq!(
+                WordLength::try_from_u8(v),
+                Err(Error::InvalidValue(v as usize))
+            );
+        });
+        (15..=u8::MAX).for_each(|v| {
+            assert_eq!(
+                WordLength::try_from_u8(v),
+                Err(Error::out_of_range(v as usize, 0, 14))
+            );
+        });
+
+        assert_eq!(
+            WordLength::try_new(DataBits::Eight, StopBits::Two, Parity::None),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            WordLength::try_new(DataBits::Seven, StopBits::One, Parity::Mark),
+            Err(Error::InvalidVariant)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::result::{Error, Result};
+
+const OFF: &str = "232XON0";
+const ON: &str = "232XON1";
+
+/// Represents the `RS232 XON/XOFF` serial command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum XonXoff {
+    /// Software flow control off.
+    Off,
+    /// Transmission pauses on XOFF (`DC3`), and resumes on XON (`DC1`) from the host.
+    On,
+}
+
+impl XonXoff {
+    /// Creates a new [XonXoff].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [XonXoff].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Off => OFF,
+            Self::On => ON,
+        }
+    }
+}
+
+impl Default for XonXoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for XonXoff {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(OFF) => Ok(Self::Off),
+            v if v.contains(ON) => Ok(Self::On),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [XonXoff::Off, XonXoff::On]
+            .into_iter()
+            .zip([OFF, ON])
+            .for_each(|(cmd, exp_ascii_cmd)| {
+                assert_eq!(cmd.command(), exp_ascii_cmd);
+                assert_eq!(XonXoff::try_from(exp_ascii_cmd), Ok(cmd));
+            });
+    }
+}
@@ -30,7 +30,11 @@ impl SerialTriggerMode {
         if ms <= MAX_SERIAL_TRIGGER {
             Ok(Self { ms })
         } else {
-            Err(Error::InvalidValue(ms as usize))
+            Err(Error::out_of_range(
+                ms as usize,
+                0,
+                MAX_SERIAL_TRIGGER as usize,
+            ))
         }
     }
 
@@ -51,12 +55,12 @@ impl TryFrom<&str> for SerialTriggerMode {
 
     fn try_from(val: &str) -> Result<Self> {
         let i = val.find(SERIAL_TRIGGER).ok_or(Error::InvalidVariant)?;
+        let pos = i + SERIAL_TRIGGER.len();
 
-        val.get(i + SERIAL_TRIGGER.len()..)
-            .ok_or(Error::InvalidVariant)?
+        val[pos..]
             .parse::<u32>()
             .map(|ms| Self { ms })
-            .map_err(|_| Error::InvalidVariant)
+            .map_err(|_| Error::invalid_data(SERIAL_TRIGGER, &val[pos..], pos))
     }
 }
 
@@ -68,6 +72,8 @@ impl TryFrom<String> for SerialTriggerMode {
     }
 }
 
+serde_value!(SerialTriggerMode, u32, Self::try_from_ms, |c| c.into_ms());
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,6 +4,7 @@ const SOFTWARE_REVISION: &str = "REVINF";
 
 /// Represents the `Software Revision` serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SoftwareRevision;
 
 impl SoftwareRevision {
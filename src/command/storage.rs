@@ -0,0 +1,62 @@
+const PERMANENT: &str = ".";
+const TEMPORARY: &str = "!";
+
+/// Represents the terminator of a menu command, selecting where the settings are stored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Storage {
+    /// Settings are saved to non-volatile memory, and kept after a power cycle.
+    Permanent,
+    /// Settings are only kept in volatile memory, and lost after a power cycle.
+    Temporary,
+}
+
+impl Storage {
+    /// Creates a new [Storage].
+    pub const fn new() -> Self {
+        Self::Permanent
+    }
+
+    /// Gets the ASCII-encoded terminator for the [Storage].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::Permanent => PERMANENT,
+            Self::Temporary => TEMPORARY,
+        }
+    }
+
+    /// Gets the [Storage] for a terminator byte, if valid.
+    pub const fn from_terminator(val: u8) -> Option<Self> {
+        match val {
+            b'.' => Some(Self::Permanent),
+            b'!' => Some(Self::Temporary),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Storage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [Storage::Permanent, Storage::Temporary]
+            .into_iter()
+            .zip([PERMANENT, TEMPORARY])
+            .for_each(|(cmd, exp_ascii)| {
+                assert_eq!(cmd.command(), exp_ascii);
+                assert_eq!(Storage::from_terminator(exp_ascii.as_bytes()[0]), Some(cmd));
+            });
+    }
+}
@@ -5,6 +5,11 @@ const ALL_SYM_ON: &str = "ALLENA1";
 
 /// Configure all barcode `Symbology` encodings.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum AllSymbologies {
     Off = 0,
     On = 1,
@@ -0,0 +1,140 @@
+use alloc::string::String;
+
+use crate::result::{Error, Result};
+
+use super::data_edit::{Affix, EditChar, Prefix, SymbologyScope};
+
+/// Represents the `Code ID` / `AIM ID` prefix transmission settings.
+///
+/// A shorthand for the [Prefix] commands for all symbologies, see
+/// [ReadFormat](crate::decode::ReadFormat) for parsing reads with the configured prefix.
+///
+/// **NOTE**: the device has no command to remove single prefix characters, so
+/// [Off](Self::Off) clears *every* prefix for all symbologies (`PRECL299`), including custom
+/// prefixes. [Profile](crate::profile::Profile) sends it before any custom [Prefix], so those
+/// are added back.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SymbologyIdTransmit {
+    /// Transmit no symbology identifiers, clearing all prefixes.
+    Off,
+    /// Transmit the Honeywell `Code ID`.
+    CodeId,
+    /// Transmit the `AIM ID`.
+    AimId,
+    /// Transmit the Honeywell `Code ID`, followed by the `AIM ID`.
+    CodeIdAimId,
+}
+
+impl SymbologyIdTransmit {
+    /// Creates a new [SymbologyIdTransmit].
+    pub const fn new() -> Self {
+        Self::Off
+    }
+
+    /// Gets the ASCII serial command code for [SymbologyIdTransmit].
+    pub fn command(&self) -> String {
+        Prefix::from(*self).command()
+    }
+
+    /// Gets whether the `Code ID` is transmitted.
+    pub const fn code_id(&self) -> bool {
+        matches!(self, Self::CodeId | Self::CodeIdAimId)
+    }
+
+    /// Gets whether the `AIM ID` is transmitted.
+    pub const fn aim_id(&self) -> bool {
+        matches!(self, Self::AimId | Self::CodeIdAimId)
+    }
+
+    // Gets the prefix characters for the symbology identifiers.
+    const fn chars(&self) -> &'static [EditChar] {
+        match self {
+            Self::Off => &[],
+            Self::CodeId => &[EditChar::CodeId],
+            Self::AimId => &[EditChar::AimId],
+            Self::CodeIdAimId => &[EditChar::CodeId, EditChar::AimId],
+        }
+    }
+}
+
+impl Default for SymbologyIdTransmit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SymbologyIdTransmit> for Prefix {
+    fn from(val: SymbologyIdTransmit) -> Self {
+        match Affix::try_new(SymbologyScope::All, val.chars()) {
+            Ok(affix) => Self::Add(affix),
+            Err(_) => Self::Clear(SymbologyScope::All),
+        }
+    }
+}
+
+impl TryFrom<&Prefix> for SymbologyIdTransmit {
+    type Error = Error;
+
+    fn try_from(val: &Prefix) -> Result<Self> {
+        let ids = [Self::Off, Self::CodeId, Self::AimId, Self::CodeIdAimId];
+
+        match val {
+            Prefix::Clear(SymbologyScope::All) => Ok(Self::Off),
+            Prefix::Add(affix) if affix.scope() == SymbologyScope::All => ids
+                .into_iter()
+                .skip(1)
+                .find(|id| id.chars() == affix.chars())
+                .ok_or(Error::InvalidVariant),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+impl TryFrom<&str> for SymbologyIdTransmit {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        Self::try_from(&Prefix::try_from(val)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            SymbologyIdTransmit::Off,
+            SymbologyIdTransmit::CodeId,
+            SymbologyIdTransmit::AimId,
+            SymbologyIdTransmit::CodeIdAimId,
+        ]
+        .into_iter()
+        .zip([
+            "PRECL299",
+            "PREBK2995C80",
+            "PREBK2995C81",
+            "PREBK2995C805C81",
+        ])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(Prefix::from(cmd).command(), exp_ascii_cmd);
+            assert_eq!(SymbologyIdTransmit::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        ["PREBK29902", "PREBK2725C80", "PRECL272", "PRECA2"]
+            .into_iter()
+            .for_each(|val| {
+                assert!(SymbologyIdTransmit::try_from(val).is_err(), "{val}");
+            });
+    }
+}
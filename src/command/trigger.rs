@@ -7,6 +7,11 @@ const TRIGGER_DEACTIVATE: &str = "\x16U\x0d";
 
 /// Represents the `Trigger Activation` serial command.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Trigger {
     Activate,
     Deactivate,
@@ -0,0 +1,119 @@
+use crate::result::{Error, Result};
+
+const KEYBOARD_PC: &str = "PAP124";
+const KEYBOARD_MAC: &str = "PAP125";
+const KEYBOARD_JAPANESE: &str = "PAP134";
+const SERIAL_EMULATION: &str = "TRMUSB130";
+const HID_POS: &str = "PAP131";
+const SUREPOS_HANDHELD: &str = "PAP128";
+const SUREPOS_TABLETOP: &str = "PAP129";
+
+/// Represents the `USB Interface` terminal selection serial commands.
+///
+/// The device re-enumerates on the USB bus after the interface changes, so the host connection
+/// must be re-opened.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UsbInterface {
+    /// USB HID keyboard, PC layout.
+    KeyboardPc,
+    /// USB HID keyboard, Mac layout.
+    KeyboardMac,
+    /// USB HID keyboard, Japanese PC layout.
+    KeyboardJapanese,
+    /// USB serial (CDC ACM) emulation.
+    SerialEmulation,
+    /// USB HID Point-of-Sale.
+    HidPos,
+    /// IBM SurePOS, handheld.
+    SurePosHandheld,
+    /// IBM SurePOS, tabletop.
+    SurePosTabletop,
+}
+
+impl UsbInterface {
+    /// Creates a new [UsbInterface].
+    pub const fn new() -> Self {
+        Self::KeyboardPc
+    }
+
+    /// Gets the ASCII serial command code for [UsbInterface].
+    pub const fn command(&self) -> &str {
+        match self {
+            Self::KeyboardPc => KEYBOARD_PC,
+            Self::KeyboardMac => KEYBOARD_MAC,
+            Self::KeyboardJapanese => KEYBOARD_JAPANESE,
+            Self::SerialEmulation => SERIAL_EMULATION,
+            Self::HidPos => HID_POS,
+            Self::SurePosHandheld => SUREPOS_HANDHELD,
+            Self::SurePosTabletop => SUREPOS_TABLETOP,
+        }
+    }
+
+    /// Gets whether the [UsbInterface] emulates a keyboard (keyboard wedge).
+    pub const fn keyboard(&self) -> bool {
+        matches!(
+            self,
+            Self::KeyboardPc | Self::KeyboardMac | Self::KeyboardJapanese
+        )
+    }
+}
+
+impl Default for UsbInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for UsbInterface {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            v if v.contains(KEYBOARD_PC) => Ok(Self::KeyboardPc),
+            v if v.contains(KEYBOARD_MAC) => Ok(Self::KeyboardMac),
+            v if v.contains(KEYBOARD_JAPANESE) => Ok(Self::KeyboardJapanese),
+            v if v.contains(SERIAL_EMULATION) => Ok(Self::SerialEmulation),
+            v if v.contains(HID_POS) => Ok(Self::HidPos),
+            v if v.contains(SUREPOS_HANDHELD) => Ok(Self::SurePosHandheld),
+            v if v.contains(SUREPOS_TABLETOP) => Ok(Self::SurePosTabletop),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        [
+            UsbInterface::KeyboardPc,
+            UsbInterface::KeyboardMac,
+            UsbInterface::KeyboardJapanese,
+            UsbInterface::SerialEmulation,
+            UsbInterface::HidPos,
+            UsbInterface::SurePosHandheld,
+            UsbInterface::SurePosTabletop,
+        ]
+        .into_iter()
+        .zip([
+            KEYBOARD_PC,
+            KEYBOARD_MAC,
+            KEYBOARD_JAPANESE,
+            SERIAL_EMULATION,
+            HID_POS,
+            SUREPOS_HANDHELD,
+            SUREPOS_TABLETOP,
+        ])
+        .for_each(|(cmd, exp_ascii_cmd)| {
+            assert_eq!(cmd.command(), exp_ascii_cmd);
+            assert_eq!(UsbInterface::try_from(exp_ascii_cmd), Ok(cmd));
+        });
+    }
+}
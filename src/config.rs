@@ -0,0 +1,492 @@
+//! Device configuration snapshots, and diffs against a desired configuration.
+//!
+//! A [DeviceConfig] is read back from the device with [QueryCommand::CurrentValue] queries for
+//! every setting tag modeled by the library. Diffing it against the desired commands produces a
+//! [ConfigDiff], with the minimal set of commands to apply, and a human-readable report.
+
+use core::fmt;
+
+use alloc::{string::String, vec::Vec};
+
+use crate::command::*;
+use crate::profile::Profile;
+use crate::result::{Error, Result};
+
+// Setting tags queried for a device configuration snapshot.
+//
+// Settings encoded in the tag itself (e.g. operating modes and interfaces), multi-value settings
+// (e.g. prefix and suffix), and actions (e.g. image capture) have no current value to query.
+const SETTINGS: &[&str] = &[
+    "ALLENA", "QRCENA", "PDFENA", "POSTAL", "PLNCKX", "NETCKX", "AUSINT", "ECIDEC", "QRCDCP",
+    "AOSCGD", "PDCWIN", "PDCLED", "TRGPTO", "TRGSTO", "DLYGRD", "DLYRRD", "DLYSCD", "DLYCHR",
+    "DLYFNC", "DLY_MG", "BEPBEP", "BEPLED", "BEPPWR", "BEPBIP", "BEPLVL", "BEPFQ1", "BEPRPT",
+    "SCNAIM", "SCNDLY", "SCNLED", "PWRLDC", "HSTACK", "HSTDLY", "KBDCTY", "KBDSTY", "RMVFNC",
+    "KBDCAS", "232WRD", "232CTS", "232XON", "232ACK", "232BAD",
+];
+
+/// Gets the queryable setting tag for a [SerialCommand], if any.
+pub fn setting_tag(cmd: &SerialCommand) -> Option<&'static str> {
+    let cmd = cmd.command();
+    SETTINGS.iter().copied().find(|tag| cmd.starts_with(tag))
+}
+
+/// Represents a snapshot of the device configuration.
+///
+/// Snapshots are read back either tag by tag, with the [query frame](Self::query_frame), or in
+/// bulk, with a wildcard [dump frame](Self::dump_frame). Every reply entry is kept verbatim in
+/// [entries](Self::entries), and displayed one per line for archiving.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeviceConfig {
+    settings: Vec<(&'static str, SerialCommand)>,
+    unknown: Vec<String>,
+    entries: Vec<String>,
+}
+
+impl DeviceConfig {
+    /// Creates a new, empty [DeviceConfig].
+    pub const fn new() -> Self {
+        Self {
+            settings: Vec::new(),
+            unknown: Vec::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Gets the list of setting tags queried for a [DeviceConfig] snapshot.
+    pub fn tags() -> impl Iterator<Item = &'static str> {
+        SETTINGS.iter().copied()
+    }
+
+    /// Gets the menu command frame querying the current value of every setting tag.
+    pub fn query_frame() -> String {
+        let query = QueryCommand::CurrentValue.command();
+        let cmds = Self::tags()
+            .map(|tag| format!("{tag}{query}"))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        format!("{MENU_PREFIX}{cmds}{}", Storage::Permanent.command())
+    }
+
+    /// Gets the menu command frame for a wildcard query of every command in the [QueryScope].
+    ///
+    /// The [Storage] selects the settings table to dump.
+    pub fn dump_frame(scope: &QueryScope, query: QueryCommand, storage: Storage) -> String {
+        format!("{MENU_PREFIX}{}{}", scope.command(query), storage.command())
+    }
+
+    /// Parses a [DeviceConfig] from the reply to a [query](Self::query_frame) or
+    /// [dump](Self::dump_frame) frame.
+    ///
+    /// Multi-command entries are split on `;`, `,` and line breaks. Entries abbreviated to the
+    /// sub-tag, as in group dumps, are expanded with the tag of the previous entry, e.g. `LED0`
+    /// after `BEPBEP1`. Entries rejected by the device, or with tags or values the library
+    /// cannot parse, are kept in [unknown](Self::unknown).
+    pub fn parse(reply: &MenuReply) -> Self {
+        let mut cfg = Self::new();
+        let mut tag = "";
+
+        for entry in reply.entries() {
+            let ack = entry.status() == ReplyStatus::Ack;
+            entry_tokens(entry.data())
+                .for_each(|token| cfg.push_entry(&expand_entry(&mut tag, token), ack));
+        }
+
+        cfg
+    }
+
+    /// Gets the raw reply entries, in the order received from the device.
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Gets the list of parsed settings, with their tags.
+    pub fn settings(&self) -> &[(&'static str, SerialCommand)] {
+        &self.settings
+    }
+
+    /// Gets the raw reply entries that could not be parsed into a setting.
+    pub fn unknown(&self) -> &[String] {
+        &self.unknown
+    }
+
+    /// Gets the current value of the setting with the `tag`.
+    pub fn get(&self, tag: &str) -> Option<&SerialCommand> {
+        self.settings
+            .iter()
+            .find_map(|(t, cmd)| (*t == tag).then_some(cmd))
+    }
+
+    /// Builder function that sets the current value of a setting.
+    ///
+    /// Returns [InvalidVariant](Error::InvalidVariant) for commands without a queryable tag.
+    pub fn with_setting(mut self, val: SerialCommand) -> Result<Self> {
+        let tag = setting_tag(&val).ok_or(Error::InvalidVariant)?;
+        self.set(tag, val);
+        Ok(self)
+    }
+
+    /// Diffs the [DeviceConfig] against the `desired` commands, in order.
+    ///
+    /// Commands already matching the current value are skipped. Commands without a queryable
+    /// tag are always sent, and so are all commands following [Defaults::Activate] or a
+    /// [Reset], since the snapshot no longer reflects the device state after them.
+    pub fn diff<I>(&self, desired: I) -> ConfigDiff
+    where
+        I: IntoIterator<Item = SerialCommand>,
+    {
+        let mut state = self.clone();
+        let entries = desired
+            .into_iter()
+            .map(|cmd| {
+                let tag = setting_tag(&cmd);
+                let current = tag.and_then(|t| state.get(t)).copied();
+                if let Some(tag) = tag {
+                    state.set(tag, cmd);
+                }
+                if matches!(
+                    cmd,
+                    SerialCommand::Defaults(Defaults::Activate) | SerialCommand::Reset(_)
+                ) {
+                    state.settings.clear();
+                }
+
+                DiffEntry {
+                    tag,
+                    current,
+                    desired: cmd,
+                }
+            })
+            .collect();
+
+        ConfigDiff {
+            entries,
+            storage: Storage::new(),
+        }
+    }
+
+    /// Diffs the [DeviceConfig] against the [Profile] settings.
+    pub fn diff_profile(&self, profile: &Profile) -> ConfigDiff {
+        self.diff(profile.serial_commands())
+            .with_storage(profile.storage())
+    }
+
+    fn push_entry(&mut self, data: &str, ack: bool) {
+        let setting = SerialCommand::try_from(data)
+            .ok()
+            .filter(|_| ack)
+            .and_then(|cmd| setting_tag(&cmd).map(|tag| (tag, cmd)));
+
+        match setting {
+            Some((tag, cmd)) => self.set(tag, cmd),
+            None => self.unknown.push(data.into()),
+        }
+        self.entries.push(data.into());
+    }
+
+    fn set(&mut self, tag: &'static str, val: SerialCommand) {
+        match self.settings.iter_mut().find(|(t, _)| *t == tag) {
+            Some(setting) => setting.1 = val,
+            None => self.settings.push((tag, val)),
+        }
+    }
+}
+
+impl From<&MenuReply> for DeviceConfig {
+    fn from(val: &MenuReply) -> Self {
+        Self::parse(val)
+    }
+}
+
+impl TryFrom<&str> for DeviceConfig {
+    type Error = Error;
+
+    /// Parses an archived [DeviceConfig], as displayed.
+    fn try_from(val: &str) -> Result<Self> {
+        let mut cfg = Self::new();
+        let mut tag = "";
+        entry_tokens(val).for_each(|token| cfg.push_entry(&expand_entry(&mut tag, token), true));
+
+        if cfg.entries.is_empty() {
+            Err(Error::InvalidVariant)
+        } else {
+            Ok(cfg)
+        }
+    }
+}
+
+impl fmt::Display for DeviceConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.entries.iter().try_for_each(|e| writeln!(f, "{e}"))
+    }
+}
+
+// Splits reply data into the individual menu command entries.
+fn entry_tokens(val: &str) -> impl Iterator<Item = &str> {
+    val.split([';', ',', '\r', '\n'])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+}
+
+// Expands an entry abbreviated to the sub-tag, keeping entries that do not parse verbatim.
+fn expand_entry<'a>(tag: &mut &'a str, token: &'a str) -> String {
+    let expanded = expand_tag(tag, token);
+
+    if SerialCommand::try_from(expanded.as_str()).is_ok() {
+        expanded
+    } else {
+        token.into()
+    }
+}
+
+/// Represents a desired command in a [ConfigDiff], and the current device value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiffEntry {
+    tag: Option<&'static str>,
+    current: Option<SerialCommand>,
+    desired: SerialCommand,
+}
+
+impl DiffEntry {
+    /// Gets the queryable setting tag, if any.
+    pub const fn tag(&self) -> Option<&'static str> {
+        self.tag
+    }
+
+    /// Gets the current device value, if known.
+    pub const fn current(&self) -> Option<&SerialCommand> {
+        self.current.as_ref()
+    }
+
+    /// Gets the desired [SerialCommand].
+    pub const fn desired(&self) -> &SerialCommand {
+        &self.desired
+    }
+
+    /// Gets whether the desired command needs to be sent to the device.
+    pub fn is_changed(&self) -> bool {
+        self.current.as_ref() != Some(&self.desired)
+    }
+}
+
+impl fmt::Display for DiffEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let desired = &self.desired;
+        let tag = self.tag.map_or_else(|| desired.command(), String::from);
+
+        match (&self.current, self.is_changed()) {
+            (Some(current), true) => write!(f, "~ {tag}: {current:?} -> {desired:?}"),
+            (Some(current), false) => write!(f, "  {tag}: {current:?}"),
+            (None, _) => write!(f, "+ {tag}: {desired:?}"),
+        }
+    }
+}
+
+/// Represents the difference between a [DeviceConfig] and the desired commands.
+///
+/// Displays a report of the changes, one per line: `~` for changed settings, and `+` for
+/// commands sent without a known current value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConfigDiff {
+    entries: Vec<DiffEntry>,
+    storage: Storage,
+}
+
+impl ConfigDiff {
+    /// Gets the list of [DiffEntry] items, including unchanged settings.
+    pub fn entries(&self) -> &[DiffEntry] {
+        &self.entries
+    }
+
+    /// Gets an iterator over the changed [DiffEntry] items.
+    pub fn changes(&self) -> impl Iterator<Item = &DiffEntry> {
+        self.entries.iter().filter(|e| e.is_changed())
+    }
+
+    /// Gets whether the device already matches the desired commands.
+    pub fn is_empty(&self) -> bool {
+        self.changes().next().is_none()
+    }
+
+    /// Gets the [Storage] the changes are saved to.
+    pub const fn storage(&self) -> Storage {
+        self.storage
+    }
+
+    /// Builder function that sets the [Storage] the changes are saved to.
+    pub fn with_storage(mut self, val: Storage) -> Self {
+        self.storage = val;
+        self
+    }
+
+    /// Gets the ordered list of [Command]s to apply the changes.
+    pub fn to_commands(&self) -> Vec<Command> {
+        self.changes()
+            .map(|e| Command::from(e.desired).with_storage(self.storage))
+            .collect()
+    }
+
+    /// Gets the ordered list of menu command frames to apply the changes.
+    pub fn frames(&self) -> Vec<String> {
+        self.to_commands().iter().map(Command::frame).collect()
+    }
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.changes().try_for_each(|e| writeln!(f, "{e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let frame = DeviceConfig::query_frame();
+        assert!(frame.starts_with("\x16M\rALLENA?;QRCENA?;"));
+        assert!(frame.ends_with(";232BAD?."));
+
+        let reply = MenuReply::parse(
+            b"BEPBEP1\x06;SCNAIM2\x06;232BAD5\x06;DLYGRD500\x06;KBDCTY\x15;BEPLVL7\x06.",
+        )
+        .unwrap();
+        let cfg = DeviceConfig::parse(&reply);
+
+        assert_eq!(
+            cfg.get("BEPBEP"),
+            Some(&SerialCommand::GoodReadBeep(GoodReadBeep::On))
+        );
+        assert_eq!(
+            cfg.get("232BAD"),
+            Some(&SerialCommand::BaudRate(BaudRate::Baud9600))
+        );
+        assert_eq!(cfg.get("KBDCTY"), None);
+        assert_eq!(cfg.settings().len(), 4);
+        assert_eq!(cfg.unknown(), ["KBDCTY", "BEPLVL7"]);
+
+        let diff = cfg.diff([
+            SerialCommand::GoodReadBeep(GoodReadBeep::On),
+            SerialCommand::AimerMode(AimerMode::Off),
+            SerialCommand::OperatingMode(OperatingMode::ScanStand),
+            SerialCommand::GoodReadDelay(GoodReadDelay::try_from_ms(500).unwrap()),
+            SerialCommand::AimerMode(AimerMode::Off),
+            SerialCommand::BeeperVolume(BeeperVolume::Low),
+        ]);
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.entries().len(), 6);
+        assert_eq!(
+            diff.frames(),
+            ["\x16M\rSCNAIM0.", "\x16M\rPAPSSM.", "\x16M\rBEPLVL1."]
+        );
+        assert_eq!(
+            format!("{diff}"),
+            "~ SCNAIM: AimerMode(Concurrent) -> AimerMode(Off)\n\
+             + PAPSSM: OperatingMode(ScanStand)\n\
+             + BEPLVL: BeeperVolume(Low)\n"
+        );
+
+        let profile = Profile::new()
+            .with_good_read_beep(GoodReadBeep::On)
+            .with_baud_rate(BaudRate::Baud9600)
+            .with_storage(Storage::Temporary);
+        let diff = cfg.diff_profile(&profile);
+        assert!(diff.is_empty());
+        assert!(diff.frames().is_empty());
+        assert_eq!(format!("{diff}"), "");
+
+        let diff = cfg.diff_profile(&profile.with_aimer_mode(AimerMode::Interlaced));
+        assert_eq!(diff.frames(), ["\x16M\rSCNAIM1!"]);
+
+        // settings following a defaults activation are always sent
+        let cfg = DeviceConfig::new()
+            .with_setting(SerialCommand::AimerMode(AimerMode::Off))
+            .and_then(|cfg| cfg.with_setting(SerialCommand::GoodReadBeep(GoodReadBeep::On)))
+            .unwrap();
+        let profile = Profile::new()
+            .with_defaults(Defaults::Activate)
+            .with_aimer_mode(AimerMode::Off)
+            .with_good_read_beep(GoodReadBeep::On);
+        assert_eq!(
+            cfg.diff_profile(&profile).frames(),
+            ["\x16M\rDEFALT.", "\x16M\rBEPBEP1.", "\x16M\rSCNAIM0."]
+        );
+        assert_eq!(
+            cfg.diff([
+                SerialCommand::AimerMode(AimerMode::Off),
+                SerialCommand::Reset(Reset::new()),
+                SerialCommand::GoodReadBeep(GoodReadBeep::On),
+            ])
+            .frames(),
+            ["\x16M\rRESET.", "\x16M\rBEPBEP1."]
+        );
+    }
+
+    #[test]
+    fn test_dump() {
+        let scope = QueryScope::try_group("BEP").unwrap();
+        assert_eq!(
+            DeviceConfig::dump_frame(&scope, QueryCommand::CurrentValue, Storage::Permanent),
+            "\x16M\rBEP?."
+        );
+        assert_eq!(
+            DeviceConfig::dump_frame(
+                &QueryScope::Menu,
+                QueryCommand::DefaultValue,
+                Storage::Temporary
+            ),
+            "\x16M\r^!"
+        );
+
+        // group dumps write the full tag on the first entry only
+        let reply =
+            MenuReply::parse(b"FOOBAR\x15;BEPBEP1\x06,LED0\x06,XYZ3\x06;232BAD9\x06,WRD3\x06.")
+                .unwrap();
+        let cfg = DeviceConfig::parse(&reply);
+
+        assert_eq!(cfg.settings().len(), 4);
+        assert_eq!(
+            cfg.get("BEPLED"),
+            Some(&SerialCommand::GoodReadLed(GoodReadLed::Off))
+        );
+        assert_eq!(cfg.unknown(), ["FOOBAR", "XYZ3"]);
+        assert_eq!(
+            cfg.entries(),
+            ["FOOBAR", "BEPBEP1", "BEPLED0", "XYZ3", "232BAD9", "232WRD3"]
+        );
+
+        let group =
+            DeviceConfig::parse(&MenuReply::parse(b"BEPBEP1\x06,LED0\x06,LVL2\x06.").unwrap());
+        assert_eq!(group.settings().len(), 3);
+        assert!(group.unknown().is_empty());
+
+        // archived snapshots round-trip, keeping unknown tags verbatim
+        let archive = format!("{cfg}");
+        assert_eq!(
+            archive,
+            "FOOBAR\nBEPBEP1\nBEPLED0\nXYZ3\n232BAD9\n232WRD3\n"
+        );
+
+        let restored = DeviceConfig::try_from(archive.as_str()).unwrap();
+        assert_eq!(restored.entries(), cfg.entries());
+        assert_eq!(restored.settings(), cfg.settings());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(DeviceConfig::try_from(" \r\n"), Err(Error::InvalidVariant));
+
+        assert_eq!(
+            DeviceConfig::new().with_setting(SerialCommand::OperatingMode(OperatingMode::new())),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(setting_tag(&SerialCommand::Reset(Reset::new())), None);
+
+        // unknown current values are always sent
+        let diff = DeviceConfig::new().diff([SerialCommand::AimerMode(AimerMode::Off)]);
+        assert_eq!(diff.frames(), ["\x16M\rSCNAIM0."]);
+        assert_eq!(diff.entries()[0].current(), None);
+    }
+}
@@ -0,0 +1,17 @@
+//! Types and algorithms for decoded barcode data transmitted by the BCS device.
+
+pub mod aamva;
+mod barcode;
+pub mod eci;
+pub mod gs1;
+pub mod iso15434;
+pub mod structured_append;
+mod symbology;
+
+pub use aamva::AamvaLicense;
+pub use barcode::*;
+pub use eci::{CharacterSet, DecodedText, TextDecoder};
+pub use gs1::Gs1Data;
+pub use iso15434::Iso15434Message;
+pub use structured_append::{StructuredAppend, StructuredAppendFragment};
+pub use symbology::*;
@@ -0,0 +1,574 @@
+//! Types and algorithms for parsing AAMVA driver's license and ID card data from PDF417 reads.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::result::{Error, Result};
+
+/// Compliance indicator starting the AAMVA header.
+pub const AAMVA_COMPLIANCE: u8 = b'@';
+/// Data element separator.
+pub const AAMVA_ELEMENT_SEP: u8 = b'\n';
+/// Record separator.
+pub const AAMVA_RECORD_SEP: u8 = 0x1e;
+/// Segment terminator.
+pub const AAMVA_SEGMENT_TERM: u8 = b'\r';
+/// Minimum supported AAMVA version.
+pub const AAMVA_VERSION_MIN: u8 = 1;
+/// Maximum supported AAMVA version.
+pub const AAMVA_VERSION_MAX: u8 = 10;
+
+const FILE_TYPE: &[u8] = b"ANSI ";
+const FILE_TYPE_LEGACY: &[u8] = b"AAMVA";
+// Compliance indicator, separators, and file type.
+const HEADER_PREAMBLE_LEN: usize = 9;
+const IIN_LEN: usize = 6;
+const DESIGNATOR_LEN: usize = 10;
+
+/// Represents the AAMVA header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AamvaHeader {
+    iin: u32,
+    version: u8,
+    jurisdiction_version: u8,
+    entries: u8,
+}
+
+impl AamvaHeader {
+    /// Creates a new [AamvaHeader].
+    pub const fn new() -> Self {
+        Self {
+            iin: 0,
+            version: AAMVA_VERSION_MAX,
+            jurisdiction_version: 0,
+            entries: 0,
+        }
+    }
+
+    /// Gets the Issuer Identification Number of the jurisdiction.
+    pub const fn iin(&self) -> u32 {
+        self.iin
+    }
+
+    /// Gets the AAMVA standard version.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Gets the jurisdiction version (always `0` for AAMVA version 1).
+    pub const fn jurisdiction_version(&self) -> u8 {
+        self.jurisdiction_version
+    }
+
+    /// Gets the number of subfile entries.
+    pub const fn entries(&self) -> u8 {
+        self.entries
+    }
+
+    /// Gets the encoded length of the [AamvaHeader], excluding subfile designators.
+    pub const fn len(&self) -> usize {
+        if self.version == 1 {
+            HEADER_PREAMBLE_LEN + IIN_LEN + 4
+        } else {
+            HEADER_PREAMBLE_LEN + IIN_LEN + 6
+        }
+    }
+
+    /// Gets whether the [AamvaHeader] has no subfile entries.
+    pub const fn is_empty(&self) -> bool {
+        self.entries == 0
+    }
+}
+
+impl Default for AamvaHeader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&[u8]> for AamvaHeader {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        match val {
+            [AAMVA_COMPLIANCE, AAMVA_ELEMENT_SEP, AAMVA_RECORD_SEP, AAMVA_SEGMENT_TERM, ..] => (),
+            _ => return Err(Error::InvalidVariant),
+        }
+
+        let file_type = val
+            .get(4..HEADER_PREAMBLE_LEN)
+            .ok_or(Error::InvalidVariant)?;
+        if file_type != FILE_TYPE && file_type != FILE_TYPE_LEGACY {
+            return Err(invalid_data(val, 4, FILE_TYPE.len()));
+        }
+
+        let mut pos = HEADER_PREAMBLE_LEN;
+        let iin = parse_num(val, pos, IIN_LEN)?;
+        pos += IIN_LEN;
+
+        let version = parse_num(val, pos, 2)? as u8;
+        if !(AAMVA_VERSION_MIN..=AAMVA_VERSION_MAX).contains(&version) {
+            let err = Error::out_of_range(
+                version as usize,
+                AAMVA_VERSION_MIN as usize,
+                AAMVA_VERSION_MAX as usize,
+            );
+            return Err(err.with_token(&String::from_utf8_lossy(&val[pos..pos + 2]), pos));
+        }
+        pos += 2;
+
+        let jurisdiction_version = if version == 1 {
+            0
+        } else {
+            let v = parse_num(val, pos, 2)? as u8;
+            pos += 2;
+            v
+        };
+
+        let entries = parse_num(val, pos, 2)? as u8;
+        if entries == 0 {
+            let err = Error::out_of_range(0, 1, 99);
+            return Err(err.with_token(&String::from_utf8_lossy(&val[pos..pos + 2]), pos));
+        }
+
+        Ok(Self {
+            iin,
+            version,
+            jurisdiction_version,
+            entries,
+        })
+    }
+}
+
+fn parse_num(val: &[u8], pos: usize, len: usize) -> Result<u32> {
+    val.get(pos..pos + len)
+        .filter(|s| s.iter().all(u8::is_ascii_digit))
+        .and_then(|s| core::str::from_utf8(s).ok())
+        .and_then(|s| s.parse::<u32>().ok())
+        .ok_or_else(|| invalid_data(val, pos, len))
+}
+
+// Creates an InvalidData error for the `len` bytes at `pos`, truncated to the payload.
+fn invalid_data(val: &[u8], pos: usize, len: usize) -> Error {
+    let data = val.get(pos..).unwrap_or_default();
+
+    Error::InvalidData {
+        data: String::from_utf8_lossy(&data[..len.min(data.len())]).into_owned(),
+        position: pos,
+    }
+}
+
+/// Represents an AAMVA date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AamvaDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl AamvaDate {
+    /// Creates a new [AamvaDate].
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Gets the year of the [AamvaDate].
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Gets the month of the [AamvaDate].
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets the day of the [AamvaDate].
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Attempts to parse an [AamvaDate] from its eight-digit encoding.
+    ///
+    /// AAMVA version 1, and Canadian jurisdictions, use `CCYYMMDD`. All other jurisdictions use
+    /// `MMDDCCYY`.
+    pub fn parse(val: &str, version: u8, canada: bool) -> Result<Self> {
+        if val.len() != 8 || !val.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidVariant);
+        }
+
+        let num = |r: core::ops::Range<usize>| val[r].parse::<u16>().unwrap_or_default();
+        let (year, month, day) = if version == 1 || canada {
+            (num(0..4), num(4..6), num(6..8))
+        } else {
+            (num(4..8), num(0..2), num(2..4))
+        };
+
+        let leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let max_day = match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => return Err(Error::out_of_range(month as usize, 1, 12)),
+        };
+
+        if (1..=max_day).contains(&day) {
+            Ok(Self::new(year, month as u8, day as u8))
+        } else {
+            Err(Error::out_of_range(day as usize, 1, max_day as usize))
+        }
+    }
+}
+
+/// Represents an AAMVA subfile (e.g. `DL`, `ID`, or jurisdiction-specific `Z*`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AamvaSubfile {
+    kind: String,
+    elements: Vec<(String, String)>,
+}
+
+impl AamvaSubfile {
+    /// Gets the two-character subfile type.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Gets the list of data element ID and value pairs.
+    pub fn elements(&self) -> &[(String, String)] {
+        &self.elements
+    }
+
+    /// Gets the value of a data element by its three-character ID (e.g. `DCS`).
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.elements
+            .iter()
+            .find(|(k, _)| k == id)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn parse(kind: &str, val: &[u8]) -> Self {
+        let val = val.strip_prefix(kind.as_bytes()).unwrap_or(val);
+        let end = val
+            .iter()
+            .position(|&c| c == AAMVA_SEGMENT_TERM)
+            .unwrap_or(val.len());
+
+        let elements = val[..end]
+            .split(|&c| c == AAMVA_ELEMENT_SEP)
+            .map(String::from_utf8_lossy)
+            .filter_map(|e| {
+                let e = e.trim();
+                e.get(..3)
+                    .zip(e.get(3..))
+                    .map(|(id, v)| (id.into(), v.trim().into()))
+            })
+            .collect();
+
+        Self {
+            kind: kind.into(),
+            elements,
+        }
+    }
+}
+
+/// Represents the type of AAMVA identification document.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AamvaDocument {
+    /// Driver's license (`DL` subfile).
+    DriverLicense,
+    /// Identification card (`ID` subfile).
+    IdCard,
+}
+
+/// Represents the sex of the cardholder.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AamvaSex {
+    Male,
+    Female,
+    NotSpecified,
+}
+
+/// Represents a parsed AAMVA driver's license or ID card record.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AamvaLicense {
+    header: AamvaHeader,
+    document: AamvaDocument,
+    subfiles: Vec<AamvaSubfile>,
+}
+
+impl AamvaLicense {
+    /// Parses an [AamvaLicense] from a PDF417 payload.
+    pub fn parse(val: &[u8]) -> Result<Self> {
+        let header = AamvaHeader::try_from(val)?;
+        let mut subfiles = Vec::with_capacity(header.entries() as usize);
+
+        for i in 0..header.entries() as usize {
+            let pos = header.len() + i * DESIGNATOR_LEN;
+            let kind = val
+                .get(pos..pos + 2)
+                .and_then(|k| core::str::from_utf8(k).ok())
+                .filter(|k| k.bytes().all(|c| c.is_ascii_uppercase()))
+                .ok_or_else(|| invalid_data(val, pos, 2))?;
+            let offset = parse_num(val, pos + 2, 4)? as usize;
+            let len = parse_num(val, pos + 6, 4)? as usize;
+
+            // Offsets are frequently miscounted by issuers, so fall back to searching for the
+            // subfile type after the designators.
+            let data = match val.get(offset..) {
+                Some(d) if d.starts_with(kind.as_bytes()) => &d[..len.min(d.len())],
+                _ => {
+                    let start = header.len() + header.entries() as usize * DESIGNATOR_LEN;
+                    let found = val
+                        .get(start..)
+                        .and_then(|rem| rem.windows(2).position(|w| w == kind.as_bytes()))
+                        .ok_or_else(|| invalid_data(val, pos, DESIGNATOR_LEN))?;
+                    &val[start + found..]
+                }
+            };
+
+            subfiles.push(AamvaSubfile::parse(kind, data));
+        }
+
+        let document = subfiles
+            .iter()
+            .find_map(|s| match s.kind() {
+                "DL" => Some(AamvaDocument::DriverLicense),
+                "ID" => Some(AamvaDocument::IdCard),
+                _ => None,
+            })
+            .ok_or(Error::InvalidVariant)?;
+
+        Ok(Self {
+            header,
+            document,
+            subfiles,
+        })
+    }
+
+    /// Gets the [AamvaHeader].
+    pub const fn header(&self) -> &AamvaHeader {
+        &self.header
+    }
+
+    /// Gets the [AamvaDocument] type.
+    pub const fn document(&self) -> AamvaDocument {
+        self.document
+    }
+
+    /// Gets the list of [AamvaSubfile]s.
+    pub fn subfiles(&self) -> &[AamvaSubfile] {
+        &self.subfiles
+    }
+
+    /// Gets the value of a data element from the `DL` or `ID` subfile.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.subfiles
+            .iter()
+            .filter(|s| s.kind() == "DL" || s.kind() == "ID")
+            .find_map(|s| s.get(id))
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Gets the family name (`DCS`, or `DAB` for version 1).
+    pub fn family_name(&self) -> Option<&str> {
+        self.get("DCS").or_else(|| self.get("DAB"))
+    }
+
+    /// Gets the first name (`DAC`, or `DCT` for versions 2 and 3).
+    pub fn first_name(&self) -> Option<&str> {
+        self.get("DAC")
+            .or_else(|| self.get("DCT").and_then(|n| n.split([',', ' ']).next()))
+    }
+
+    /// Gets the middle name(s) (`DAD`).
+    pub fn middle_name(&self) -> Option<&str> {
+        self.get("DAD")
+    }
+
+    /// Gets the customer ID / license number (`DAQ`).
+    pub fn customer_id(&self) -> Option<&str> {
+        self.get("DAQ")
+    }
+
+    /// Gets the street address (`DAG`).
+    pub fn street(&self) -> Option<&str> {
+        self.get("DAG")
+    }
+
+    /// Gets the city (`DAI`).
+    pub fn city(&self) -> Option<&str> {
+        self.get("DAI")
+    }
+
+    /// Gets the jurisdiction code (`DAJ`).
+    pub fn jurisdiction(&self) -> Option<&str> {
+        self.get("DAJ")
+    }
+
+    /// Gets the postal code (`DAK`).
+    pub fn postal_code(&self) -> Option<&str> {
+        self.get("DAK")
+    }
+
+    /// Gets the country identification (`DCG`).
+    pub fn country(&self) -> Option<&str> {
+        self.get("DCG")
+    }
+
+    /// Gets the sex of the cardholder (`DBC`).
+    pub fn sex(&self) -> Option<AamvaSex> {
+        match self.get("DBC")? {
+            "1" | "M" => Some(AamvaSex::Male),
+            "2" | "F" => Some(AamvaSex::Female),
+            _ => Some(AamvaSex::NotSpecified),
+        }
+    }
+
+    /// Gets the date of birth (`DBB`).
+    pub fn date_of_birth(&self) -> Result<AamvaDate> {
+        self.date("DBB")
+    }
+
+    /// Gets the document expiration date (`DBA`).
+    pub fn expiration_date(&self) -> Result<AamvaDate> {
+        self.date("DBA")
+    }
+
+    /// Gets the document issue date (`DBD`).
+    pub fn issue_date(&self) -> Result<AamvaDate> {
+        self.date("DBD")
+    }
+
+    /// Gets a date data element, parsed according to the AAMVA version and jurisdiction.
+    pub fn date(&self, id: &str) -> Result<AamvaDate> {
+        let canada = self.country() == Some("CAN");
+
+        self.get(id)
+            .ok_or(Error::InvalidVariant)
+            .and_then(|d| AamvaDate::parse(d, self.header.version(), canada))
+    }
+}
+
+impl TryFrom<&[u8]> for AamvaLicense {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        Self::parse(val)
+    }
+}
+
+impl TryFrom<&str> for AamvaLicense {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        Self::parse(val.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V10: &str = "@\n\x1e\rANSI 636000100002DL00410278ZV03190008DLDAQT64235789\nDCSSAMPLE\nDDEN\nDACMICHAEL\nDDFN\nDADJOHN\nDDGN\nDCAD\nDCBK\nDCDPH\nDBD06062016\nDBB06061986\nDBA12102024\nDBC1\nDAU068 in\nDAYBRO\nDAG2300 WEST BROAD STREET\nDAIRICHMOND\nDAJVA\nDAK232690000  \nDCF2424244747474786102204\nDCGUSA\nDCK123456789\nDDAF\nDDB06062008\nDDC06062009\nDDD1\rZVZVA01\r";
+
+    #[test]
+    fn test_valid() {
+        let lic = AamvaLicense::try_from(V10).unwrap();
+        let header = lic.header();
+
+        assert_eq!(header.iin(), 636000);
+        assert_eq!(header.version(), 10);
+        assert_eq!(header.jurisdiction_version(), 0);
+        assert_eq!(header.entries(), 2);
+        assert_eq!(lic.document(), AamvaDocument::DriverLicense);
+        assert_eq!(lic.subfiles().len(), 2);
+        assert_eq!(lic.subfiles()[1].get("ZVA"), Some("01"));
+
+        assert_eq!(lic.family_name(), Some("SAMPLE"));
+        assert_eq!(lic.first_name(), Some("MICHAEL"));
+        assert_eq!(lic.middle_name(), Some("JOHN"));
+        assert_eq!(lic.customer_id(), Some("T64235789"));
+        assert_eq!(lic.postal_code(), Some("232690000"));
+        assert_eq!(lic.sex(), Some(AamvaSex::Male));
+        assert_eq!(lic.date_of_birth(), Ok(AamvaDate::new(1986, 6, 6)));
+        assert_eq!(lic.expiration_date(), Ok(AamvaDate::new(2024, 12, 10)));
+    }
+
+    #[test]
+    fn test_version_dates() {
+        let v1 = "@\n\x1e\rANSI 6360170101DL00290090DLDAQ0123456789\nDABPUBLIC\nDACJOHN\nDBB19700131\nDBA20300131\r";
+        let lic = AamvaLicense::try_from(v1).unwrap();
+
+        assert_eq!(lic.header().version(), 1);
+        assert_eq!(lic.family_name(), Some("PUBLIC"));
+        assert_eq!(lic.date_of_birth(), Ok(AamvaDate::new(1970, 1, 31)));
+
+        // Canadian jurisdictions use CCYYMMDD, and offsets are miscounted
+        let can = "@\n\x1e\rANSI 636012080001ID00009999IDDAQ123\nDCSDOE\nDCGCAN\nDBB19800229\r";
+        let lic = AamvaLicense::try_from(can).unwrap();
+
+        assert_eq!(lic.document(), AamvaDocument::IdCard);
+        assert_eq!(lic.header().jurisdiction_version(), 0);
+        assert_eq!(lic.date_of_birth(), Ok(AamvaDate::new(1980, 2, 29)));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let invalid = |data: &str, position| Error::InvalidData {
+            data: data.into(),
+            position,
+        };
+
+        [
+            ("", Error::InvalidVariant),
+            ("@\n\x1e\r", Error::InvalidVariant),
+            ("@\n\x1e\rANSE 636000100002", invalid("ANSE ", 4)),
+            ("@\n\x1e\rANSI 63600A100002", invalid("63600A", 9)),
+            ("@\n\x1e\rANSI +36000100002", invalid("+36000", 9)),
+            ("@\n\x1e\rANSI 636000+10002", invalid("+1", 15)),
+            (
+                "@\n\x1e\rANSI 636000110002",
+                Error::out_of_range(11, 1, 10).with_token("11", 15),
+            ),
+            (
+                "@\n\x1e\rANSI 636000000002",
+                Error::out_of_range(0, 1, 10).with_token("00", 15),
+            ),
+            (
+                "@\n\x1e\rANSI 636000100000",
+                Error::out_of_range(0, 1, 99).with_token("00", 19),
+            ),
+            ("@\n\x1e\rANSI 636000100001dl00310010", invalid("dl", 21)),
+            (
+                "@\n\x1e\rANSI 636000100001ZV00990010",
+                invalid("ZV00990010", 21),
+            ),
+            (
+                "@\n\x1e\rANSI 636000100001ZV00310010ZVZVA01\r",
+                Error::InvalidVariant,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(s, err)| {
+            assert_eq!(AamvaLicense::try_from(s), Err(err), "{s:?}");
+        });
+
+        [
+            ("02312024", 10, false, Error::out_of_range(31, 1, 29)),
+            ("04312024", 10, false, Error::out_of_range(31, 1, 30)),
+            ("02292023", 10, false, Error::out_of_range(29, 1, 28)),
+            ("02291900", 10, false, Error::out_of_range(29, 1, 28)),
+            ("19000229", 1, false, Error::out_of_range(29, 1, 28)),
+            ("20241301", 10, true, Error::out_of_range(13, 1, 12)),
+            ("20240100", 10, true, Error::out_of_range(0, 1, 31)),
+        ]
+        .into_iter()
+        .for_each(|(s, version, canada, err)| {
+            assert_eq!(AamvaDate::parse(s, version, canada), Err(err), "{s}");
+        });
+        assert_eq!(
+            AamvaDate::parse("02292000", 10, false),
+            Ok(AamvaDate::new(2000, 2, 29))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use alloc::vec::Vec;
+
+use crate::command::SymbologyIdTransmit;
+use crate::result::{Error, Result};
+
+use super::{AimId, Symbology};
+
+/// Describes the framing the BCS device adds around decoded barcode data.
+///
+/// Fields are expected in transmission order: user prefix, `Code ID`, `AIM ID`, payload, suffix.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReadFormat {
+    prefix: Vec<u8>,
+    code_id: bool,
+    aim_id: bool,
+    suffix: Vec<u8>,
+}
+
+impl ReadFormat {
+    /// Creates a new [ReadFormat].
+    pub const fn new() -> Self {
+        Self {
+            prefix: Vec::new(),
+            code_id: false,
+            aim_id: false,
+            suffix: Vec::new(),
+        }
+    }
+
+    /// Gets the user-configured prefix bytes.
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Builder function that sets the user-configured prefix bytes.
+    pub fn with_prefix(mut self, val: &[u8]) -> Self {
+        self.prefix = val.into();
+        self
+    }
+
+    /// Gets whether the device transmits the Honeywell `Code ID`.
+    pub const fn code_id(&self) -> bool {
+        self.code_id
+    }
+
+    /// Builder function that sets whether the device transmits the Honeywell `Code ID`.
+    pub fn with_code_id(mut self, val: bool) -> Self {
+        self.code_id = val;
+        self
+    }
+
+    /// Gets whether the device transmits the `AIM ID`.
+    pub const fn aim_id(&self) -> bool {
+        self.aim_id
+    }
+
+    /// Builder function that sets whether the device transmits the `AIM ID`.
+    pub fn with_aim_id(mut self, val: bool) -> Self {
+        self.aim_id = val;
+        self
+    }
+
+    /// Gets the user-configured suffix (terminator) bytes.
+    pub fn suffix(&self) -> &[u8] {
+        &self.suffix
+    }
+
+    /// Builder function that sets the user-configured suffix (terminator) bytes.
+    pub fn with_suffix(mut self, val: &[u8]) -> Self {
+        self.suffix = val.into();
+        self
+    }
+}
+
+impl From<SymbologyIdTransmit> for ReadFormat {
+    fn from(val: SymbologyIdTransmit) -> Self {
+        Self::new()
+            .with_code_id(val.code_id())
+            .with_aim_id(val.aim_id())
+    }
+}
+
+/// Represents a single barcode read transmitted by the BCS device.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodedBarcode {
+    prefix: Vec<u8>,
+    code_id: Option<u8>,
+    aim_id: Option<AimId>,
+    symbology: Option<Symbology>,
+    payload: Vec<u8>,
+    suffix: Vec<u8>,
+}
+
+impl DecodedBarcode {
+    /// Creates a new [DecodedBarcode].
+    pub const fn new() -> Self {
+        Self {
+            prefix: Vec::new(),
+            code_id: None,
+            aim_id: None,
+            symbology: None,
+            payload: Vec::new(),
+            suffix: Vec::new(),
+        }
+    }
+
+    /// Parses a [DecodedBarcode] from raw read bytes framed according to the [ReadFormat].
+    pub fn parse(val: &[u8], fmt: &ReadFormat) -> Result<Self> {
+        let mut rem = val
+            .strip_prefix(fmt.prefix())
+            .ok_or(Error::InvalidVariant)?;
+        let mut suffix = Vec::new();
+
+        if !fmt.suffix().is_empty() {
+            rem = rem
+                .strip_suffix(fmt.suffix())
+                .ok_or(Error::InvalidVariant)?;
+            suffix = fmt.suffix().into();
+        }
+
+        let code_id = if fmt.code_id() {
+            let (id, r) = rem.split_first().ok_or(Error::InvalidVariant)?;
+            rem = r;
+            Some(*id)
+        } else {
+            None
+        };
+
+        let aim_id = if fmt.aim_id() {
+            let aim = AimId::try_from(rem)?;
+            rem = &rem[AimId::LEN..];
+            Some(aim)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            prefix: fmt.prefix().into(),
+            code_id,
+            aim_id,
+            symbology: Symbology::from_ids(code_id, aim_id),
+            payload: rem.into(),
+            suffix,
+        })
+    }
+
+    /// Gets the user-configured prefix bytes of the [DecodedBarcode].
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Gets the Honeywell `Code ID` of the [DecodedBarcode].
+    pub const fn code_id(&self) -> Option<u8> {
+        self.code_id
+    }
+
+    /// Gets the [AimId] of the [DecodedBarcode].
+    pub const fn aim_id(&self) -> Option<AimId> {
+        self.aim_id
+    }
+
+    /// Gets the [Symbology] of the [DecodedBarcode].
+    pub const fn symbology(&self) -> Option<Symbology> {
+        self.symbology
+    }
+
+    /// Gets the raw payload bytes of the [DecodedBarcode].
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Converts the [DecodedBarcode] into its raw payload bytes.
+    pub fn into_payload(self) -> Vec<u8> {
+        self.payload
+    }
+
+    /// Gets the user-configured suffix bytes of the [DecodedBarcode].
+    pub fn suffix(&self) -> &[u8] {
+        &self.suffix
+    }
+}
+
+impl TryFrom<&[u8]> for DecodedBarcode {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        Self::parse(val, &ReadFormat::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let fmt = ReadFormat::new()
+            .with_code_id(true)
+            .with_aim_id(true)
+            .with_suffix(b"\r");
+        let read = DecodedBarcode::parse(b"s]Q1http://example.com\r", &fmt).unwrap();
+
+        assert_eq!(read.code_id(), Some(b's'));
+        assert_eq!(read.aim_id(), Some(AimId::new(b'Q', b'1')));
+        assert_eq!(read.symbology(), Some(Symbology::QRCode));
+        assert_eq!(read.payload(), b"http://example.com");
+        assert_eq!(read.suffix(), b"\r");
+
+        let fmt = ReadFormat::new().with_prefix(b"\x02").with_aim_id(true);
+        let read = DecodedBarcode::parse(b"\x02]C10101234567890128", &fmt).unwrap();
+
+        assert_eq!(read.prefix(), b"\x02");
+        assert_eq!(read.code_id(), None);
+        assert_eq!(read.symbology(), Some(Symbology::Code128));
+        assert!(read.aim_id().unwrap().gs1());
+        assert_eq!(read.payload(), b"0101234567890128");
+
+        let fmt = ReadFormat::new().with_code_id(true);
+        let read = DecodedBarcode::parse(b"r@\n\x1e\rANSI ", &fmt).unwrap();
+
+        assert_eq!(read.symbology(), Some(Symbology::Pdf417));
+        assert_eq!(read.payload(), b"@\n\x1e\rANSI ");
+
+        let fmt = ReadFormat::from(SymbologyIdTransmit::AimId);
+        let read = DecodedBarcode::parse(b"]E00012345678905", &fmt).unwrap();
+
+        assert_eq!(read.symbology(), Some(Symbology::Ean13));
+        assert_eq!(read.payload(), b"0012345678905");
+
+        let read = DecodedBarcode::try_from(b"12345".as_ref()).unwrap();
+
+        assert_eq!(read.symbology(), None);
+        assert_eq!(read.into_payload(), b"12345");
+    }
+
+    #[test]
+    fn test_invalid() {
+        let fmt = ReadFormat::new().with_code_id(true).with_aim_id(true);
+
+        ["", "s", "s]Q", "sQ1data"].into_iter().for_each(|s| {
+            assert_eq!(
+                DecodedBarcode::parse(s.as_bytes(), &fmt),
+                Err(Error::InvalidVariant)
+            );
+        });
+
+        let fmt = ReadFormat::new().with_prefix(b"\x02").with_suffix(b"\r\n");
+
+        ["data\r\n", "\x02data", "\x02data\r"]
+            .into_iter()
+            .for_each(|s| {
+                assert_eq!(
+                    DecodedBarcode::parse(s.as_bytes(), &fmt),
+                    Err(Error::InvalidVariant)
+                );
+            });
+    }
+}
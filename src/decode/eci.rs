@@ -0,0 +1,486 @@
+//! Types and algorithms for decoding scanned payloads into Unicode text, honoring ECI designators.
+
+use alloc::{string::String, vec::Vec};
+
+use encoding_rs::Encoding;
+
+use crate::result::{Error, Result};
+
+use super::DecodedBarcode;
+
+/// Escape character starting an ECI designator (`\nnnnnn`) in transmitted data.
+pub const ECI_ESCAPE: u8 = b'\\';
+/// Number of digits in a transmitted ECI designator.
+pub const ECI_DIGITS: usize = 6;
+
+// Code page 437 characters for bytes `0x80`-`0xff`, the lower half is ASCII.
+#[rustfmt::skip]
+const CP437_HIGH: [char; 128] = [
+    '\u{00c7}', '\u{00fc}', '\u{00e9}', '\u{00e2}', '\u{00e4}', '\u{00e0}', '\u{00e5}', '\u{00e7}',
+    '\u{00ea}', '\u{00eb}', '\u{00e8}', '\u{00ef}', '\u{00ee}', '\u{00ec}', '\u{00c4}', '\u{00c5}',
+    '\u{00c9}', '\u{00e6}', '\u{00c6}', '\u{00f4}', '\u{00f6}', '\u{00f2}', '\u{00fb}', '\u{00f9}',
+    '\u{00ff}', '\u{00d6}', '\u{00dc}', '\u{00a2}', '\u{00a3}', '\u{00a5}', '\u{20a7}', '\u{0192}',
+    '\u{00e1}', '\u{00ed}', '\u{00f3}', '\u{00fa}', '\u{00f1}', '\u{00d1}', '\u{00aa}', '\u{00ba}',
+    '\u{00bf}', '\u{2310}', '\u{00ac}', '\u{00bd}', '\u{00bc}', '\u{00a1}', '\u{00ab}', '\u{00bb}',
+    '\u{2591}', '\u{2592}', '\u{2593}', '\u{2502}', '\u{2524}', '\u{2561}', '\u{2562}', '\u{2556}',
+    '\u{2555}', '\u{2563}', '\u{2551}', '\u{2557}', '\u{255d}', '\u{255c}', '\u{255b}', '\u{2510}',
+    '\u{2514}', '\u{2534}', '\u{252c}', '\u{251c}', '\u{2500}', '\u{253c}', '\u{255e}', '\u{255f}',
+    '\u{255a}', '\u{2554}', '\u{2569}', '\u{2566}', '\u{2560}', '\u{2550}', '\u{256c}', '\u{2567}',
+    '\u{2568}', '\u{2564}', '\u{2565}', '\u{2559}', '\u{2558}', '\u{2552}', '\u{2553}', '\u{256b}',
+    '\u{256a}', '\u{2518}', '\u{250c}', '\u{2588}', '\u{2584}', '\u{258c}', '\u{2590}', '\u{2580}',
+    '\u{03b1}', '\u{00df}', '\u{0393}', '\u{03c0}', '\u{03a3}', '\u{03c3}', '\u{00b5}', '\u{03c4}',
+    '\u{03a6}', '\u{0398}', '\u{03a9}', '\u{03b4}', '\u{221e}', '\u{03c6}', '\u{03b5}', '\u{2229}',
+    '\u{2261}', '\u{00b1}', '\u{2265}', '\u{2264}', '\u{2320}', '\u{2321}', '\u{00f7}', '\u{2248}',
+    '\u{00b0}', '\u{2219}', '\u{00b7}', '\u{221a}', '\u{207f}', '\u{00b2}', '\u{25a0}', '\u{00a0}',
+];
+
+/// Represents the character sets supported for decoding scanned payloads.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CharacterSet {
+    /// ISO/IEC 8859-1 (Latin-1), the default for most symbologies.
+    Latin1,
+    /// Code page 437, the original IBM PC character set.
+    Cp437,
+    /// ISO/IEC 8859-2 (Latin-2).
+    Latin2,
+    /// ISO/IEC 8859-5 (Cyrillic).
+    Cyrillic,
+    /// ISO/IEC 8859-6 (Arabic).
+    Arabic,
+    /// ISO/IEC 8859-7 (Greek).
+    Greek,
+    /// ISO/IEC 8859-8 (Hebrew).
+    Hebrew,
+    /// ISO/IEC 8859-15 (Latin-9).
+    Latin9,
+    /// Shift JIS.
+    ShiftJis,
+    /// Windows-1250.
+    Windows1250,
+    /// Windows-1251.
+    Windows1251,
+    /// Windows-1252.
+    Windows1252,
+    /// Windows-1256.
+    Windows1256,
+    /// UTF-16 (big-endian).
+    Utf16Be,
+    /// UTF-8.
+    Utf8,
+    /// US-ASCII.
+    Ascii,
+    /// Big5.
+    Big5,
+    /// GB18030 (superset of GB2312 and GBK).
+    Gb18030,
+    /// EUC-KR.
+    EucKr,
+}
+
+impl CharacterSet {
+    /// Creates a new [CharacterSet].
+    pub const fn new() -> Self {
+        Self::Latin1
+    }
+
+    /// Attempts to convert an ECI assignment number into a [CharacterSet].
+    pub const fn try_from_eci(eci: u32) -> Result<Self> {
+        match eci {
+            0 | 2 => Ok(Self::Cp437),
+            1 | 3 => Ok(Self::Latin1),
+            4 => Ok(Self::Latin2),
+            7 => Ok(Self::Cyrillic),
+            8 => Ok(Self::Arabic),
+            9 => Ok(Self::Greek),
+            10 => Ok(Self::Hebrew),
+            17 => Ok(Self::Latin9),
+            20 => Ok(Self::ShiftJis),
+            21 => Ok(Self::Windows1250),
+            22 => Ok(Self::Windows1251),
+            23 => Ok(Self::Windows1252),
+            24 => Ok(Self::Windows1256),
+            25 => Ok(Self::Utf16Be),
+            26 => Ok(Self::Utf8),
+            27 | 170 => Ok(Self::Ascii),
+            28 => Ok(Self::Big5),
+            29 | 32 => Ok(Self::Gb18030),
+            30 => Ok(Self::EucKr),
+            _ if eci > 170 => Err(Error::out_of_range(eci as usize, 0, 170)),
+            _ => Err(Error::InvalidValue(eci as usize)),
+        }
+    }
+
+    /// Gets the ECI assignment number for the [CharacterSet].
+    pub const fn eci(&self) -> u32 {
+        match self {
+            Self::Latin1 => 3,
+            Self::Cp437 => 2,
+            Self::Latin2 => 4,
+            Self::Cyrillic => 7,
+            Self::Arabic => 8,
+            Self::Greek => 9,
+            Self::Hebrew => 10,
+            Self::Latin9 => 17,
+            Self::ShiftJis => 20,
+            Self::Windows1250 => 21,
+            Self::Windows1251 => 22,
+            Self::Windows1252 => 23,
+            Self::Windows1256 => 24,
+            Self::Utf16Be => 25,
+            Self::Utf8 => 26,
+            Self::Ascii => 27,
+            Self::Big5 => 28,
+            Self::Gb18030 => 32,
+            Self::EucKr => 30,
+        }
+    }
+
+    /// Attempts to convert a Windows code page number into a [CharacterSet].
+    pub const fn try_from_code_page(code_page: u16) -> Result<Self> {
+        match code_page {
+            28591 => Ok(Self::Latin1),
+            437 => Ok(Self::Cp437),
+            28592 => Ok(Self::Latin2),
+            28595 => Ok(Self::Cyrillic),
+            28596 => Ok(Self::Arabic),
+            28597 => Ok(Self::Greek),
+            28598 => Ok(Self::Hebrew),
+            28605 => Ok(Self::Latin9),
+            932 => Ok(Self::ShiftJis),
+            1250 => Ok(Self::Windows1250),
+            1251 => Ok(Self::Windows1251),
+            1252 => Ok(Self::Windows1252),
+            1256 => Ok(Self::Windows1256),
+            1201 => Ok(Self::Utf16Be),
+            65001 => Ok(Self::Utf8),
+            20127 => Ok(Self::Ascii),
+            950 => Ok(Self::Big5),
+            936 | 54936 => Ok(Self::Gb18030),
+            949 | 51949 => Ok(Self::EucKr),
+            _ if code_page < 437 || code_page > 65001 => {
+                Err(Error::out_of_range(code_page as usize, 437, 65001))
+            }
+            _ => Err(Error::InvalidValue(code_page as usize)),
+        }
+    }
+
+    /// Gets the Windows code page number for the [CharacterSet].
+    pub const fn code_page(&self) -> u16 {
+        match self {
+            Self::Latin1 => 28591,
+            Self::Cp437 => 437,
+            Self::Latin2 => 28592,
+            Self::Cyrillic => 28595,
+            Self::Arabic => 28596,
+            Self::Greek => 28597,
+            Self::Hebrew => 28598,
+            Self::Latin9 => 28605,
+            Self::ShiftJis => 932,
+            Self::Windows1250 => 1250,
+            Self::Windows1251 => 1251,
+            Self::Windows1252 => 1252,
+            Self::Windows1256 => 1256,
+            Self::Utf16Be => 1201,
+            Self::Utf8 => 65001,
+            Self::Ascii => 20127,
+            Self::Big5 => 950,
+            Self::Gb18030 => 54936,
+            Self::EucKr => 51949,
+        }
+    }
+
+    fn encoding(&self) -> Option<&'static Encoding> {
+        match self {
+            // encoding_rs follows WHATWG, mapping Latin-1 and ASCII to Windows-1252, and has no
+            // code page 437
+            Self::Latin1 | Self::Ascii | Self::Cp437 => None,
+            Self::Latin2 => Some(encoding_rs::ISO_8859_2),
+            Self::Cyrillic => Some(encoding_rs::ISO_8859_5),
+            Self::Arabic => Some(encoding_rs::ISO_8859_6),
+            Self::Greek => Some(encoding_rs::ISO_8859_7),
+            Self::Hebrew => Some(encoding_rs::ISO_8859_8),
+            Self::Latin9 => Some(encoding_rs::ISO_8859_15),
+            Self::ShiftJis => Some(encoding_rs::SHIFT_JIS),
+            Self::Windows1250 => Some(encoding_rs::WINDOWS_1250),
+            Self::Windows1251 => Some(encoding_rs::WINDOWS_1251),
+            Self::Windows1252 => Some(encoding_rs::WINDOWS_1252),
+            Self::Windows1256 => Some(encoding_rs::WINDOWS_1256),
+            Self::Utf16Be => Some(encoding_rs::UTF_16BE),
+            Self::Utf8 => Some(encoding_rs::UTF_8),
+            Self::Big5 => Some(encoding_rs::BIG5),
+            Self::Gb18030 => Some(encoding_rs::GB18030),
+            Self::EucKr => Some(encoding_rs::EUC_KR),
+        }
+    }
+
+    /// Decodes bytes in the [CharacterSet], appending the Unicode text to `out`.
+    ///
+    /// Returns whether any malformed sequences were replaced with `U+FFFD`.
+    pub fn decode_into(&self, val: &[u8], out: &mut String) -> bool {
+        match self.encoding() {
+            Some(enc) => {
+                let (text, malformed) = enc.decode_without_bom_handling(val);
+                out.push_str(&text);
+                malformed
+            }
+            None => {
+                let ascii = matches!(self, Self::Ascii);
+                let cp437 = matches!(self, Self::Cp437);
+                out.extend(val.iter().map(|&c| match c {
+                    c if ascii && !c.is_ascii() => char::REPLACEMENT_CHARACTER,
+                    c if cp437 && !c.is_ascii() => CP437_HIGH[(c & 0x7f) as usize],
+                    c => c as char,
+                }));
+                ascii && !val.is_ascii()
+            }
+        }
+    }
+}
+
+impl Default for CharacterSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a decoded text payload, along with the original bytes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodedText {
+    text: String,
+    bytes: Vec<u8>,
+    malformed: bool,
+}
+
+impl DecodedText {
+    /// Gets the decoded Unicode text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Converts the [DecodedText] into the decoded Unicode [String].
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    /// Gets the original payload bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Gets whether any malformed sequences were replaced with `U+FFFD`.
+    pub const fn malformed(&self) -> bool {
+        self.malformed
+    }
+}
+
+/// Decodes scanned payloads into Unicode text.
+///
+/// When ECI transmission is enabled on the device, ECI designators are transmitted in the data as
+/// `\nnnnnn` escape sequences (with literal backslashes doubled), switching the character set
+/// for the data that follows.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TextDecoder {
+    default: CharacterSet,
+}
+
+impl TextDecoder {
+    /// Creates a new [TextDecoder].
+    pub const fn new() -> Self {
+        Self {
+            default: CharacterSet::new(),
+        }
+    }
+
+    /// Creates a new [TextDecoder] with a default [CharacterSet] for data without ECI
+    /// designators.
+    pub const fn with_default(default: CharacterSet) -> Self {
+        Self { default }
+    }
+
+    /// Gets the default [CharacterSet].
+    pub const fn default_charset(&self) -> CharacterSet {
+        self.default
+    }
+
+    /// Decodes a payload using the default [CharacterSet], without processing ECI escapes.
+    pub fn decode(&self, val: &[u8]) -> DecodedText {
+        let mut text = String::with_capacity(val.len());
+        let malformed = self.default.decode_into(val, &mut text);
+
+        DecodedText {
+            text,
+            bytes: val.into(),
+            malformed,
+        }
+    }
+
+    /// Decodes a payload containing ECI escape sequences.
+    pub fn decode_eci(&self, val: &[u8]) -> Result<DecodedText> {
+        let mut text = String::with_capacity(val.len());
+        let mut charset = self.default;
+        let mut segment = Vec::with_capacity(val.len());
+        let mut malformed = false;
+        let mut pos = 0;
+
+        while pos < val.len() {
+            match val[pos] {
+                ECI_ESCAPE if val.get(pos + 1) == Some(&ECI_ESCAPE) => {
+                    segment.push(ECI_ESCAPE);
+                    pos += 2;
+                }
+                ECI_ESCAPE => {
+                    let end = (pos + 1 + ECI_DIGITS).min(val.len());
+                    let escape = String::from_utf8_lossy(&val[pos..end]);
+                    let eci = val[pos + 1..end]
+                        .iter()
+                        .try_fold(0u32, |eci, &d| {
+                            d.is_ascii_digit().then(|| eci * 10 + u32::from(d - b'0'))
+                        })
+                        .filter(|_| end - pos == 1 + ECI_DIGITS)
+                        .ok_or_else(|| Error::InvalidData {
+                            data: escape.as_ref().into(),
+                            position: pos,
+                        })?;
+
+                    malformed |= charset.decode_into(&segment, &mut text);
+                    segment.clear();
+                    charset = CharacterSet::try_from_eci(eci)
+                        .map_err(|err| err.with_token(&escape, pos))?;
+                    pos += 1 + ECI_DIGITS;
+                }
+                c => {
+                    segment.push(c);
+                    pos += 1;
+                }
+            }
+        }
+        malformed |= charset.decode_into(&segment, &mut text);
+
+        Ok(DecodedText {
+            text,
+            bytes: val.into(),
+            malformed,
+        })
+    }
+
+    /// Decodes the payload of a [DecodedBarcode].
+    ///
+    /// ECI escape sequences are processed if the `AIM ID` modifier indicates ECI protocol.
+    pub fn decode_barcode(&self, val: &DecodedBarcode) -> Result<DecodedText> {
+        match val.aim_id() {
+            Some(aim) if aim.eci() => self.decode_eci(val.payload()),
+            _ => Ok(self.decode(val.payload())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::ReadFormat;
+
+    #[test]
+    fn test_valid() {
+        let latin1 = TextDecoder::new().decode(b"caf\xe9");
+        assert_eq!(latin1.text(), "café");
+        assert_eq!(latin1.bytes(), b"caf\xe9");
+        assert!(!latin1.malformed());
+
+        let sjis = TextDecoder::with_default(CharacterSet::ShiftJis).decode(b"\x93\xfa\x96\x7b");
+        assert_eq!(sjis.text(), "日本");
+
+        let eci = TextDecoder::new()
+            .decode_eci(b"caf\xe9 \\000020\x93\xfa\x96\x7b \\000026\xe4\xb8\xad\\\\")
+            .unwrap();
+        assert_eq!(eci.into_text(), "café 日本 中\\");
+
+        let gb = TextDecoder::new()
+            .decode_eci(b"\\000032\xd6\xd0\xce\xc4")
+            .unwrap();
+        assert_eq!(gb.text(), "中文");
+
+        let fmt = ReadFormat::new().with_aim_id(true);
+        let read = DecodedBarcode::parse(b"]Q2\\000026\xe4\xb8\xad", &fmt).unwrap();
+        assert_eq!(
+            TextDecoder::new()
+                .decode_barcode(&read)
+                .map(DecodedText::into_text),
+            Ok(String::from("中"))
+        );
+
+        let read = DecodedBarcode::parse(b"]Q1\\000026", &fmt).unwrap();
+        assert_eq!(
+            TextDecoder::new()
+                .decode_barcode(&read)
+                .map(DecodedText::into_text),
+            Ok(String::from("\\000026"))
+        );
+
+        let cp437 = TextDecoder::new()
+            .decode_eci(b"\\000000\x82\xe1 \\000002\xc9\xcd\xbb")
+            .unwrap();
+        assert_eq!(cp437.text(), "éß ╔═╗");
+        assert!(!cp437.malformed());
+        assert_eq!(CharacterSet::try_from_eci(0), Ok(CharacterSet::Cp437));
+
+        [
+            CharacterSet::Latin1,
+            CharacterSet::Cp437,
+            CharacterSet::ShiftJis,
+            CharacterSet::Utf8,
+            CharacterSet::Gb18030,
+            CharacterSet::EucKr,
+        ]
+        .into_iter()
+        .for_each(|cs| {
+            assert_eq!(CharacterSet::try_from_eci(cs.eci()), Ok(cs));
+            assert_eq!(CharacterSet::try_from_code_page(cs.code_page()), Ok(cs));
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        let dec = TextDecoder::new();
+
+        assert_eq!(
+            dec.decode_eci(b"abc\\00002"),
+            Err(Error::InvalidData {
+                data: "\\00002".into(),
+                position: 3
+            })
+        );
+        assert_eq!(
+            dec.decode_eci(b"\\00a026"),
+            Err(Error::InvalidData {
+                data: "\\00a026".into(),
+                position: 0
+            })
+        );
+        assert_eq!(
+            dec.decode_eci(b"a\\000899"),
+            Err(Error::out_of_range(899, 0, 170).with_token("\\000899", 1))
+        );
+        assert_eq!(
+            dec.decode_eci(b"\\000005"),
+            Err(Error::InvalidValue(5).with_token("\\000005", 0))
+        );
+
+        assert_eq!(
+            CharacterSet::try_from_eci(899),
+            Err(Error::out_of_range(899, 0, 170))
+        );
+        assert_eq!(CharacterSet::try_from_eci(5), Err(Error::InvalidValue(5)));
+        assert_eq!(
+            CharacterSet::try_from_code_page(65002),
+            Err(Error::out_of_range(65002, 437, 65001))
+        );
+        assert_eq!(
+            CharacterSet::try_from_code_page(1253),
+            Err(Error::InvalidValue(1253))
+        );
+
+        let ascii = TextDecoder::with_default(CharacterSet::Ascii).decode(b"a\xff");
+        assert!(ascii.malformed());
+        assert_eq!(ascii.text(), "a\u{fffd}");
+    }
+}
@@ -0,0 +1,620 @@
+//! Types and algorithms for parsing GS1 element strings (Application Identifier / value pairs).
+
+use alloc::{string::String, vec::Vec};
+
+use crate::result::{Error, Result};
+
+use super::{AimId, DecodedBarcode};
+
+/// Group Separator, transmitted by the device in place of `FNC1` separators.
+pub const GS1_SEPARATOR: u8 = 0x1d;
+/// Maximum length of a GS1 element string value.
+pub const GS1_MAX_VALUE: usize = 90;
+
+/// Represents the format of a GS1 Application Identifier value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Gs1Format {
+    /// Fixed-length numeric value.
+    Numeric(usize),
+    /// Fixed-length numeric value, with a GS1 mod-10 check digit.
+    NumericCheck(usize),
+    /// Variable-length numeric value, up to the maximum length.
+    NumericVar(usize),
+    /// Variable-length alphanumeric value, up to the maximum length.
+    Alphanumeric(usize),
+    /// Fixed-length `YYMMDD` date value.
+    Date,
+    /// Fixed-length numeric value, with implied decimal places set by the last AI digit.
+    Decimal(usize),
+    /// Variable-length numeric value, with implied decimal places set by the last AI digit.
+    DecimalVar(usize),
+}
+
+impl Gs1Format {
+    /// Gets whether the [Gs1Format] is a fixed length, not requiring a separator.
+    pub const fn fixed(&self) -> bool {
+        matches!(
+            self,
+            Self::Numeric(_) | Self::NumericCheck(_) | Self::Date | Self::Decimal(_)
+        )
+    }
+
+    /// Gets the (maximum) length of the [Gs1Format] value.
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Numeric(len)
+            | Self::NumericCheck(len)
+            | Self::NumericVar(len)
+            | Self::Alphanumeric(len)
+            | Self::Decimal(len)
+            | Self::DecimalVar(len) => *len,
+            Self::Date => 6,
+        }
+    }
+
+    /// Gets whether the [Gs1Format] has a zero length.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+const GS1_FORMATS: &[(&str, Gs1Format)] = &[
+    ("00", Gs1Format::NumericCheck(18)),
+    ("01", Gs1Format::NumericCheck(14)),
+    ("02", Gs1Format::NumericCheck(14)),
+    ("10", Gs1Format::Alphanumeric(20)),
+    ("11", Gs1Format::Date),
+    ("12", Gs1Format::Date),
+    ("13", Gs1Format::Date),
+    ("15", Gs1Format::Date),
+    ("16", Gs1Format::Date),
+    ("17", Gs1Format::Date),
+    ("20", Gs1Format::Numeric(2)),
+    ("21", Gs1Format::Alphanumeric(20)),
+    ("22", Gs1Format::Alphanumeric(20)),
+    ("235", Gs1Format::Alphanumeric(28)),
+    ("240", Gs1Format::Alphanumeric(30)),
+    ("241", Gs1Format::Alphanumeric(30)),
+    ("242", Gs1Format::NumericVar(6)),
+    ("243", Gs1Format::Alphanumeric(20)),
+    ("250", Gs1Format::Alphanumeric(30)),
+    ("251", Gs1Format::Alphanumeric(30)),
+    ("253", Gs1Format::Alphanumeric(30)),
+    ("254", Gs1Format::Alphanumeric(20)),
+    ("255", Gs1Format::NumericVar(25)),
+    ("30", Gs1Format::NumericVar(8)),
+    ("37", Gs1Format::NumericVar(8)),
+    ("400", Gs1Format::Alphanumeric(30)),
+    ("401", Gs1Format::Alphanumeric(30)),
+    ("402", Gs1Format::NumericCheck(17)),
+    ("403", Gs1Format::Alphanumeric(30)),
+    ("410", Gs1Format::NumericCheck(13)),
+    ("411", Gs1Format::NumericCheck(13)),
+    ("412", Gs1Format::NumericCheck(13)),
+    ("413", Gs1Format::NumericCheck(13)),
+    ("414", Gs1Format::NumericCheck(13)),
+    ("415", Gs1Format::NumericCheck(13)),
+    ("416", Gs1Format::NumericCheck(13)),
+    ("417", Gs1Format::NumericCheck(13)),
+    ("420", Gs1Format::Alphanumeric(20)),
+    ("421", Gs1Format::Alphanumeric(12)),
+    ("422", Gs1Format::Numeric(3)),
+    ("423", Gs1Format::NumericVar(15)),
+    ("424", Gs1Format::Numeric(3)),
+    ("425", Gs1Format::NumericVar(15)),
+    ("426", Gs1Format::Numeric(3)),
+    ("427", Gs1Format::Alphanumeric(3)),
+    ("7001", Gs1Format::Numeric(13)),
+    ("7002", Gs1Format::Alphanumeric(30)),
+    ("7003", Gs1Format::Numeric(10)),
+    ("7004", Gs1Format::NumericVar(4)),
+    ("7006", Gs1Format::Date),
+    ("7007", Gs1Format::NumericVar(12)),
+    ("8001", Gs1Format::Numeric(14)),
+    ("8002", Gs1Format::Alphanumeric(20)),
+    ("8003", Gs1Format::Alphanumeric(30)),
+    ("8004", Gs1Format::Alphanumeric(30)),
+    ("8005", Gs1Format::Numeric(6)),
+    ("8006", Gs1Format::Numeric(18)),
+    ("8007", Gs1Format::Alphanumeric(34)),
+    ("8008", Gs1Format::NumericVar(12)),
+    ("8017", Gs1Format::NumericCheck(18)),
+    ("8018", Gs1Format::NumericCheck(18)),
+    ("8020", Gs1Format::Alphanumeric(25)),
+    ("8200", Gs1Format::Alphanumeric(70)),
+    ("90", Gs1Format::Alphanumeric(30)),
+];
+
+/// Gets the number of digits in the Application Identifier starting the element string.
+///
+/// The AI length is determined by its first two digits.
+pub fn gs1_ai_len(val: &[u8]) -> Result<usize> {
+    let len = match val {
+        [b'0', b'0'..=b'4', ..] | [b'1', b'0'..=b'7', ..] | [b'2', b'0'..=b'2', ..] => 2,
+        [b'3', b'0' | b'7', ..] | [b'9', b'0'..=b'9', ..] => 2,
+        [b'2', b'3'..=b'5', ..] | [b'4', b'0'..=b'2', ..] | [b'7', b'1', ..] => 3,
+        [b'3', b'1'..=b'6' | b'9', ..] | [b'4', b'3', ..] | [b'7', b'0' | b'2', ..] => 4,
+        [b'8', b'0'..=b'2', ..] => 4,
+        _ => return Err(Error::InvalidVariant),
+    };
+
+    if val.len() >= len && val[..len].iter().all(u8::is_ascii_digit) {
+        Ok(len)
+    } else {
+        Err(Error::InvalidVariant)
+    }
+}
+
+/// Gets the [Gs1Format] for an Application Identifier.
+///
+/// Unknown AIs with a predefined length (per the GS1 General Specifications) are fixed-length
+/// numeric, all others are treated as variable-length alphanumeric.
+pub fn gs1_format(ai: &str) -> Gs1Format {
+    let ai_bytes = ai.as_bytes();
+
+    if let Some((_, fmt)) = GS1_FORMATS.iter().find(|(a, _)| *a == ai) {
+        *fmt
+    } else {
+        match ai_bytes {
+            [b'3', b'1'..=b'6', _, _] => Gs1Format::Decimal(6),
+            [b'3', b'9', b'0' | b'2', _] => Gs1Format::DecimalVar(15),
+            [b'3', b'9', b'1' | b'3', _] => Gs1Format::NumericVar(18),
+            [b'3', b'9', b'4', _] => Gs1Format::Decimal(4),
+            [b'3', b'9', b'5', _] => Gs1Format::Decimal(6),
+            [b'0', b'3'] => Gs1Format::Numeric(14),
+            [b'0', b'4'] => Gs1Format::Numeric(16),
+            [b'1', b'8' | b'9'] => Gs1Format::Numeric(6),
+            [b'4', b'1', _] => Gs1Format::Numeric(13),
+            _ => Gs1Format::Alphanumeric(GS1_MAX_VALUE),
+        }
+    }
+}
+
+/// Calculates the GS1 mod-10 check digit for a string of ASCII digits.
+pub fn gs1_check_digit(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            let d = (d - b'0') as u32;
+            if i % 2 == 0 {
+                d * 3
+            } else {
+                d
+            }
+        })
+        .sum();
+
+    ((10 - (sum % 10)) % 10) as u8 + b'0'
+}
+
+/// Represents a GS1 `YYMMDD` date value.
+///
+/// Not ordered, since the two-digit year only resolves to a full year with a
+/// [reference](Self::year).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Gs1Date {
+    yy: u8,
+    month: u8,
+    day: u8,
+}
+
+impl Gs1Date {
+    /// Creates a new [Gs1Date].
+    pub const fn new() -> Self {
+        Self {
+            yy: 0,
+            month: 1,
+            day: 1,
+        }
+    }
+
+    /// Gets the two-digit year of the [Gs1Date], as encoded.
+    pub const fn yy(&self) -> u8 {
+        self.yy
+    }
+
+    /// Gets the full year of the [Gs1Date], relative to the `reference` year (e.g. the current
+    /// year).
+    ///
+    /// Follows the GS1 General Specifications sliding window: years more than 50 years ahead of
+    /// `reference` are in the previous century, years 50 or more years behind are in the next.
+    /// For a `reference` before year `100`, there is no previous century, so the year is kept in
+    /// the first century.
+    pub const fn year(&self, reference: u16) -> u16 {
+        let century = reference - reference % 100;
+        let diff = self.yy as i16 - (reference % 100) as i16;
+
+        match diff {
+            51.. => century.saturating_sub(100) + self.yy as u16,
+            ..=-50 => century + 100 + self.yy as u16,
+            _ => century + self.yy as u16,
+        }
+    }
+
+    /// Gets the month of the [Gs1Date].
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets the day of the [Gs1Date].
+    ///
+    /// Returns `None` for day `00`, which GS1 defines as the last day of the month.
+    pub const fn day(&self) -> Option<u8> {
+        match self.day {
+            0 => None,
+            d => Some(d),
+        }
+    }
+
+    /// Attempts to create a [Gs1Date] from ASCII `YYMMDD` digits.
+    ///
+    /// The century is unknown while parsing, so `29` February is accepted for every year
+    /// divisible by four, including `00`.
+    pub fn try_from_yymmdd(val: &[u8]) -> Result<Self> {
+        if val.len() != 6 || !val.iter().all(u8::is_ascii_digit) {
+            return Err(Error::InvalidVariant);
+        }
+
+        let num = |i: usize| (val[i] - b'0') * 10 + (val[i + 1] - b'0');
+        let (yy, month, day) = (num(0), num(2), num(4));
+
+        let max_day = match month {
+            2 if yy.is_multiple_of(4) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => return Err(Error::out_of_range(month as usize, 1, 12)),
+        };
+
+        if day <= max_day {
+            Ok(Self { yy, month, day })
+        } else {
+            Err(Error::out_of_range(day as usize, 0, max_day as usize))
+        }
+    }
+}
+
+impl Default for Gs1Date {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a parsed GS1 element value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Gs1Value {
+    /// Numeric digits (including any check digit).
+    Numeric(String),
+    /// Alphanumeric characters.
+    Alphanumeric(String),
+    /// Date value.
+    Date(Gs1Date),
+    /// Numeric value with implied decimal places: `value / 10^decimals`.
+    Decimal { value: u64, decimals: u8 },
+}
+
+impl Gs1Value {
+    /// Gets the [Gs1Value] as a string, if the value is numeric or alphanumeric.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Numeric(s) | Self::Alphanumeric(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Gets the [Gs1Value] as a [Gs1Date], if the value is a date.
+    pub const fn as_date(&self) -> Option<Gs1Date> {
+        match self {
+            Self::Date(d) => Some(*d),
+            _ => None,
+        }
+    }
+}
+
+/// Represents a single GS1 Application Identifier and value pair.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gs1Element {
+    ai: String,
+    value: Gs1Value,
+}
+
+impl Gs1Element {
+    /// Gets the Application Identifier of the [Gs1Element].
+    pub fn ai(&self) -> &str {
+        &self.ai
+    }
+
+    /// Gets the [Gs1Value] of the [Gs1Element].
+    pub fn value(&self) -> &Gs1Value {
+        &self.value
+    }
+}
+
+/// Represents a parsed GS1 element string.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Gs1Data {
+    elements: Vec<Gs1Element>,
+}
+
+impl Gs1Data {
+    /// Creates a new [Gs1Data].
+    pub const fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+
+    /// Parses a GS1 element string from a decoded barcode payload.
+    ///
+    /// A leading GS1 `AIM ID` (`]C1`, `]d2`, `]Q3`, `]e0`, `]z1`) or `FNC1` separator is skipped.
+    ///
+    /// On malformed data, returns [InvalidData](Error::InvalidData) with the offending element,
+    /// up to the next separator, and its byte offset. Out of range dates are returned with the
+    /// same [token](Error::InvalidToken) context.
+    pub fn parse(val: &[u8]) -> Result<Self> {
+        let mut pos = match AimId::try_from(val) {
+            Ok(aim) if aim.gs1() => AimId::LEN,
+            Ok(_) => return Err(Error::InvalidVariant),
+            Err(_) => 0,
+        };
+        let mut elements = Vec::new();
+
+        while pos < val.len() {
+            if val[pos] == GS1_SEPARATOR {
+                pos += 1;
+                continue;
+            }
+
+            let (element, len) = Self::parse_element(&val[pos..]).map_err(|err| {
+                let end = val[pos..]
+                    .iter()
+                    .position(|&c| c == GS1_SEPARATOR)
+                    .map_or(val.len(), |n| pos + n);
+                let data = String::from_utf8_lossy(&val[pos..end]);
+
+                match err {
+                    Error::InvalidVariant => Error::InvalidData {
+                        data: data.into_owned(),
+                        position: pos,
+                    },
+                    err => err.with_token(&data, pos),
+                }
+            })?;
+
+            elements.push(element);
+            pos += len;
+        }
+
+        if elements.is_empty() {
+            Err(Error::InvalidVariant)
+        } else {
+            Ok(Self { elements })
+        }
+    }
+
+    fn parse_element(val: &[u8]) -> Result<(Gs1Element, usize)> {
+        let ai_len = gs1_ai_len(val)?;
+        // AI is validated as ASCII digits
+        let ai = String::from_utf8_lossy(&val[..ai_len]).into_owned();
+        let fmt = gs1_format(&ai);
+        let rem = &val[ai_len..];
+
+        let len = if fmt.fixed() {
+            fmt.len()
+        } else {
+            rem.iter()
+                .position(|&c| c == GS1_SEPARATOR)
+                .unwrap_or(rem.len())
+        };
+        let value = rem.get(..len).ok_or(Error::InvalidVariant)?;
+
+        if value.is_empty() || value.len() > fmt.len() {
+            return Err(Error::InvalidVariant);
+        }
+
+        let numeric = value.iter().all(u8::is_ascii_digit);
+        let decimals = ai.as_bytes()[ai_len - 1] - b'0';
+
+        let value = match fmt {
+            Gs1Format::Numeric(_) | Gs1Format::NumericVar(_) if numeric => {
+                Gs1Value::Numeric(String::from_utf8_lossy(value).into_owned())
+            }
+            Gs1Format::NumericCheck(len) if numeric => {
+                if gs1_check_digit(&value[..len - 1]) != value[len - 1] {
+                    return Err(Error::InvalidVariant);
+                }
+                Gs1Value::Numeric(String::from_utf8_lossy(value).into_owned())
+            }
+            Gs1Format::Alphanumeric(_) if value.iter().all(u8::is_ascii_graphic) => {
+                Gs1Value::Alphanumeric(String::from_utf8_lossy(value).into_owned())
+            }
+            Gs1Format::Date => Gs1Value::Date(Gs1Date::try_from_yymmdd(value)?),
+            Gs1Format::Decimal(_) | Gs1Format::DecimalVar(_) if numeric => Gs1Value::Decimal {
+                value: core::str::from_utf8(value)
+                    .map_err(|_| Error::InvalidVariant)?
+                    .parse::<u64>()
+                    .map_err(|_| Error::InvalidVariant)?,
+                decimals,
+            },
+            _ => return Err(Error::InvalidVariant),
+        };
+
+        Ok((Gs1Element { ai, value }, ai_len + len))
+    }
+
+    /// Gets the list of [Gs1Element]s.
+    pub fn elements(&self) -> &[Gs1Element] {
+        &self.elements
+    }
+
+    /// Gets the [Gs1Value] of the first element with a matching Application Identifier.
+    pub fn get(&self, ai: &str) -> Option<&Gs1Value> {
+        self.elements.iter().find(|e| e.ai == ai).map(|e| &e.value)
+    }
+
+    /// Gets the Serial Shipping Container Code (AI `00`).
+    pub fn sscc(&self) -> Option<&str> {
+        self.get("00").and_then(Gs1Value::as_str)
+    }
+
+    /// Gets the Global Trade Item Number (AI `01`).
+    pub fn gtin(&self) -> Option<&str> {
+        self.get("01").and_then(Gs1Value::as_str)
+    }
+
+    /// Gets the batch or lot number (AI `10`).
+    pub fn batch(&self) -> Option<&str> {
+        self.get("10").and_then(Gs1Value::as_str)
+    }
+
+    /// Gets the expiration date (AI `17`).
+    pub fn expiration(&self) -> Option<Gs1Date> {
+        self.get("17").and_then(Gs1Value::as_date)
+    }
+
+    /// Gets the serial number (AI `21`).
+    pub fn serial(&self) -> Option<&str> {
+        self.get("21").and_then(Gs1Value::as_str)
+    }
+}
+
+impl TryFrom<&[u8]> for Gs1Data {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        Self::parse(val)
+    }
+}
+
+impl TryFrom<&DecodedBarcode> for Gs1Data {
+    type Error = Error;
+
+    fn try_from(val: &DecodedBarcode) -> Result<Self> {
+        match val.aim_id() {
+            Some(aim) if !aim.gs1() => Err(Error::InvalidVariant),
+            _ => Self::parse(val.payload()),
+        }
+    }
+}
+
+impl TryFrom<&str> for Gs1Data {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        Self::parse(val.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::ReadFormat;
+
+    #[test]
+    fn test_valid() {
+        let data = Gs1Data::try_from("]C1010950110153000317260630\x1d10ABC123\x1d21SN-42").unwrap();
+
+        assert_eq!(data.elements().len(), 4);
+        assert_eq!(data.gtin(), Some("09501101530003"));
+        assert_eq!(
+            data.expiration(),
+            Some(Gs1Date {
+                yy: 26,
+                month: 6,
+                day: 30
+            })
+        );
+        assert_eq!(data.batch(), Some("ABC123"));
+        assert_eq!(data.serial(), Some("SN-42"));
+
+        let data = Gs1Data::try_from("\x1d00106141411234567897").unwrap();
+        assert_eq!(data.sscc(), Some("106141411234567897"));
+
+        let data = Gs1Data::try_from("3103000250\x1d3922499").unwrap();
+        assert_eq!(
+            data.get("3103"),
+            Some(&Gs1Value::Decimal {
+                value: 250,
+                decimals: 3
+            })
+        );
+        assert_eq!(
+            data.get("3922"),
+            Some(&Gs1Value::Decimal {
+                value: 499,
+                decimals: 2
+            })
+        );
+
+        let fmt = ReadFormat::new().with_aim_id(true);
+        let read = DecodedBarcode::parse(b"]d20109501101530003", &fmt).unwrap();
+        assert_eq!(
+            Gs1Data::try_from(&read).map(|d| d.gtin().map(String::from)),
+            Ok(Some("09501101530003".into()))
+        );
+
+        let read = DecodedBarcode::parse(b"]d10109501101530003", &fmt).unwrap();
+        assert_eq!(Gs1Data::try_from(&read), Err(Error::InvalidVariant));
+
+        let date = Gs1Date::try_from_yymmdd(b"991200").unwrap();
+        assert_eq!((date.yy(), date.month(), date.day()), (99, 12, None));
+        assert_eq!(date.year(2026), 1999);
+        assert_eq!(date.year(2060), 2099);
+
+        let date = Gs1Date::try_from_yymmdd(b"760229").unwrap();
+        assert_eq!(date.year(2026), 2076);
+        assert_eq!(date.year(2025), 1976);
+        assert_eq!(
+            Gs1Date::try_from_yymmdd(b"150101").unwrap().year(2080),
+            2115
+        );
+
+        let date = Gs1Date::try_from_yymmdd(b"991231").unwrap();
+        assert_eq!(date.year(20), 99);
+        assert_eq!(date.year(0), 99);
+    }
+
+    #[test]
+    fn test_check_digit() {
+        assert_eq!(gs1_check_digit(b"0950110153000"), b'3');
+        assert_eq!(gs1_check_digit(b"10614141123456789"), b'7');
+        assert_eq!(gs1_check_digit(b"400638133393"), b'1');
+    }
+
+    #[test]
+    fn test_invalid() {
+        let invalid = |data: &str, position| Error::InvalidData {
+            data: data.into(),
+            position,
+        };
+
+        [
+            ("", Error::InvalidVariant),
+            ("]Q1http://example.com", Error::InvalidVariant),
+            ("0109501101530004", invalid("0109501101530004", 0)),
+            ("010950110153000", invalid("010950110153000", 0)),
+            ("0109501101530003171313", invalid("171313", 16)),
+            (
+                "010950110153000317261301",
+                Error::out_of_range(13, 1, 12).with_token("17261301", 16),
+            ),
+            (
+                "0109501101530003172602300",
+                Error::out_of_range(30, 0, 28).with_token("172602300", 16),
+            ),
+            (
+                "01095011015300031012345678901234567890123",
+                invalid("1012345678901234567890123", 16),
+            ),
+            ("10ABC\x1d99", invalid("99", 6)),
+            ("10AB C", invalid("10AB C", 0)),
+            ("55123", invalid("55123", 0)),
+        ]
+        .into_iter()
+        .for_each(|(s, err)| {
+            assert_eq!(Gs1Data::try_from(s), Err(err), "{s:?}");
+        });
+    }
+}
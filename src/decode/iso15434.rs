@@ -0,0 +1,248 @@
+//! Types and algorithms for unwrapping ISO/IEC 15434 message envelopes.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::result::{Error, Result};
+
+/// Message header starting an ISO/IEC 15434 envelope (`[)>` RS).
+pub const ISO15434_HEADER: &[u8] = b"[)>\x1e";
+/// Record Separator, terminating each format envelope.
+pub const ISO15434_RS: u8 = 0x1e;
+/// Group Separator, separating data elements.
+pub const ISO15434_GS: u8 = 0x1d;
+/// End of Transmission, terminating the message.
+pub const ISO15434_EOT: u8 = 0x04;
+
+/// Represents an ANSI MH10.8.2 Data Identifier and value pair (format `06`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DataIdentifier {
+    identifier: String,
+    value: String,
+}
+
+impl DataIdentifier {
+    /// Gets the Data Identifier (e.g. `P`, `1P`, `25S`).
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Gets the value of the data element.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<&[u8]> for DataIdentifier {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        // Data Identifiers are up to three digits, followed by an uppercase letter
+        let digits = val.iter().take_while(|c| c.is_ascii_digit()).count();
+
+        match val.get(digits) {
+            Some(c) if digits <= 3 && c.is_ascii_uppercase() => Ok(Self {
+                identifier: String::from_utf8_lossy(&val[..=digits]).into_owned(),
+                value: String::from_utf8_lossy(&val[digits + 1..]).into_owned(),
+            }),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+/// Represents a single format envelope in an ISO/IEC 15434 message.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Iso15434Format {
+    format: u8,
+    header: Vec<u8>,
+    elements: Vec<Vec<u8>>,
+}
+
+impl Iso15434Format {
+    /// Gets the two-digit format indicator (e.g. `6` for format `06`).
+    pub const fn format(&self) -> u8 {
+        self.format
+    }
+
+    /// Gets the format header data (e.g. version information for formats `01`, `02`, `07`,
+    /// `08`), if any.
+    pub fn header(&self) -> &[u8] {
+        &self.header
+    }
+
+    /// Gets the raw data elements of the format envelope.
+    pub fn elements(&self) -> &[Vec<u8>] {
+        &self.elements
+    }
+
+    /// Parses the data elements as [DataIdentifier]s.
+    ///
+    /// Only valid for format `06` (Data Identifiers) envelopes.
+    pub fn data_identifiers(&self) -> Result<Vec<DataIdentifier>> {
+        if self.format != 6 {
+            return Err(Error::InvalidValue(self.format as usize));
+        }
+
+        self.elements
+            .iter()
+            .map(|e| DataIdentifier::try_from(e.as_slice()))
+            .collect()
+    }
+
+    /// Gets the value of the first [DataIdentifier] matching `id` (format `06` only).
+    pub fn get(&self, id: &str) -> Option<String> {
+        self.data_identifiers()
+            .ok()?
+            .into_iter()
+            .find(|d| d.identifier() == id)
+            .map(|d| d.value)
+    }
+
+    fn parse(val: &[u8]) -> Result<Self> {
+        let format = val
+            .get(..2)
+            .filter(|f| f.iter().all(u8::is_ascii_digit))
+            .map(|f| (f[0] - b'0') * 10 + (f[1] - b'0'))
+            .ok_or(Error::InvalidVariant)?;
+
+        let mut parts = val[2..].split(|&c| c == ISO15434_GS);
+        // Any data between the format indicator and the first GS is format header data
+        let header = parts.next().unwrap_or_default().into();
+        let elements = parts.filter(|e| !e.is_empty()).map(Vec::from).collect();
+
+        Ok(Self {
+            format,
+            header,
+            elements,
+        })
+    }
+}
+
+/// Represents an ISO/IEC 15434 message envelope.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Iso15434Message {
+    formats: Vec<Iso15434Format>,
+}
+
+impl Iso15434Message {
+    /// Creates a new [Iso15434Message].
+    pub const fn new() -> Self {
+        Self {
+            formats: Vec::new(),
+        }
+    }
+
+    /// Gets whether the payload starts with an ISO/IEC 15434 message header.
+    pub fn detect(val: &[u8]) -> bool {
+        val.starts_with(ISO15434_HEADER)
+    }
+
+    /// Parses an [Iso15434Message] from a decoded barcode payload.
+    ///
+    /// A missing `EOT` message trailer is tolerated, since some hosts strip control characters.
+    pub fn parse(val: &[u8]) -> Result<Self> {
+        let rem = val
+            .strip_prefix(ISO15434_HEADER)
+            .ok_or(Error::InvalidVariant)?;
+        let rem = rem.strip_suffix(&[ISO15434_EOT]).unwrap_or(rem);
+
+        let formats = rem
+            .split(|&c| c == ISO15434_RS)
+            .filter(|f| !f.is_empty())
+            .map(Iso15434Format::parse)
+            .collect::<Result<Vec<_>>>()?;
+
+        if formats.is_empty() {
+            Err(Error::InvalidVariant)
+        } else {
+            Ok(Self { formats })
+        }
+    }
+
+    /// Gets the list of [Iso15434Format] envelopes.
+    pub fn formats(&self) -> &[Iso15434Format] {
+        &self.formats
+    }
+
+    /// Gets the first [Iso15434Format] envelope with a matching format indicator.
+    pub fn format(&self, format: u8) -> Option<&Iso15434Format> {
+        self.formats.iter().find(|f| f.format == format)
+    }
+}
+
+impl TryFrom<&[u8]> for Iso15434Message {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        Self::parse(val)
+    }
+}
+
+impl TryFrom<&str> for Iso15434Message {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        Self::parse(val.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let msg = Iso15434Message::try_from(
+            "[)>\x1e06\x1dP12345-A\x1d1PABC\x1dQ100\x1d25SUN123456789\x1e\x04",
+        )
+        .unwrap();
+
+        assert!(Iso15434Message::detect(b"[)>\x1e06"));
+        assert_eq!(msg.formats().len(), 1);
+
+        let fmt = msg.format(6).unwrap();
+        let ids = fmt.data_identifiers().unwrap();
+
+        assert!(fmt.header().is_empty());
+        assert_eq!(ids.len(), 4);
+        assert_eq!(ids[3].identifier(), "25S");
+        assert_eq!(ids[3].value(), "UN123456789");
+        assert_eq!(fmt.get("P").as_deref(), Some("12345-A"));
+        assert_eq!(fmt.get("Q").as_deref(), Some("100"));
+
+        let msg =
+            Iso15434Message::try_from("[)>\x1e05\x1d0109501101530003\x1e07ABC\x1dfree text\x1e")
+                .unwrap();
+
+        assert_eq!(msg.formats().len(), 2);
+        assert_eq!(
+            msg.format(5).unwrap().elements(),
+            [b"0109501101530003".to_vec()]
+        );
+        assert_eq!(msg.format(7).unwrap().header(), b"ABC");
+        assert_eq!(
+            msg.format(5).unwrap().data_identifiers(),
+            Err(Error::InvalidValue(5))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        [
+            "",
+            "[)>",
+            "[)>\x1e",
+            "[)>\x1e\x04",
+            "[)>\x1eAB\x1dP1\x1e\x04",
+        ]
+        .into_iter()
+        .for_each(|s| {
+            assert_eq!(Iso15434Message::try_from(s), Err(Error::InvalidVariant));
+        });
+
+        let msg = Iso15434Message::try_from("[)>\x1e06\x1d1234P\x1e\x04").unwrap();
+        assert_eq!(
+            msg.format(6).unwrap().data_identifiers(),
+            Err(Error::InvalidVariant)
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! Types and algorithms for reassembling structured append messages split across 2D symbols.
+
+use alloc::vec::Vec;
+
+use crate::result::{Error, Result};
+
+/// Maximum number of symbols in a QR Code structured append message.
+pub const STRUCTURED_APPEND_MAX: u8 = 16;
+/// Default timeout (in milliseconds) for collecting all fragments of a message.
+pub const STRUCTURED_APPEND_TIMEOUT_MS: u64 = 5_000;
+
+/// Represents a single symbol of a structured append message.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StructuredAppendFragment {
+    index: u8,
+    total: u8,
+    parity: u8,
+    data: Vec<u8>,
+}
+
+impl StructuredAppendFragment {
+    /// Attempts to create a new [StructuredAppendFragment].
+    ///
+    /// - `index`: zero-based position of the symbol in the message
+    /// - `total`: total number of symbols in the message
+    /// - `parity`: message parity (XOR of all message data bytes)
+    pub fn try_new(index: u8, total: u8, parity: u8, data: &[u8]) -> Result<Self> {
+        if !(1..=STRUCTURED_APPEND_MAX).contains(&total) {
+            Err(Error::out_of_range(
+                total as usize,
+                1,
+                STRUCTURED_APPEND_MAX as usize,
+            ))
+        } else if index >= total {
+            Err(Error::out_of_range(index as usize, 0, total as usize - 1))
+        } else {
+            Ok(Self {
+                index,
+                total,
+                parity,
+                data: data.into(),
+            })
+        }
+    }
+
+    /// Attempts to create a [StructuredAppendFragment] from the QR Code structured append
+    /// header: symbol sequence indicator (position and total-1 nibbles), followed by parity.
+    pub fn try_from_qr_header(sequence: u8, parity: u8, data: &[u8]) -> Result<Self> {
+        Self::try_new(sequence >> 4, (sequence & 0xf) + 1, parity, data)
+    }
+
+    /// Gets the zero-based position of the symbol in the message.
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Gets the total number of symbols in the message.
+    pub const fn total(&self) -> u8 {
+        self.total
+    }
+
+    /// Gets the message parity.
+    pub const fn parity(&self) -> u8 {
+        self.parity
+    }
+
+    /// Gets the symbol data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Buffers [StructuredAppendFragment]s until the complete message is received.
+///
+/// The caller provides a monotonic timestamp (in milliseconds) with each fragment, so the buffer
+/// can be used without `std`. A partial message is discarded when it times out, or when a
+/// fragment belonging to a different message (total or parity mismatch) arrives.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuredAppend {
+    fragments: Vec<Option<Vec<u8>>>,
+    parity: u8,
+    started: u64,
+    timeout_ms: u64,
+    check_parity: bool,
+}
+
+impl StructuredAppend {
+    /// Creates a new [StructuredAppend].
+    pub const fn new() -> Self {
+        Self {
+            fragments: Vec::new(),
+            parity: 0,
+            started: 0,
+            timeout_ms: STRUCTURED_APPEND_TIMEOUT_MS,
+            check_parity: true,
+        }
+    }
+
+    /// Gets the timeout (in milliseconds) for collecting all fragments.
+    pub const fn timeout_ms(&self) -> u64 {
+        self.timeout_ms
+    }
+
+    /// Builder function that sets the timeout (in milliseconds) for collecting all fragments.
+    pub fn with_timeout_ms(mut self, val: u64) -> Self {
+        self.timeout_ms = val;
+        self
+    }
+
+    /// Builder function that sets whether to verify the message parity on completion.
+    ///
+    /// Parity is only defined for QR Code, disable for other symbologies.
+    pub fn with_parity_check(mut self, val: bool) -> Self {
+        self.check_parity = val;
+        self
+    }
+
+    /// Gets whether a partial message is buffered.
+    pub fn is_pending(&self) -> bool {
+        !self.fragments.is_empty()
+    }
+
+    /// Gets the number of fragments received for the pending message.
+    pub fn received(&self) -> usize {
+        self.fragments.iter().filter(|f| f.is_some()).count()
+    }
+
+    /// Discards any partial message.
+    pub fn reset(&mut self) {
+        self.fragments.clear();
+    }
+
+    /// Discards the partial message if it has timed out, returning whether it was discarded.
+    pub fn expire(&mut self, now_ms: u64) -> bool {
+        let expired = self.is_pending() && now_ms.saturating_sub(self.started) > self.timeout_ms;
+        if expired {
+            self.reset();
+        }
+        expired
+    }
+
+    /// Adds a [StructuredAppendFragment] to the buffer.
+    ///
+    /// Returns the complete message once every fragment has been received, `None` otherwise.
+    /// Returns an error for a duplicate fragment, or if the completed message fails the parity
+    /// check.
+    pub fn push(
+        &mut self,
+        fragment: StructuredAppendFragment,
+        now_ms: u64,
+    ) -> Result<Option<Vec<u8>>> {
+        self.expire(now_ms);
+
+        if self.fragments.len() != fragment.total as usize || self.parity != fragment.parity {
+            self.fragments = vec![None; fragment.total as usize];
+            self.parity = fragment.parity;
+            self.started = now_ms;
+        }
+
+        let slot = &mut self.fragments[fragment.index as usize];
+        if slot.is_some() {
+            return Err(Error::InvalidValue(fragment.index as usize));
+        }
+        *slot = Some(fragment.data);
+
+        if self.fragments.iter().any(Option::is_none) {
+            return Ok(None);
+        }
+
+        let msg: Vec<u8> = self.fragments.drain(..).flatten().flatten().collect();
+        let parity = msg.iter().fold(0, |acc, c| acc ^ c);
+
+        if self.check_parity && parity != self.parity {
+            Err(Error::InvalidValue(parity as usize))
+        } else {
+            Ok(Some(msg))
+        }
+    }
+}
+
+impl Default for StructuredAppend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragments(msg: &[u8], total: u8) -> Vec<StructuredAppendFragment> {
+        let parity = msg.iter().fold(0, |acc, c| acc ^ c);
+        let size = msg.len().div_ceil(total as usize);
+
+        msg.chunks(size)
+            .enumerate()
+            .map(|(i, c)| StructuredAppendFragment::try_new(i as u8, total, parity, c).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_valid() {
+        let msg = b"structured append message split across symbols";
+        let mut buf = StructuredAppend::new();
+        let mut frags = fragments(msg, 3);
+
+        assert_eq!(StructuredAppend::default(), buf);
+        assert_eq!(buf.timeout_ms(), STRUCTURED_APPEND_TIMEOUT_MS);
+
+        frags.reverse();
+
+        assert_eq!(buf.push(frags[0].clone(), 0), Ok(None));
+        assert_eq!(buf.push(frags[1].clone(), 10), Ok(None));
+        assert_eq!(buf.received(), 2);
+        assert_eq!(buf.push(frags[2].clone(), 20), Ok(Some(msg.to_vec())));
+        assert!(!buf.is_pending());
+
+        let frag = StructuredAppendFragment::try_from_qr_header(0x12, 0xab, b"x").unwrap();
+        assert_eq!((frag.index(), frag.total(), frag.parity()), (1, 3, 0xab));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let msg = b"timed out message";
+        let mut buf = StructuredAppend::new().with_timeout_ms(100);
+        let frags = fragments(msg, 2);
+
+        assert_eq!(buf.push(frags[0].clone(), 0), Ok(None));
+        assert!(!buf.expire(100));
+        assert_eq!(buf.push(frags[1].clone(), 101), Ok(None));
+        assert_eq!(buf.received(), 1);
+        assert!(buf.expire(202));
+        assert!(!buf.is_pending());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            StructuredAppendFragment::try_new(0, 0, 0, b""),
+            Err(Error::out_of_range(0, 1, 16))
+        );
+        assert_eq!(
+            StructuredAppendFragment::try_new(0, 17, 0, b""),
+            Err(Error::out_of_range(17, 1, 16))
+        );
+        assert_eq!(
+            StructuredAppendFragment::try_new(2, 2, 0, b""),
+            Err(Error::out_of_range(2, 0, 1))
+        );
+
+        let mut buf = StructuredAppend::new();
+        let frags = fragments(b"duplicate", 2);
+
+        assert_eq!(buf.push(frags[0].clone(), 0), Ok(None));
+        assert_eq!(buf.push(frags[0].clone(), 0), Err(Error::InvalidValue(0)));
+
+        let bad = StructuredAppendFragment::try_new(1, 2, frags[1].parity(), b"XXXX").unwrap();
+        assert!(buf.push(bad.clone(), 0).is_err());
+
+        let mut buf = StructuredAppend::new().with_parity_check(false);
+        assert_eq!(buf.push(frags[0].clone(), 0), Ok(None));
+        assert_eq!(buf.push(bad, 0), Ok(Some(b"dupliXXXX".to_vec())));
+    }
+}
@@ -0,0 +1,412 @@
+use crate::result::{Error, Result};
+
+/// Represents barcode symbologies decoded by the BCS device.
+///
+/// Each variant maps to the one-character Honeywell `Code ID` and the `AIM ID` code character
+/// transmitted by the device.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Symbology {
+    AustralianPost,
+    Aztec,
+    BritishPost,
+    CanadianPost,
+    ChinaPost,
+    Codabar,
+    CodablockA,
+    CodablockF,
+    Code11,
+    Code128,
+    Code32,
+    Code39,
+    Code49,
+    Code93,
+    DataMatrix,
+    Ean13,
+    Ean8,
+    Gs1_128,
+    Gs1Composite,
+    Gs1DataBar,
+    Gs1DataBarExpanded,
+    Gs1DataBarLimited,
+    HanXin,
+    InfoMail,
+    IntelligentMail,
+    Interleaved2of5,
+    JapanPost,
+    Kix,
+    KoreaPost,
+    Matrix2of5,
+    MaxiCode,
+    MicroPdf417,
+    Msi,
+    Nec2of5,
+    Pdf417,
+    Planet,
+    Postal4i,
+    Postnet,
+    QRCode,
+    Straight2of5Iata,
+    Straight2of5Industrial,
+    Telepen,
+    Tlc39,
+    UpcA,
+    UpcE,
+}
+
+impl Symbology {
+    /// Creates a new [Symbology].
+    pub const fn new() -> Self {
+        Self::Code128
+    }
+
+    /// Gets the Honeywell `Code ID` character for the [Symbology].
+    pub const fn code_id(&self) -> u8 {
+        match self {
+            Self::AustralianPost => b'A',
+            Self::Aztec => b'z',
+            Self::BritishPost => b'B',
+            Self::CanadianPost => b'C',
+            Self::ChinaPost => b'Q',
+            Self::Codabar => b'a',
+            Self::CodablockA => b'V',
+            Self::CodablockF => b'q',
+            Self::Code11 => b'h',
+            Self::Code128 => b'j',
+            Self::Code32 => b'<',
+            Self::Code39 => b'b',
+            Self::Code49 => b'l',
+            Self::Code93 => b'i',
+            Self::DataMatrix => b'w',
+            Self::Ean13 => b'd',
+            Self::Ean8 => b'D',
+            Self::Gs1_128 => b'I',
+            Self::Gs1Composite => b'y',
+            Self::Gs1DataBar => b'y',
+            Self::Gs1DataBarExpanded => b'}',
+            Self::Gs1DataBarLimited => b'{',
+            Self::HanXin => b'H',
+            Self::InfoMail => b',',
+            Self::IntelligentMail => b'M',
+            Self::Interleaved2of5 => b'e',
+            Self::JapanPost => b'J',
+            Self::Kix => b'K',
+            Self::KoreaPost => b'?',
+            Self::Matrix2of5 => b'm',
+            Self::MaxiCode => b'x',
+            Self::MicroPdf417 => b'R',
+            Self::Msi => b'g',
+            Self::Nec2of5 => b'Y',
+            Self::Pdf417 => b'r',
+            Self::Planet => b'L',
+            Self::Postal4i => b'N',
+            Self::Postnet => b'P',
+            Self::QRCode => b's',
+            Self::Straight2of5Iata => b'f',
+            Self::Straight2of5Industrial => b'f',
+            Self::Telepen => b't',
+            Self::Tlc39 => b'T',
+            Self::UpcA => b'c',
+            Self::UpcE => b'E',
+        }
+    }
+
+    /// Gets the `AIM ID` code character for the [Symbology].
+    pub const fn aim_code(&self) -> u8 {
+        match self {
+            Self::Aztec => b'z',
+            Self::Codabar => b'F',
+            Self::CodablockA | Self::CodablockF => b'O',
+            Self::Code11 => b'H',
+            Self::Code128 | Self::Gs1_128 => b'C',
+            Self::Code39 => b'A',
+            Self::Code49 => b'T',
+            Self::Code93 => b'G',
+            Self::DataMatrix => b'd',
+            Self::Ean13 | Self::Ean8 | Self::UpcA | Self::UpcE => b'E',
+            Self::Gs1Composite
+            | Self::Gs1DataBar
+            | Self::Gs1DataBarExpanded
+            | Self::Gs1DataBarLimited => b'e',
+            Self::Interleaved2of5 => b'I',
+            Self::MaxiCode => b'U',
+            Self::MicroPdf417 | Self::Pdf417 => b'L',
+            Self::Msi => b'M',
+            Self::QRCode => b'Q',
+            Self::Straight2of5Iata => b'R',
+            Self::Straight2of5Industrial => b'S',
+            Self::Telepen => b'B',
+            _ => b'X',
+        }
+    }
+
+    /// Attempts to convert a Honeywell `Code ID` character into a [Symbology].
+    ///
+    /// **NOTE**: Code IDs shared by multiple symbologies resolve to the most common one, use
+    /// [from_ids](Self::from_ids) to disambiguate with an `AIM ID`.
+    pub const fn try_from_code_id(val: u8) -> Result<Self> {
+        match Self::code_id_symbology(val) {
+            Some(sym) => Ok(sym),
+            None => Err(Error::InvalidValue(val as usize)),
+        }
+    }
+
+    /// Attempts to convert an `AIM ID` code character into a [Symbology].
+    ///
+    /// **NOTE**: AIM code characters shared by multiple symbologies resolve to the most common
+    /// one, and `X` (other symbologies) is always an error.
+    pub const fn try_from_aim_code(val: u8) -> Result<Self> {
+        match Self::aim_code_symbology(val) {
+            Some(sym) => Ok(sym),
+            None => Err(Error::InvalidValue(val as usize)),
+        }
+    }
+
+    /// Attempts to determine the [Symbology] from a combination of `Code ID` and [AimId].
+    ///
+    /// The `Code ID` is more specific, and takes precedence, except where the `AIM ID`
+    /// disambiguates symbologies sharing a `Code ID`.
+    pub const fn from_ids(code_id: Option<u8>, aim_id: Option<AimId>) -> Option<Self> {
+        match (code_id, aim_id) {
+            (Some(b'f'), Some(aim)) if aim.code() == b'R' => Some(Self::Straight2of5Iata),
+            (Some(b'y'), Some(aim)) if aim.modifier() == b'1' => Some(Self::Gs1Composite),
+            (Some(id), _) => Self::code_id_symbology(id),
+            (None, Some(aim)) => Self::aim_code_symbology(aim.code()),
+            (None, None) => None,
+        }
+    }
+
+    const fn code_id_symbology(val: u8) -> Option<Self> {
+        match val {
+            b'A' => Some(Self::AustralianPost),
+            b'z' => Some(Self::Aztec),
+            b'B' => Some(Self::BritishPost),
+            b'C' => Some(Self::CanadianPost),
+            b'Q' => Some(Self::ChinaPost),
+            b'a' => Some(Self::Codabar),
+            b'V' => Some(Self::CodablockA),
+            b'q' => Some(Self::CodablockF),
+            b'h' => Some(Self::Code11),
+            b'j' => Some(Self::Code128),
+            b'<' => Some(Self::Code32),
+            b'b' => Some(Self::Code39),
+            b'l' => Some(Self::Code49),
+            b'i' => Some(Self::Code93),
+            b'w' => Some(Self::DataMatrix),
+            b'd' => Some(Self::Ean13),
+            b'D' => Some(Self::Ean8),
+            b'I' => Some(Self::Gs1_128),
+            b'y' => Some(Self::Gs1DataBar),
+            b'}' => Some(Self::Gs1DataBarExpanded),
+            b'{' => Some(Self::Gs1DataBarLimited),
+            b'H' => Some(Self::HanXin),
+            b',' => Some(Self::InfoMail),
+            b'M' => Some(Self::IntelligentMail),
+            b'e' => Some(Self::Interleaved2of5),
+            b'J' => Some(Self::JapanPost),
+            b'K' => Some(Self::Kix),
+            b'?' => Some(Self::KoreaPost),
+            b'm' => Some(Self::Matrix2of5),
+            b'x' => Some(Self::MaxiCode),
+            b'R' => Some(Self::MicroPdf417),
+            b'g' => Some(Self::Msi),
+            b'Y' => Some(Self::Nec2of5),
+            b'r' => Some(Self::Pdf417),
+            b'L' => Some(Self::Planet),
+            b'N' => Some(Self::Postal4i),
+            b'P' => Some(Self::Postnet),
+            b's' => Some(Self::QRCode),
+            b'f' => Some(Self::Straight2of5Industrial),
+            b't' => Some(Self::Telepen),
+            b'T' => Some(Self::Tlc39),
+            b'c' => Some(Self::UpcA),
+            b'E' => Some(Self::UpcE),
+            _ => None,
+        }
+    }
+
+    const fn aim_code_symbology(val: u8) -> Option<Self> {
+        match val {
+            b'z' => Some(Self::Aztec),
+            b'F' => Some(Self::Codabar),
+            b'O' => Some(Self::CodablockF),
+            b'H' => Some(Self::Code11),
+            b'C' => Some(Self::Code128),
+            b'A' => Some(Self::Code39),
+            b'T' => Some(Self::Code49),
+            b'G' => Some(Self::Code93),
+            b'd' => Some(Self::DataMatrix),
+            b'E' => Some(Self::Ean13),
+            b'e' => Some(Self::Gs1DataBar),
+            b'I' => Some(Self::Interleaved2of5),
+            b'U' => Some(Self::MaxiCode),
+            b'L' => Some(Self::Pdf417),
+            b'M' => Some(Self::Msi),
+            b'Q' => Some(Self::QRCode),
+            b'R' => Some(Self::Straight2of5Iata),
+            b'S' => Some(Self::Straight2of5Industrial),
+            b'B' => Some(Self::Telepen),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Symbology {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents the three-character `AIM ID` (`]cm`) symbology identifier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AimId {
+    code: u8,
+    modifier: u8,
+}
+
+impl AimId {
+    /// `AIM ID` flag character.
+    pub const FLAG: u8 = b']';
+    /// Length of an encoded `AIM ID`.
+    pub const LEN: usize = 3;
+
+    /// Creates a new [AimId].
+    pub const fn new(code: u8, modifier: u8) -> Self {
+        Self { code, modifier }
+    }
+
+    /// Gets the `AIM ID` code character.
+    pub const fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Gets the `AIM ID` modifier character.
+    pub const fn modifier(&self) -> u8 {
+        self.modifier
+    }
+
+    /// Gets the [Symbology] indicated by the [AimId].
+    pub const fn symbology(&self) -> Option<Symbology> {
+        Symbology::from_ids(None, Some(*self))
+    }
+
+    /// Gets whether the `AIM ID` modifier indicates GS1 (FNC1 in first position) data.
+    pub const fn gs1(&self) -> bool {
+        matches!(
+            (self.code, self.modifier),
+            (b'C', b'1') | (b'd', b'2') | (b'Q', b'3') | (b'e', b'0') | (b'z', b'1')
+        )
+    }
+
+    /// Gets whether the `AIM ID` modifier indicates ECI escape sequences in the data.
+    pub const fn eci(&self) -> bool {
+        matches!(
+            (self.code, self.modifier),
+            (b'd', b'4' | b'5' | b'6') | (b'Q', b'2' | b'4' | b'6') | (b'L', b'1')
+        )
+    }
+
+    /// Gets the ASCII encoded [AimId].
+    pub const fn to_bytes(&self) -> [u8; Self::LEN] {
+        [Self::FLAG, self.code, self.modifier]
+    }
+}
+
+impl TryFrom<&[u8]> for AimId {
+    type Error = Error;
+
+    fn try_from(val: &[u8]) -> Result<Self> {
+        match val {
+            [Self::FLAG, code, modifier, ..] if code.is_ascii_graphic() => Ok(Self {
+                code: *code,
+                modifier: *modifier,
+            }),
+            _ => Err(Error::InvalidVariant),
+        }
+    }
+}
+
+impl TryFrom<&str> for AimId {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        val.as_bytes().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_id() {
+        [
+            Symbology::AustralianPost,
+            Symbology::Aztec,
+            Symbology::Codabar,
+            Symbology::Code128,
+            Symbology::Code39,
+            Symbology::DataMatrix,
+            Symbology::Ean13,
+            Symbology::Gs1_128,
+            Symbology::Gs1DataBar,
+            Symbology::MicroPdf417,
+            Symbology::Pdf417,
+            Symbology::QRCode,
+            Symbology::Straight2of5Industrial,
+            Symbology::UpcA,
+            Symbology::UpcE,
+        ]
+        .into_iter()
+        .for_each(|sym| {
+            assert_eq!(Symbology::try_from_code_id(sym.code_id()), Ok(sym));
+        });
+
+        assert_eq!(
+            Symbology::try_from_code_id(b'~'),
+            Err(Error::InvalidValue(b'~' as usize))
+        );
+    }
+
+    #[test]
+    fn test_aim_id() {
+        let aim = AimId::try_from("]Q1http://example.com").unwrap();
+
+        assert_eq!(aim, AimId::new(b'Q', b'1'));
+        assert_eq!(aim.to_bytes(), *b"]Q1");
+        assert_eq!(aim.symbology(), Some(Symbology::QRCode));
+        assert!(!aim.gs1());
+
+        assert!(AimId::try_from("]C1").unwrap().gs1());
+        assert!(AimId::try_from("]d4").unwrap().eci());
+        assert_eq!(AimId::new(b'X', b'0').symbology(), None);
+
+        ["", "]", "]Q", "Q1]", "] 0"].into_iter().for_each(|s| {
+            assert_eq!(AimId::try_from(s), Err(Error::InvalidVariant));
+        });
+    }
+
+    #[test]
+    fn test_from_ids() {
+        let iata = AimId::new(b'R', b'0');
+        let composite = AimId::new(b'e', b'1');
+
+        assert_eq!(
+            Symbology::from_ids(Some(b'f'), Some(iata)),
+            Some(Symbology::Straight2of5Iata)
+        );
+        assert_eq!(
+            Symbology::from_ids(Some(b'y'), Some(composite)),
+            Some(Symbology::Gs1Composite)
+        );
+        assert_eq!(
+            Symbology::from_ids(Some(b'D'), Some(AimId::new(b'E', b'4'))),
+            Some(Symbology::Ean8)
+        );
+        assert_eq!(Symbology::from_ids(None, None), None);
+    }
+}
@@ -0,0 +1,281 @@
+//! Import and export of EZConfig menu command configuration files.
+//!
+//! Configuration files list menu commands as text, one or more per line:
+//!
+//! - each line may start with the [MENU_PREFIX] (`<SYN>M<CR>`), and ends with a [Storage]
+//!   terminator (`.` or `!`), applied to every command on the line
+//! - commands on a line are separated with `;`
+//! - after a `,`, a command that is not complete on its own reuses the three-character tag of the
+//!   previous command, e.g. `232BAD5,WRD2.`
+//! - empty lines, and lines starting with `#` or `//`, are ignored
+
+use core::fmt;
+
+use alloc::{string::String, vec::Vec};
+
+use crate::command::{expand_tag, Command, SerialCommand, Storage, MENU_PREFIX};
+use crate::profile::Profile;
+use crate::result::{Error, Result};
+
+/// Represents a menu command in a configuration file that the library does not support.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Unsupported {
+    line: usize,
+    command: String,
+    error: Error,
+}
+
+impl Unsupported {
+    /// Gets the line number of the command, starting from one.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the unsupported menu command, verbatim.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Gets the parse [Error] for the command.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: unsupported command {}",
+            self.line, self.command
+        )
+    }
+}
+
+/// Represents an EZConfig menu command configuration file.
+///
+/// Displays the configuration in the file format, one command per line.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EzConfig {
+    commands: Vec<Command>,
+    unsupported: Vec<Unsupported>,
+}
+
+impl EzConfig {
+    /// Creates a new, empty [EzConfig].
+    pub const fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            unsupported: Vec::new(),
+        }
+    }
+
+    /// Parses an [EzConfig] from the contents of a configuration file.
+    ///
+    /// Commands the library does not support are reported in [unsupported](Self::unsupported),
+    /// and do not fail the import.
+    pub fn parse(val: &str) -> Self {
+        let mut cfg = Self::new();
+
+        for (i, line) in val.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+
+            let line = line.strip_prefix(MENU_PREFIX).unwrap_or(line);
+            let (line, storage) = match line.bytes().last().and_then(Storage::from_terminator) {
+                Some(storage) => (&line[..line.len() - 1], storage),
+                None => (line, Storage::new()),
+            };
+
+            for cmd in line.split(';').flat_map(expand_tags) {
+                match SerialCommand::try_from(cmd.as_str()) {
+                    Ok(serial) => cfg
+                        .commands
+                        .push(Command::from(serial).with_storage(storage)),
+                    Err(error) => cfg.unsupported.push(Unsupported {
+                        line: i + 1,
+                        command: cmd,
+                        error,
+                    }),
+                }
+            }
+        }
+
+        cfg
+    }
+
+    /// Gets the list of supported [Command]s, in file order.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Gets the list of [Unsupported] commands, in file order.
+    pub fn unsupported(&self) -> &[Unsupported] {
+        &self.unsupported
+    }
+
+    /// Gets whether every command in the file is supported.
+    pub fn is_supported(&self) -> bool {
+        self.unsupported.is_empty()
+    }
+
+    /// Builder function that appends a [Command] to the [EzConfig].
+    pub fn with_command(mut self, val: Command) -> Self {
+        self.commands.push(val);
+        self
+    }
+
+    /// Attempts to convert the supported commands into a [Profile], keeping the file order.
+    ///
+    /// A [Profile] saves every command with the same [Storage], so files mixing temporary and
+    /// permanent commands fail with an [InvalidArgument](Error::InvalidArgument) error.
+    pub fn to_profile(&self) -> Result<Profile> {
+        let storage = self
+            .commands
+            .first()
+            .map_or_else(Storage::new, Command::storage);
+
+        if self.commands.iter().any(|cmd| cmd.storage() != storage) {
+            return Err(Error::InvalidArgument("mixed command storages"));
+        }
+
+        Ok(self
+            .commands
+            .iter()
+            .fold(Profile::new().with_storage(storage), |profile, cmd| {
+                profile.with_command(cmd.serial())
+            }))
+    }
+}
+
+impl From<&Profile> for EzConfig {
+    fn from(val: &Profile) -> Self {
+        Self {
+            commands: val.to_commands(),
+            unsupported: Vec::new(),
+        }
+    }
+}
+
+impl TryFrom<&str> for EzConfig {
+    type Error = Error;
+
+    /// Parses an [EzConfig], failing with the [Error] of the first unsupported command.
+    fn try_from(val: &str) -> Result<Self> {
+        let cfg = Self::parse(val);
+
+        match cfg.unsupported.first() {
+            Some(unsupported) => Err(unsupported.error.clone()),
+            None => Ok(cfg),
+        }
+    }
+}
+
+impl fmt::Display for EzConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.commands
+            .iter()
+            .try_for_each(|cmd| writeln!(f, "{cmd}"))
+    }
+}
+
+// Expands commands separated by `,` that reuse the tag of the previous command.
+fn expand_tags(val: &str) -> Vec<String> {
+    let mut tag = "";
+
+    val.split(',')
+        .map(str::trim)
+        .filter(|cmd| !cmd.is_empty())
+        .map(|cmd| expand_tag(&mut tag, cmd))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::{AimerMode, BaudRate, GoodReadBeep, WordLength};
+
+    #[test]
+    fn test_valid() {
+        let file = "# exported configuration\r\n\
+                    \x16M\r232BAD5,WRD2.\r\n\
+                    SCNAIM0;BEPBEP1!\r\n\
+                    \r\n\
+                    // vendor tuning\r\n\
+                    XYZABC1;BEPLVL9.\r\n\
+                    DLYGRD500\r\n";
+
+        let cfg = EzConfig::parse(file);
+
+        assert_eq!(
+            cfg.commands()
+                .iter()
+                .map(|cmd| (format!("{cmd}"), cmd.storage()))
+                .collect::<Vec<_>>(),
+            [
+                ("232BAD5.".into(), Storage::Permanent),
+                ("232WRD2.".into(), Storage::Permanent),
+                ("SCNAIM0!".into(), Storage::Temporary),
+                ("BEPBEP1!".into(), Storage::Temporary),
+                ("DLYGRD500.".into(), Storage::Permanent),
+            ]
+        );
+        assert_eq!(
+            cfg.unsupported()
+                .iter()
+                .map(|u| (u.line(), u.command()))
+                .collect::<Vec<_>>(),
+            [(6, "XYZABC1"), (6, "BEPLVL9")]
+        );
+        assert_eq!(
+            format!("{}", cfg.unsupported()[0]),
+            "line 6: unsupported command XYZABC1"
+        );
+
+        let profile = Profile::new()
+            .with_aimer_mode(AimerMode::Off)
+            .with_good_read_beep(GoodReadBeep::On)
+            .with_word_length(WordLength::try_from_u8(2).unwrap())
+            .with_baud_rate(BaudRate::Baud9600)
+            .with_storage(Storage::Temporary);
+
+        // profiles round-trip through the file format
+        let export = format!("{}", EzConfig::from(&profile));
+        assert_eq!(export, "BEPBEP1!\nSCNAIM0!\n232WRD2!\n232BAD5!\n");
+
+        let import = EzConfig::try_from(export.as_str())
+            .unwrap()
+            .to_profile()
+            .unwrap();
+        assert_eq!(import.frames(), profile.frames());
+        assert_eq!(import.storage(), Storage::Temporary);
+        assert_eq!(EzConfig::new().to_profile(), Ok(Profile::new()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            EzConfig::try_from("232BAD5.\nXYZABC1."),
+            Err(Error::InvalidVariant)
+        );
+        assert_eq!(
+            EzConfig::try_from("232BADx."),
+            Err(Error::invalid_data("232BAD", "x", 6))
+        );
+        assert_eq!(EzConfig::parse("232BAD5;BEPLVL9.").commands().len(), 1);
+        assert_eq!(
+            EzConfig::parse("232BAD5,SCNAIM0,XYZ.").unsupported()[0].command(),
+            "SCNXYZ"
+        );
+
+        // mixed storages cannot be kept in a profile, but round-trip through the file format
+        let cfg = EzConfig::try_from("SCNAIM0!\nBEPBEP1.\n").unwrap();
+        assert_eq!(format!("{cfg}"), "SCNAIM0!\nBEPBEP1.\n");
+        assert_eq!(
+            cfg.to_profile(),
+            Err(Error::InvalidArgument("mixed command storages"))
+        );
+    }
+}
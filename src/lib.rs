@@ -6,4 +6,10 @@
 extern crate alloc;
 
 pub mod command;
+pub mod config;
+pub mod decode;
+pub mod ezconfig;
+pub mod profile;
 pub mod result;
+#[cfg(feature = "std")]
+pub mod transport;
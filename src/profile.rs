@@ -0,0 +1,646 @@
+//! Declarative device configuration profiles.
+//!
+//! With the `serde` feature, a [Profile] can be loaded from any `serde` format, e.g. TOML:
+//!
+//! ```toml
+//! all_symbologies = "on"
+//! baud_rate = 115200
+//!
+//! [image_snap]
+//! exposure_ms = 1016
+//! gain = "medium"
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use crate::command::*;
+use crate::result::{Error, Result};
+
+macro_rules! profile_field {
+    ($field:ident: $field_ty:ident) => {
+        paste::paste! {
+            impl Profile {
+                #[doc = "Gets the [" $field_ty "] setting for [Profile]."]
+                pub const fn $field(&self) -> Option<$field_ty> {
+                    self.$field
+                }
+
+                #[doc = "Builder function that sets the [" $field_ty "] setting for [Profile]."]
+                pub fn [<with_ $field>](mut self, val: $field_ty) -> Self {
+                    self.$field = Some(val);
+                    self
+                }
+            }
+        }
+    };
+}
+
+/// Represents a set of device settings, sent to the device as an ordered list of commands.
+///
+/// Commands are ordered so that each one is accepted by the device:
+///
+/// - [Defaults] are restored first, so the remaining settings apply on top of them.
+/// - Mode-dependent settings follow their [OperatingMode] or [Postal] group.
+/// - Interface settings are sent last, since they may change the communication link.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Profile {
+    storage: Storage,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    defaults: Option<Defaults>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    all_symbologies: Option<AllSymbologies>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    qr_code: Option<QRCode>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pdf417: Option<PDF417>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    postal: Option<PostalConfig>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    eci_decoder: Option<EciDecoder>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    qr_code_page: Option<QRCodePage>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    symbology_id_transmit: Option<SymbologyIdTransmit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    prefix: Vec<Prefix>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    suffix: Vec<Suffix>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    data_format: Vec<DataFormatCommand>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    operating_mode: Option<OperatingModeConfig>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    manual_trigger_mode: Option<ManualTriggerMode>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    mobile_phone_read_mode: Option<MobilePhoneReadMode>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "serial_trigger_timeout_ms",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    serial_trigger: Option<SerialTriggerMode>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "good_read_delay_ms", skip_serializing_if = "Option::is_none")
+    )]
+    good_read_delay: Option<GoodReadDelay>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "reread_delay_ms", skip_serializing_if = "Option::is_none")
+    )]
+    reread_delay: Option<RereadDelay>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "same_code_delay_ms", skip_serializing_if = "Option::is_none")
+    )]
+    same_code_delay: Option<SameCodeDelay>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "intercharacter_delay_ms",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    intercharacter_delay: Option<IntercharacterDelay>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "interfunction_delay_ms",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    interfunction_delay: Option<InterfunctionDelay>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "intermessage_delay_ms",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    intermessage_delay: Option<IntermessageDelay>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    good_read_beep: Option<GoodReadBeep>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    good_read_led: Option<GoodReadLed>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    power_up_beep: Option<PowerUpBeep>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    beep_duration: Option<BeepDuration>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    beeper_volume: Option<BeeperVolume>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    beeper_pitch: Option<BeeperPitch>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    beep_count: Option<BeepCount>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    aimer_mode: Option<AimerMode>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aimer_delay_ms", skip_serializing_if = "Option::is_none")
+    )]
+    aimer_delay: Option<AimerDelay>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    illumination: Option<Illumination>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    led_power_level: Option<LedPowerLevel>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    image_snap: Option<ImageSnap>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    image_ship: Option<ImageShip>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    host_ack: Option<HostAck>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "host_ack_timeout_ms",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    host_ack_timeout: Option<HostAckTimeout>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    keyboard_country: Option<KeyboardCountry>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    keyboard_style: Option<KeyboardStyle>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    function_code_transmit: Option<FunctionCodeTransmit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    control_ascii: Option<ControlAscii>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    commands: Vec<SerialCommand>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    usb_interface: Option<UsbInterface>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    word_length: Option<WordLength>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    rts_cts: Option<RtsCts>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    xon_xoff: Option<XonXoff>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    ack_nak: Option<AckNak>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    baud_rate: Option<BaudRate>,
+}
+
+impl Profile {
+    /// Creates a new [Profile], with no settings.
+    pub const fn new() -> Self {
+        Self {
+            storage: Storage::new(),
+            defaults: None,
+            all_symbologies: None,
+            qr_code: None,
+            pdf417: None,
+            postal: None,
+            eci_decoder: None,
+            qr_code_page: None,
+            symbology_id_transmit: None,
+            prefix: Vec::new(),
+            suffix: Vec::new(),
+            data_format: Vec::new(),
+            operating_mode: None,
+            manual_trigger_mode: None,
+            mobile_phone_read_mode: None,
+            serial_trigger: None,
+            good_read_delay: None,
+            reread_delay: None,
+            same_code_delay: None,
+            intercharacter_delay: None,
+            interfunction_delay: None,
+            intermessage_delay: None,
+            good_read_beep: None,
+            good_read_led: None,
+            power_up_beep: None,
+            beep_duration: None,
+            beeper_volume: None,
+            beeper_pitch: None,
+            beep_count: None,
+            aimer_mode: None,
+            aimer_delay: None,
+            illumination: None,
+            led_power_level: None,
+            image_snap: None,
+            image_ship: None,
+            host_ack: None,
+            host_ack_timeout: None,
+            keyboard_country: None,
+            keyboard_style: None,
+            function_code_transmit: None,
+            control_ascii: None,
+            commands: Vec::new(),
+            usb_interface: None,
+            word_length: None,
+            rts_cts: None,
+            xon_xoff: None,
+            ack_nak: None,
+            baud_rate: None,
+        }
+    }
+
+    /// Gets the [Storage] the [Profile] settings are saved to.
+    pub const fn storage(&self) -> Storage {
+        self.storage
+    }
+
+    /// Builder function that sets the [Storage] the [Profile] settings are saved to.
+    pub fn with_storage(mut self, val: Storage) -> Self {
+        self.storage = val;
+        self
+    }
+
+    /// Gets the [Prefix] commands for [Profile].
+    pub fn prefix(&self) -> &[Prefix] {
+        &self.prefix
+    }
+
+    /// Builder function that appends a [Prefix] command for [Profile].
+    pub fn with_prefix(mut self, val: Prefix) -> Self {
+        self.prefix.push(val);
+        self
+    }
+
+    /// Gets the [Suffix] commands for [Profile].
+    pub fn suffix(&self) -> &[Suffix] {
+        &self.suffix
+    }
+
+    /// Builder function that appends a [Suffix] command for [Profile].
+    pub fn with_suffix(mut self, val: Suffix) -> Self {
+        self.suffix.push(val);
+        self
+    }
+
+    /// Gets the [DataFormatCommand]s for [Profile].
+    pub fn data_format(&self) -> &[DataFormatCommand] {
+        &self.data_format
+    }
+
+    /// Builder function that appends a [DataFormatCommand] for [Profile].
+    pub fn with_data_format(mut self, val: DataFormatCommand) -> Self {
+        self.data_format.push(val);
+        self
+    }
+
+    /// Gets the additional [SerialCommand]s for [Profile].
+    pub fn commands(&self) -> &[SerialCommand] {
+        &self.commands
+    }
+
+    /// Builder function that appends an additional [SerialCommand] for [Profile].
+    ///
+    /// Additional commands are sent after the other settings, before the interface settings.
+    pub fn with_command(mut self, val: SerialCommand) -> Self {
+        self.commands.push(val);
+        self
+    }
+
+    /// Gets the ordered list of [SerialCommand]s for the [Profile] settings.
+    pub fn serial_commands(&self) -> Vec<SerialCommand> {
+        let mut cmds = Vec::new();
+
+        cmds.extend(self.defaults.map(SerialCommand::Defaults));
+
+        cmds.extend(self.all_symbologies.map(SerialCommand::AllSymbologies));
+        cmds.extend(self.qr_code.map(SerialCommand::QRCode));
+        cmds.extend(self.pdf417.map(SerialCommand::PDF417));
+        if let Some(postal) = self.postal {
+            cmds.extend(postal.serial_commands());
+        }
+        cmds.extend(self.eci_decoder.map(SerialCommand::EciDecoder));
+        cmds.extend(self.qr_code_page.map(SerialCommand::QRCodePage));
+        // symbology IDs before custom prefixes, as `Off` clears every prefix
+        cmds.extend(
+            self.symbology_id_transmit
+                .map(SerialCommand::SymbologyIdTransmit),
+        );
+
+        cmds.extend(self.prefix.iter().copied().map(SerialCommand::Prefix));
+        cmds.extend(self.suffix.iter().copied().map(SerialCommand::Suffix));
+        cmds.extend(
+            self.data_format
+                .iter()
+                .copied()
+                .map(SerialCommand::DataFormat),
+        );
+
+        if let Some(mode) = self.operating_mode {
+            cmds.extend(mode.serial_commands());
+        }
+        cmds.extend(
+            self.manual_trigger_mode
+                .map(SerialCommand::ManualTriggerMode),
+        );
+        cmds.extend(
+            self.mobile_phone_read_mode
+                .map(SerialCommand::MobilePhoneReadMode),
+        );
+        cmds.extend(self.serial_trigger.map(SerialCommand::SerialTriggerMode));
+
+        cmds.extend(self.good_read_delay.map(SerialCommand::GoodReadDelay));
+        cmds.extend(self.reread_delay.map(SerialCommand::RereadDelay));
+        cmds.extend(self.same_code_delay.map(SerialCommand::SameCodeDelay));
+        cmds.extend(
+            self.intercharacter_delay
+                .map(SerialCommand::IntercharacterDelay),
+        );
+        cmds.extend(
+            self.interfunction_delay
+                .map(SerialCommand::InterfunctionDelay),
+        );
+        cmds.extend(
+            self.intermessage_delay
+                .map(SerialCommand::IntermessageDelay),
+        );
+
+        cmds.extend(self.good_read_beep.map(SerialCommand::GoodReadBeep));
+        cmds.extend(self.good_read_led.map(SerialCommand::GoodReadLed));
+        cmds.extend(self.power_up_beep.map(SerialCommand::PowerUpBeep));
+        cmds.extend(self.beep_duration.map(SerialCommand::BeepDuration));
+        cmds.extend(self.beeper_volume.map(SerialCommand::BeeperVolume));
+        cmds.extend(self.beeper_pitch.map(SerialCommand::BeeperPitch));
+        cmds.extend(self.beep_count.map(SerialCommand::BeepCount));
+
+        cmds.extend(self.aimer_mode.map(SerialCommand::AimerMode));
+        cmds.extend(self.aimer_delay.map(SerialCommand::AimerDelay));
+        cmds.extend(self.illumination.map(SerialCommand::Illumination));
+        cmds.extend(self.led_power_level.map(SerialCommand::LedPowerLevel));
+        cmds.extend(self.image_snap.map(SerialCommand::ImageSnap));
+        cmds.extend(self.image_ship.map(SerialCommand::ImageShip));
+
+        cmds.extend(self.host_ack.map(SerialCommand::HostAck));
+        cmds.extend(self.host_ack_timeout.map(SerialCommand::HostAckTimeout));
+        cmds.extend(self.keyboard_country.map(SerialCommand::KeyboardCountry));
+        cmds.extend(self.keyboard_style.map(SerialCommand::KeyboardStyle));
+        cmds.extend(
+            self.function_code_transmit
+                .map(SerialCommand::FunctionCodeTransmit),
+        );
+        cmds.extend(self.control_ascii.map(SerialCommand::ControlAscii));
+
+        cmds.extend(self.commands.iter().copied());
+
+        cmds.extend(self.usb_interface.map(SerialCommand::UsbInterface));
+        cmds.extend(self.word_length.map(SerialCommand::WordLength));
+        cmds.extend(self.rts_cts.map(SerialCommand::RtsCts));
+        cmds.extend(self.xon_xoff.map(SerialCommand::XonXoff));
+        cmds.extend(self.ack_nak.map(SerialCommand::AckNak));
+        cmds.extend(self.baud_rate.map(SerialCommand::BaudRate));
+
+        cmds
+    }
+
+    /// Gets the ordered list of [Command]s for the [Profile], with the [Profile] [Storage].
+    pub fn to_commands(&self) -> Vec<Command> {
+        self.serial_commands()
+            .into_iter()
+            .map(|cmd| Command::from(cmd).with_storage(self.storage))
+            .collect()
+    }
+
+    /// Gets the ordered list of menu command frames to send to the device.
+    pub fn frames(&self) -> Vec<String> {
+        self.to_commands().iter().map(Command::frame).collect()
+    }
+
+    /// Validates the [Profile] settings against the device [Capabilities].
+    ///
+    /// The operating mode, manual trigger mode and mobile phone read mode all set the device
+    /// operating mode (`PAP` tag), so setting them to different modes is an error.
+    pub fn validate(&self, caps: &Capabilities) -> Result<()> {
+        let mut modes = [
+            self.operating_mode.map(|cfg| cfg.mode()),
+            self.manual_trigger_mode.map(OperatingMode::from),
+            self.mobile_phone_read_mode.map(OperatingMode::from),
+        ]
+        .into_iter()
+        .flatten();
+
+        if let Some(mode) = modes.next() {
+            if modes.any(|m| m != mode) {
+                return Err(Error::InvalidArgument("conflicting operating modes"));
+            }
+        }
+
+        self.serial_commands()
+            .iter()
+            .try_for_each(|cmd| cmd.validate(caps))
+    }
+}
+
+profile_field!(defaults: Defaults);
+profile_field!(all_symbologies: AllSymbologies);
+profile_field!(qr_code: QRCode);
+profile_field!(pdf417: PDF417);
+profile_field!(postal: PostalConfig);
+profile_field!(eci_decoder: EciDecoder);
+profile_field!(qr_code_page: QRCodePage);
+profile_field!(symbology_id_transmit: SymbologyIdTransmit);
+profile_field!(operating_mode: OperatingModeConfig);
+profile_field!(manual_trigger_mode: ManualTriggerMode);
+profile_field!(mobile_phone_read_mode: MobilePhoneReadMode);
+profile_field!(serial_trigger: SerialTriggerMode);
+profile_field!(good_read_delay: GoodReadDelay);
+profile_field!(reread_delay: RereadDelay);
+profile_field!(same_code_delay: SameCodeDelay);
+profile_field!(intercharacter_delay: IntercharacterDelay);
+profile_field!(interfunction_delay: InterfunctionDelay);
+profile_field!(intermessage_delay: IntermessageDelay);
+profile_field!(good_read_beep: GoodReadBeep);
+profile_field!(good_read_led: GoodReadLed);
+profile_field!(power_up_beep: PowerUpBeep);
+profile_field!(beep_duration: BeepDuration);
+profile_field!(beeper_volume: BeeperVolume);
+profile_field!(beeper_pitch: BeeperPitch);
+profile_field!(beep_count: BeepCount);
+profile_field!(aimer_mode: AimerMode);
+profile_field!(aimer_delay: AimerDelay);
+profile_field!(illumination: Illumination);
+profile_field!(led_power_level: LedPowerLevel);
+profile_field!(image_snap: ImageSnap);
+profile_field!(image_ship: ImageShip);
+profile_field!(host_ack: HostAck);
+profile_field!(host_ack_timeout: HostAckTimeout);
+profile_field!(keyboard_country: KeyboardCountry);
+profile_field!(keyboard_style: KeyboardStyle);
+profile_field!(function_code_transmit: FunctionCodeTransmit);
+profile_field!(control_ascii: ControlAscii);
+profile_field!(usb_interface: UsbInterface);
+profile_field!(word_length: WordLength);
+profile_field!(rts_cts: RtsCts);
+profile_field!(xon_xoff: XonXoff);
+profile_field!(ack_nak: AckNak);
+profile_field!(baud_rate: BaudRate);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::data_edit::{Affix, SymbologyScope};
+    use crate::command::image_snap::{Exposure, Gain};
+
+    fn exp_profile() -> Profile {
+        Profile::new()
+            .with_baud_rate(BaudRate::Baud115200)
+            .with_all_symbologies(AllSymbologies::On)
+            .with_defaults(Defaults::Activate)
+            .with_image_snap(
+                ImageSnap::new()
+                    .with_exposure(Exposure::try_from_ms(1016).unwrap())
+                    .with_gain(Gain::Medium),
+            )
+            .with_operating_mode(
+                OperatingModeConfig::stand()
+                    .with_hands_free_timeout(HandsFreeTimeout::try_from_ms(5000).unwrap())
+                    .unwrap(),
+            )
+            .with_good_read_delay(GoodReadDelay::try_from_ms(500).unwrap())
+            .with_command(SerialCommand::AimerMode(AimerMode::Off))
+    }
+
+    #[test]
+    fn test_valid() {
+        let profile = exp_profile();
+
+        assert_eq!(
+            profile.frames(),
+            [
+                "\x16M\rDEFALT.",
+                "\x16M\rALLENA1.",
+                "\x16M\rPAPSPN.",
+                "\x16M\rTRGPTO5000.",
+                "\x16M\rDLYGRD500.",
+                "\x16M\rIMGSNP8E2G.",
+                "\x16M\rSCNAIM0.",
+                "\x16M\r232BAD9.",
+            ]
+        );
+        assert_eq!(
+            profile
+                .with_storage(Storage::Temporary)
+                .to_commands()
+                .iter()
+                .map(Command::storage)
+                .collect::<Vec<_>>(),
+            [Storage::Temporary; 8]
+        );
+        assert!(Profile::new().frames().is_empty());
+        assert_eq!(
+            Profile::new()
+                .with_prefix(Prefix::Add(
+                    Affix::try_from_hex(SymbologyScope::All, "02").unwrap()
+                ))
+                .with_symbology_id_transmit(SymbologyIdTransmit::Off)
+                .frames(),
+            ["\x16M\rPRECL299.", "\x16M\rPREBK29902."]
+        );
+        assert_eq!(exp_profile().validate(&Capabilities::new()), Ok(()));
+        assert_eq!(
+            Profile::new()
+                .with_operating_mode(OperatingModeConfig::handheld())
+                .with_manual_trigger_mode(ManualTriggerMode::Normal)
+                .validate(&Capabilities::new()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let caps = Capabilities::new();
+        let conflict = Err(Error::InvalidArgument("conflicting operating modes"));
+
+        assert_eq!(
+            Profile::new()
+                .with_operating_mode(OperatingModeConfig::stand())
+                .with_manual_trigger_mode(ManualTriggerMode::Normal)
+                .validate(&caps),
+            conflict
+        );
+        assert_eq!(
+            Profile::new()
+                .with_manual_trigger_mode(ManualTriggerMode::Enhanced)
+                .with_mobile_phone_read_mode(MobilePhoneReadMode::HandheldScanning)
+                .validate(&caps),
+            conflict
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let toml_profile = r#"
+            defaults = "activate"
+            all_symbologies = "on"
+            good_read_delay_ms = 500
+            baud_rate = 115200
+            commands = [{ aimer_mode = "off" }]
+
+            [operating_mode]
+            mode = "streaming_presentation_normal"
+            hands_free_timeout_ms = 5000
+
+            [image_snap]
+            exposure_ms = 1016
+            gain = "medium"
+        "#;
+
+        let profile: Profile = toml::from_str(toml_profile).unwrap();
+        assert_eq!(profile, exp_profile());
+
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(serde_json::from_str::<Profile>(&json).unwrap(), profile);
+
+        let toml = toml::to_string(&profile).unwrap();
+        assert_eq!(toml::from_str::<Profile>(&toml).unwrap(), profile);
+
+        let prefix: Profile = serde_json::from_str(
+            r#"{"prefix": [{"add": {"scope": "all", "chars": "02"}}], "word_length":
+                {"data_bits": "seven", "stop_bits": "one", "parity": "even"}}"#,
+        )
+        .unwrap();
+        assert_eq!(prefix.frames(), ["\x16M\rPREBK29902.", "\x16M\r232WRD0."]);
+
+        // modifier sequences keep the modifier order
+        let seq: Profile = serde_json::from_str(
+            r#"{"commands": [{"image_snap_sequence":
+                [{"exposure_ms": 1016}, {"imaging_style": "photo"}, {"exposure_ms": 127}]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(seq.frames(), ["\x16M\rIMGSNP8E1P1E."]);
+        assert_eq!(
+            serde_json::from_str::<Profile>(&serde_json::to_string(&seq).unwrap()).unwrap(),
+            seq
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_invalid() {
+        [
+            // unknown field
+            "exposure = 1016",
+            // out of range
+            "good_read_delay_ms = 30001",
+            "baud_rate = 1234",
+            "[image_snap]\nexposure_ms = 0",
+            // not a multiple of the 127 ms exposure unit
+            "[image_snap]\nexposure_ms = 1000",
+            "[image_snap]\nexposure = 1016",
+            // invalid setting for the operating mode
+            "[operating_mode]\nmode = \"manual_normal\"\nhands_free_timeout_ms = 5000",
+            // invalid word length combination
+            "[word_length]\ndata_bits = \"eight\"\nstop_bits = \"two\"\nparity = \"none\"",
+            // empty prefix
+            "[[prefix]]\nadd = { scope = \"all\", chars = \"\" }",
+            "gain = \"loud\"",
+        ]
+        .into_iter()
+        .for_each(|val| {
+            assert!(toml::from_str::<Profile>(val).is_err(), "{val}");
+        });
+    }
+}
@@ -2,14 +2,64 @@
 
 use core::fmt;
 
+use alloc::{boxed::Box, string::String};
+
+use crate::command::ReplyStatus;
+
 /// Convenience alias for the library [Result](core::result::Result) type.
 pub type Result<T> = core::result::Result<T, Error>;
 
 /// Represents error conditions for the library.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+///
+/// Marked `non_exhaustive`, as transport variants are only available with the `std` feature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     InvalidVariant,
+    /// Value inside the supported range, but not accepted by the device, e.g. a gap in a list of
+    /// menu settings.
     InvalidValue(usize),
+    /// Value is outside the inclusive `min..=max` range supported by the device.
+    OutOfRange {
+        value: usize,
+        min: usize,
+        max: usize,
+    },
+    /// Invalid data in a command, starting at `position` in the command string.
+    InvalidData {
+        data: String,
+        position: usize,
+    },
+    /// Error in a command token, starting at `position` in the command string, with the token
+    /// for context.
+    InvalidToken {
+        data: String,
+        position: usize,
+        source: Box<Error>,
+    },
+    /// Error in a command, with the command tag for context.
+    Command {
+        tag: &'static str,
+        source: Box<Error>,
+    },
+    /// Menu command rejected by the device: `NAK` for an invalid tag or sub-tag, `ENQ` for an
+    /// invalid data field.
+    Rejected {
+        command: String,
+        status: ReplyStatus,
+    },
+    /// Read still rejected after all host `ACK` retries, with the number of reads attempted.
+    RetriesExhausted {
+        attempts: usize,
+    },
+    /// Invalid argument, with a description of the requirement.
+    InvalidArgument(&'static str),
+    /// I/O error on the serial transport.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// Timed out waiting for the device.
+    #[cfg(feature = "std")]
+    Timeout,
 }
 
 impl Error {
@@ -17,6 +67,63 @@ impl Error {
     pub const fn new() -> Self {
         Self::InvalidVariant
     }
+
+    /// Creates an [InvalidData](Self::InvalidData) error for a command tag.
+    pub fn invalid_data(tag: &'static str, data: &str, position: usize) -> Self {
+        Self::InvalidData {
+            data: data.into(),
+            position,
+        }
+        .with_tag(tag)
+    }
+
+    /// Creates an [OutOfRange](Self::OutOfRange) error for a value outside `min..=max`.
+    pub const fn out_of_range(value: usize, min: usize, max: usize) -> Self {
+        Self::OutOfRange { value, min, max }
+    }
+
+    /// Adds the command token context to the [Error].
+    ///
+    /// Errors that already have a command token or tag are returned unchanged.
+    pub fn with_token(self, data: &str, position: usize) -> Self {
+        match self {
+            Self::InvalidToken { .. } | Self::Command { .. } => self,
+            err => Self::InvalidToken {
+                data: data.into(),
+                position,
+                source: Box::new(err),
+            },
+        }
+    }
+
+    /// Adds the command tag context to the [Error].
+    ///
+    /// Errors that already have a command tag are returned unchanged.
+    pub fn with_tag(self, tag: &'static str) -> Self {
+        match self {
+            Self::Command { .. } => self,
+            err => Self::Command {
+                tag,
+                source: Box::new(err),
+            },
+        }
+    }
+
+    /// Gets the command tag context, if any.
+    pub fn tag(&self) -> Option<&'static str> {
+        match self {
+            Self::Command { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Gets the underlying [Error], without the command tag and token context.
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::Command { source, .. } | Self::InvalidToken { source, .. } => source.root(),
+            err => err,
+        }
+    }
 }
 
 impl Default for Error {
@@ -30,8 +137,111 @@ impl fmt::Display for Error {
         match self {
             Self::InvalidVariant => write!(f, "invalid variant"),
             Self::InvalidValue(err) => write!(f, "invalid value: {err}"),
+            Self::OutOfRange { value, min, max } => {
+                write!(f, "value out of range: {value}, expected {min}..={max}")
+            }
+            Self::InvalidData { data, position } => {
+                write!(f, "invalid data at position {position}: {data:?}")
+            }
+            Self::InvalidToken {
+                data,
+                position,
+                source,
+            } => write!(
+                f,
+                "invalid token at position {position}: {data:?}: {source}"
+            ),
+            Self::Command { tag, source } => write!(f, "{tag}: {source}"),
+            Self::Rejected { command, status } => {
+                let reason = match status {
+                    ReplyStatus::Nak => "invalid tag or sub-tag",
+                    ReplyStatus::Enq => "invalid data",
+                    ReplyStatus::Ack => "acknowledged",
+                };
+                write!(f, "command rejected by the device ({reason}): {command:?}")
+            }
+            Self::RetriesExhausted { attempts } => {
+                write!(f, "read rejected after {attempts} attempts")
+            }
+            Self::InvalidArgument(err) => write!(f, "invalid argument: {err}"),
+            #[cfg(feature = "std")]
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            #[cfg(feature = "std")]
+            Self::Timeout => write!(f, "timed out"),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Command { source, .. } | Self::InvalidToken { source, .. } => {
+                Some(source.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::TimedOut => Self::Timeout,
+            kind => Self::Io(kind),
         }
     }
 }
 
-impl core::error::Error for Error {}
+#[cfg(feature = "std")]
+impl From<serialport::Error> for Error {
+    fn from(err: serialport::Error) -> Self {
+        std::io::Error::from(err).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let err = Error::invalid_data("232BAD", "x", 6);
+
+        assert_eq!(err.tag(), Some("232BAD"));
+        assert_eq!(
+            err.root(),
+            &Error::InvalidData {
+                data: "x".into(),
+                position: 6
+            }
+        );
+        assert_eq!(
+            format!("{err}"),
+            "232BAD: invalid data at position 6: \"x\""
+        );
+        assert_eq!(err.clone().with_tag("SCNAIM"), err);
+
+        let err = Error::Rejected {
+            command: "232BAD5".into(),
+            status: ReplyStatus::Enq,
+        };
+        assert_eq!(err.tag(), None);
+        assert_eq!(err.root(), &err);
+        assert_eq!(
+            format!("{err}"),
+            "command rejected by the device (invalid data): \"232BAD5\""
+        );
+        assert!(core::error::Error::source(&Error::new().with_tag("REVINF")).is_some());
+
+        let err = Error::out_of_range(5000, 0, 100)
+            .with_token("5000J", 6)
+            .with_tag("IMGSHP");
+        assert_eq!(err.tag(), Some("IMGSHP"));
+        assert_eq!(err.root(), &Error::out_of_range(5000, 0, 100));
+        assert_eq!(
+            format!("{err}"),
+            "IMGSHP: invalid token at position 6: \"5000J\": value out of range: 5000, expected 0..=100"
+        );
+    }
+}
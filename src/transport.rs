@@ -0,0 +1,805 @@
+//! Serial transport for communicating with the device over a [SerialPort].
+
+use std::io::{ErrorKind, Read, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serialport::{ClearBuffer, SerialPort};
+
+use crate::command::{
+    BaudRate, Capabilities, Command, Defaults, DeviceInfo, MenuReply, QueryCommand, QueryScope,
+    Reset, SerialCommand, SoftwareRevision, Storage, ACK, NAK,
+};
+use crate::config::{ConfigDiff, DeviceConfig};
+use crate::decode::{DecodedBarcode, ReadFormat};
+use crate::profile::Profile;
+use crate::result::{Error, Result};
+
+/// Default timeout for device replies.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(1_000);
+/// Default time to wait for the device to come back after a [Reset].
+pub const DEFAULT_RESET_TIMEOUT: Duration = Duration::from_millis(10_000);
+/// Default number of retransmissions requested for an invalid read in host ACK mode.
+pub const DEFAULT_HOST_ACK_RETRIES: u8 = 3;
+// Time for the device to switch baud rates after acknowledging the change.
+const BAUD_SWITCH_DELAY: Duration = Duration::from_millis(100);
+// Interval between polls while waiting for the device to reboot.
+const RESET_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const READ_CHUNK: usize = 256;
+
+/// Represents a read accepted by the host in [HostAck](crate::command::HostAck) mode.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AckedRead {
+    barcode: DecodedBarcode,
+    retries: u8,
+}
+
+impl AckedRead {
+    /// Gets the accepted [DecodedBarcode].
+    pub fn barcode(&self) -> &DecodedBarcode {
+        &self.barcode
+    }
+
+    /// Converts the [AckedRead] into the accepted [DecodedBarcode].
+    pub fn into_barcode(self) -> DecodedBarcode {
+        self.barcode
+    }
+
+    /// Gets the number of retransmissions requested (`NAK`) before the read was accepted.
+    pub const fn retries(&self) -> u8 {
+        self.retries
+    }
+}
+
+/// Sends menu commands to, and reads replies from, the device over a [SerialPort].
+pub struct Transport<P: ?Sized + SerialPort = dyn SerialPort> {
+    port: Box<P>,
+    timeout: Duration,
+    rx: Vec<u8>,
+}
+
+impl Transport {
+    /// Opens a [Transport] on the serial port at `path`, with the default RS232 settings.
+    pub fn open(path: &str, baud: BaudRate) -> Result<Self> {
+        let port = serialport::new(path, baud.baud())
+            .timeout(DEFAULT_TIMEOUT)
+            .open()?;
+
+        Ok(Self::new(port))
+    }
+}
+
+impl<P: ?Sized + SerialPort> Transport<P> {
+    /// Creates a new [Transport] from an open [SerialPort].
+    pub fn new(port: Box<P>) -> Self {
+        Self {
+            port,
+            timeout: DEFAULT_TIMEOUT,
+            rx: Vec::new(),
+        }
+    }
+
+    /// Gets the timeout for device replies.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Builder function that sets the timeout for device replies.
+    pub fn with_timeout(mut self, val: Duration) -> Self {
+        self.timeout = val;
+        self
+    }
+
+    /// Gets a reference to the underlying [SerialPort].
+    pub fn port(&self) -> &P {
+        &self.port
+    }
+
+    /// Gets a mutable reference to the underlying [SerialPort].
+    pub fn port_mut(&mut self) -> &mut P {
+        &mut self.port
+    }
+
+    /// Consumes the [Transport], returning the underlying [SerialPort].
+    pub fn into_inner(self) -> Box<P> {
+        self.port
+    }
+
+    /// Writes a [Command] frame to the device.
+    pub fn write_command(&mut self, cmd: &Command) -> Result<()> {
+        self.write_frame(&cmd.frame())
+    }
+
+    /// Reads a [MenuReply] from the device.
+    ///
+    /// Returns [Timeout](Error::Timeout) if no complete reply is received within the timeout.
+    pub fn read_reply(&mut self) -> Result<MenuReply> {
+        let len = self.read_until(MenuReply::complete_len)?;
+        let reply = MenuReply::parse(&self.rx[..len]);
+
+        self.rx.drain(..len);
+        reply
+    }
+
+    /// Sends a [Command] to the device, and reads the [MenuReply].
+    pub fn send(&mut self, cmd: &Command) -> Result<MenuReply> {
+        self.write_command(cmd)?;
+        self.read_reply()
+    }
+
+    /// Queries the device with the [SoftwareRevision] command, and parses the [DeviceInfo].
+    pub fn device_info(&mut self) -> Result<DeviceInfo> {
+        let cmd = Command::from(SerialCommand::SoftwareRevision(SoftwareRevision::new()));
+        DeviceInfo::try_from(&self.send(&cmd)?)
+    }
+
+    /// Queries the device [Capabilities], derived from the [DeviceInfo].
+    pub fn capabilities(&mut self) -> Result<Capabilities> {
+        self.device_info().map(|info| Capabilities::from(&info))
+    }
+
+    /// Reads a [DeviceConfig] snapshot, querying the current value of every setting tag.
+    pub fn read_config(&mut self) -> Result<DeviceConfig> {
+        self.write_frame(&DeviceConfig::query_frame())?;
+        self.read_reply().map(|reply| DeviceConfig::from(&reply))
+    }
+
+    /// Dumps every command in the [QueryScope] with a wildcard query, e.g. to archive a complete
+    /// configuration snapshot.
+    ///
+    /// Full menu dumps can be large: the reply timeout may need to be increased at low baud rates.
+    pub fn dump(&mut self, scope: &QueryScope, query: QueryCommand) -> Result<DeviceConfig> {
+        self.write_frame(&DeviceConfig::dump_frame(scope, query, Storage::Permanent))?;
+        self.read_reply().map(|reply| DeviceConfig::from(&reply))
+    }
+
+    /// Provisions the device with the [Profile], only sending the settings that differ from the
+    /// current device configuration.
+    ///
+    /// Returns the applied [ConfigDiff], e.g. for a change report.
+    pub fn provision(&mut self, profile: &Profile) -> Result<ConfigDiff> {
+        let diff = self.read_config()?.diff_profile(profile);
+        self.apply_diff(&diff)?;
+        Ok(diff)
+    }
+
+    /// Sends the changed commands in a [ConfigDiff], stopping at the first one that is not
+    /// acknowledged.
+    ///
+    /// Permanent [BaudRate] changes go through [change_baud_rate](Self::change_baud_rate), to
+    /// keep the local serial port in sync with the device.
+    pub fn apply_diff(&mut self, diff: &ConfigDiff) -> Result<()> {
+        diff.to_commands()
+            .iter()
+            .try_for_each(|cmd| match cmd.serial() {
+                SerialCommand::BaudRate(baud) if cmd.storage() == Storage::Permanent => {
+                    self.change_baud_rate(baud)
+                }
+                _ => self.send(cmd)?.ensure_ack(),
+            })
+    }
+
+    /// Resets the device, and waits up to [DEFAULT_RESET_TIMEOUT] for it to come back.
+    pub fn reset(&mut self) -> Result<()> {
+        self.reset_with_timeout(DEFAULT_RESET_TIMEOUT)
+    }
+
+    /// Resets the device, and waits up to `timeout` for it to come back.
+    ///
+    /// The device is polled with the [SoftwareRevision] command until it replies. Errors while
+    /// the device reboots are ignored, but ports that disappear on reset (e.g. USB serial
+    /// emulation) must be reopened by the caller instead.
+    pub fn reset_with_timeout(&mut self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+
+        // the device may reboot before replying
+        match self.send(&Command::from(SerialCommand::Reset(Reset::new()))) {
+            Ok(reply) => reply.ensure_ack()?,
+            Err(Error::Timeout) => (),
+            Err(err) => return Err(err),
+        }
+
+        let poll = Command::from(SerialCommand::SoftwareRevision(SoftwareRevision::new()));
+
+        loop {
+            thread::sleep(RESET_POLL_INTERVAL);
+            self.port.clear(ClearBuffer::All).ok();
+            self.rx.clear();
+
+            if matches!(self.send(&poll), Ok(reply) if reply.is_ack()) {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+        }
+    }
+
+    /// Restores the factory defaults, removing any custom defaults.
+    pub fn restore_factory_defaults(&mut self) -> Result<()> {
+        self.send_all([Defaults::RemoveCustom, Defaults::Activate].map(SerialCommand::Defaults))
+    }
+
+    /// Saves the menu commands as custom defaults, restored by [Defaults::Activate].
+    pub fn save_custom_defaults<I>(&mut self, cmds: I) -> Result<()>
+    where
+        I: IntoIterator<Item = SerialCommand>,
+    {
+        self.send_all(
+            [SerialCommand::Defaults(Defaults::SetCustom)]
+                .into_iter()
+                .chain(cmds)
+                .chain([SerialCommand::Defaults(Defaults::SaveCustom)]),
+        )
+    }
+
+    // Sends each command in order, stopping at the first one that is not acknowledged.
+    fn send_all(&mut self, cmds: impl IntoIterator<Item = SerialCommand>) -> Result<()> {
+        cmds.into_iter()
+            .try_for_each(|cmd| self.send(&Command::from(cmd))?.ensure_ack())
+    }
+
+    /// Changes the device baud rate, and reconfigures the local serial port to match.
+    ///
+    /// The new baud rate is first set in temporary storage, and the link verified with a
+    /// [SoftwareRevision] query, before saving it permanently. If verification fails, the previous
+    /// baud rate is set in temporary storage (at the new speed), and the local port is restored.
+    /// The device reverts on its next power cycle if it missed the restore command.
+    pub fn change_baud_rate(&mut self, baud: BaudRate) -> Result<()> {
+        let prev = self.port.baud_rate()?;
+        let cmd = Command::from(SerialCommand::BaudRate(baud));
+
+        self.send(&cmd.with_storage(Storage::Temporary))?
+            .ensure_ack()?;
+
+        thread::sleep(BAUD_SWITCH_DELAY);
+        self.set_local_baud(baud.baud())?;
+
+        let verify = Command::from(SerialCommand::SoftwareRevision(SoftwareRevision::new()));
+        if let Err(err) = self.send(&verify).and_then(|reply| reply.ensure_ack()) {
+            // best effort: the link is unverified, so the restore reply is not checked
+            if let Ok(prev_baud) = BaudRate::try_from_baud(prev) {
+                let restore = Command::from(SerialCommand::BaudRate(prev_baud));
+                let _ = self.send(&restore.with_storage(Storage::Temporary));
+                thread::sleep(BAUD_SWITCH_DELAY);
+            }
+            self.set_local_baud(prev)?;
+            return Err(err);
+        }
+
+        self.send(&cmd)?.ensure_ack()
+    }
+
+    /// Reads a [DecodedBarcode] transmitted by the device.
+    ///
+    /// Reads are framed by the [ReadFormat] suffix, which must not be empty.
+    pub fn read_barcode(&mut self, fmt: &ReadFormat) -> Result<DecodedBarcode> {
+        let suffix = fmt.suffix();
+        if suffix.is_empty() {
+            return Err(Error::InvalidArgument(
+                "read format suffix must not be empty",
+            ));
+        }
+
+        let len = self.read_until(|rx| {
+            rx.windows(suffix.len())
+                .position(|w| w == suffix)
+                .map(|i| i + suffix.len())
+        })?;
+        let read = DecodedBarcode::parse(&self.rx[..len], fmt);
+
+        self.rx.drain(..len);
+        read
+    }
+
+    /// Reads a [DecodedBarcode] in [HostAck](crate::command::HostAck) mode.
+    ///
+    /// Each read is passed to `validate`: accepted reads are acknowledged with `ACK`, and invalid
+    /// or malformed reads are rejected with `NAK`, requesting a retransmission from the device.
+    /// Returns a [RetriesExhausted](Error::RetriesExhausted) error if the read is still rejected
+    /// after `retries` retransmissions, or a [Timeout](Error::Timeout) error if no read arrives
+    /// within the timeout.
+    pub fn read_barcode_acked<F>(
+        &mut self,
+        fmt: &ReadFormat,
+        retries: u8,
+        mut validate: F,
+    ) -> Result<AckedRead>
+    where
+        F: FnMut(&DecodedBarcode) -> bool,
+    {
+        let mut attempt = 0;
+
+        loop {
+            // malformed reads are rejected like invalid reads
+            let res = match self.read_barcode(fmt) {
+                Err(err @ (Error::Timeout | Error::Io(_) | Error::InvalidArgument(_))) => {
+                    return Err(err)
+                }
+                Ok(read) if validate(&read) => Some(read),
+                _ => None,
+            };
+
+            match res {
+                Some(barcode) => {
+                    self.write_byte(ACK)?;
+                    return Ok(AckedRead {
+                        barcode,
+                        retries: attempt,
+                    });
+                }
+                None => {
+                    self.write_byte(NAK)?;
+                    if attempt >= retries {
+                        return Err(Error::RetriesExhausted {
+                            attempts: attempt as usize + 1,
+                        });
+                    }
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn write_frame(&mut self, frame: &str) -> Result<()> {
+        self.port.write_all(frame.as_bytes())?;
+        self.port.flush()?;
+        Ok(())
+    }
+
+    fn write_byte(&mut self, val: u8) -> Result<()> {
+        self.port.write_all(&[val])?;
+        self.port.flush()?;
+        Ok(())
+    }
+
+    fn set_local_baud(&mut self, baud: u32) -> Result<()> {
+        self.port.set_baud_rate(baud)?;
+        self.port.clear(ClearBuffer::All)?;
+        self.rx.clear();
+        Ok(())
+    }
+
+    // Reads from the port until `complete` finds a complete message in the receive buffer.
+    fn read_until(&mut self, complete: impl Fn(&[u8]) -> Option<usize>) -> Result<usize> {
+        let deadline = Instant::now() + self.timeout;
+        let mut chunk = [0u8; READ_CHUNK];
+
+        loop {
+            if let Some(len) = complete(&self.rx) {
+                return Ok(len);
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+
+            match self.port.read(&mut chunk) {
+                Ok(n) => self.rx.extend_from_slice(&chunk[..n]),
+                Err(err) if err.kind() == ErrorKind::TimedOut => (),
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::{HashMap, VecDeque};
+    use std::io;
+
+    use serialport::{DataBits, FlowControl, Parity, StopBits};
+
+    use super::*;
+    use crate::command::{AimerMode, GoodReadBeep, ReplyStatus, MENU_PREFIX};
+
+    /// Simulated device replying to menu commands, for transport tests.
+    #[derive(Default)]
+    pub(crate) struct MockPort {
+        pub baud: u32,
+        pub device_baud: u32,
+        pub rx: VecDeque<u8>,
+        pub tx: Vec<u8>,
+        pub sent: Vec<String>,
+        pub ignore_baud: bool,
+        pub reads: VecDeque<Vec<u8>>,
+        pub acks: Vec<u8>,
+        pub values: HashMap<String, String>,
+        pub reset_polls: usize,
+        pub rebooting: usize,
+        pub rejects: Vec<String>,
+    }
+
+    impl MockPort {
+        pub fn new(baud: u32) -> Self {
+            Self {
+                baud,
+                device_baud: baud,
+                ..Default::default()
+            }
+        }
+
+        fn reply(&mut self, frame: &str) {
+            let cmd = &frame[..frame.len() - 1];
+            let term = &frame[frame.len() - 1..];
+
+            self.sent.push(frame.into());
+            if self.rebooting > 0 {
+                self.rebooting -= 1;
+                return;
+            }
+            if self.baud != self.device_baud {
+                self.rx.extend(b"\xff\xfe");
+                return;
+            }
+
+            for (i, cmd) in cmd.split(';').enumerate() {
+                if i > 0 {
+                    self.rx.push_back(b';');
+                }
+
+                // reply with the configured value, or echo the command (NAK unknown queries)
+                let (data, status) = match self.values.get(cmd) {
+                    _ if self.rejects.iter().any(|r| r == cmd) => (cmd, NAK),
+                    Some(val) => (val.as_str(), ACK),
+                    None if cmd.ends_with('?') => (cmd, NAK),
+                    None => (cmd, ACK),
+                };
+                self.rx.extend(data.as_bytes());
+                self.rx.push_back(status);
+
+                if cmd == Reset::new().command() {
+                    self.rebooting = self.reset_polls;
+                }
+                if let Some(baud) = BaudRate::try_from(cmd).ok().filter(|_| !self.ignore_baud) {
+                    self.device_baud = baud.baud();
+                }
+            }
+            self.rx.extend(term.as_bytes());
+        }
+    }
+
+    impl Read for MockPort {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.rx.is_empty() {
+                return Err(ErrorKind::TimedOut.into());
+            }
+
+            let n = buf.len().min(self.rx.len());
+            self.rx
+                .drain(..n)
+                .zip(buf.iter_mut())
+                .for_each(|(b, o)| *o = b);
+            Ok(n)
+        }
+    }
+
+    impl Write for MockPort {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.tx.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            let tx = String::from_utf8_lossy(&self.tx).into_owned();
+            self.tx.clear();
+
+            if let Some(frame) = tx.strip_prefix(MENU_PREFIX) {
+                self.reply(frame);
+            } else {
+                self.acks.extend(tx.bytes());
+                // transmit the next read on NAK, or after the host accepts the previous one
+                if let Some(read) = self.reads.pop_front() {
+                    self.rx.extend(read);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl SerialPort for MockPort {
+        fn name(&self) -> Option<String> {
+            None
+        }
+        fn baud_rate(&self) -> serialport::Result<u32> {
+            Ok(self.baud)
+        }
+        fn data_bits(&self) -> serialport::Result<DataBits> {
+            Ok(DataBits::Eight)
+        }
+        fn flow_control(&self) -> serialport::Result<FlowControl> {
+            Ok(FlowControl::None)
+        }
+        fn parity(&self) -> serialport::Result<Parity> {
+            Ok(Parity::None)
+        }
+        fn stop_bits(&self) -> serialport::Result<StopBits> {
+            Ok(StopBits::One)
+        }
+        fn timeout(&self) -> Duration {
+            Duration::ZERO
+        }
+        fn set_baud_rate(&mut self, baud_rate: u32) -> serialport::Result<()> {
+            self.baud = baud_rate;
+            Ok(())
+        }
+        fn set_data_bits(&mut self, _: DataBits) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_flow_control(&mut self, _: FlowControl) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_parity(&mut self, _: Parity) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_stop_bits(&mut self, _: StopBits) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_timeout(&mut self, _: Duration) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn write_request_to_send(&mut self, _: bool) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn write_data_terminal_ready(&mut self, _: bool) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn read_clear_to_send(&mut self) -> serialport::Result<bool> {
+            Ok(true)
+        }
+        fn read_data_set_ready(&mut self) -> serialport::Result<bool> {
+            Ok(true)
+        }
+        fn read_ring_indicator(&mut self) -> serialport::Result<bool> {
+            Ok(false)
+        }
+        fn read_carrier_detect(&mut self) -> serialport::Result<bool> {
+            Ok(false)
+        }
+        fn bytes_to_read(&self) -> serialport::Result<u32> {
+            Ok(self.rx.len() as u32)
+        }
+        fn bytes_to_write(&self) -> serialport::Result<u32> {
+            Ok(0)
+        }
+        fn clear(&self, _: ClearBuffer) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn try_clone(&self) -> serialport::Result<Box<dyn SerialPort>> {
+            Err(serialport::Error::new(
+                serialport::ErrorKind::Unknown,
+                "unsupported",
+            ))
+        }
+        fn set_break(&self) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn clear_break(&self) -> serialport::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_send() {
+        let mut transport = Transport::new(Box::new(MockPort::new(115200)));
+        let cmd = Command::from(SerialCommand::SoftwareRevision(SoftwareRevision::new()));
+        let reply = transport.send(&cmd).unwrap();
+
+        assert!(reply.is_ack());
+        assert_eq!(reply.entries()[0].data(), "REVINF");
+        assert_eq!(transport.port().sent, ["REVINF."]);
+    }
+
+    #[test]
+    fn test_device_info() {
+        let mut port = MockPort::new(115200);
+        port.values.insert(
+            "REVINF".into(),
+            "REVINF\r\nProduct Name: Vuquest 3320g\r\nSoftware Revision: 31205.01\r\n".into(),
+        );
+
+        let mut transport = Transport::new(Box::new(port));
+        let info = transport.device_info().unwrap();
+
+        assert_eq!(info.product_name(), Some("Vuquest 3320g"));
+        assert_eq!(info.firmware_version().unwrap().parts(), [31205, 1]);
+        assert_eq!(transport.capabilities(), Ok(Capabilities::new()));
+
+        transport.port_mut().values.clear();
+        assert_eq!(transport.device_info(), Err(Error::InvalidVariant));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut port = MockPort::new(115200);
+        // device ignores the first polls while rebooting
+        port.reset_polls = 2;
+
+        let mut transport = Transport::new(Box::new(port)).with_timeout(Duration::from_millis(10));
+
+        transport.reset().unwrap();
+        assert_eq!(
+            transport.port().sent,
+            ["RESET.", "REVINF.", "REVINF.", "REVINF."]
+        );
+
+        transport.port_mut().reset_polls = usize::MAX;
+        assert_eq!(
+            transport.reset_with_timeout(Duration::from_millis(500)),
+            Err(Error::Timeout)
+        );
+    }
+
+    #[test]
+    fn test_defaults() {
+        let mut transport = Transport::new(Box::new(MockPort::new(115200)));
+
+        transport.restore_factory_defaults().unwrap();
+        transport
+            .save_custom_defaults([SerialCommand::AimerMode(AimerMode::Off)])
+            .unwrap();
+
+        assert_eq!(
+            transport.port().sent,
+            ["DEFOVR.", "DEFALT.", "MNUCDP.", "SCNAIM0.", "MNUCDS."]
+        );
+    }
+
+    #[test]
+    fn test_provision() {
+        let mut port = MockPort::new(115200);
+        port.values.extend(
+            [
+                ("SCNAIM?", "SCNAIM1"),
+                ("BEPBEP?", "BEPBEP1"),
+                ("232BAD?", "232BAD9"),
+            ]
+            .map(|(k, v)| (k.into(), v.into())),
+        );
+
+        let mut transport = Transport::new(Box::new(port));
+        let cfg = transport.read_config().unwrap();
+
+        assert_eq!(cfg.settings().len(), 3);
+        assert_eq!(
+            cfg.unknown().len(),
+            DeviceConfig::tags().count() - cfg.settings().len()
+        );
+
+        let profile = Profile::new()
+            .with_aimer_mode(AimerMode::Off)
+            .with_good_read_beep(GoodReadBeep::On)
+            .with_baud_rate(BaudRate::Baud9600);
+
+        transport.port_mut().sent.clear();
+        let diff = transport.provision(&profile).unwrap();
+
+        assert_eq!(diff.changes().count(), 2);
+        assert_eq!(
+            transport.port().sent[1..],
+            ["SCNAIM0.", "232BAD5!", "REVINF.", "232BAD5."]
+        );
+        assert_eq!(transport.port().baud, 9600);
+    }
+
+    #[test]
+    fn test_dump() {
+        let mut port = MockPort::new(115200);
+        port.values
+            .insert("?".into(), "BEPBEP1,SCNAIM2,XYZABC7,232BAD9".into());
+        port.values.insert("BEP^".into(), "BEPBEP1,BEPLED1".into());
+
+        let mut transport = Transport::new(Box::new(port));
+        let cfg = transport
+            .dump(&QueryScope::Menu, QueryCommand::CurrentValue)
+            .unwrap();
+
+        assert_eq!(cfg.settings().len(), 3);
+        assert_eq!(cfg.unknown(), ["XYZABC7"]);
+
+        let group = QueryScope::try_group("BEP").unwrap();
+        let cfg = transport.dump(&group, QueryCommand::DefaultValue).unwrap();
+
+        assert_eq!(cfg.entries(), ["BEPBEP1", "BEPLED1"]);
+        assert_eq!(transport.port().sent, ["?.", "BEP^."]);
+    }
+
+    #[test]
+    fn test_change_baud_rate() {
+        let mut transport = Transport::new(Box::new(MockPort::new(115200)));
+
+        transport.change_baud_rate(BaudRate::Baud9600).unwrap();
+
+        assert_eq!(transport.port().baud, 9600);
+        assert_eq!(transport.port().device_baud, 9600);
+        assert_eq!(transport.port().sent, ["232BAD5!", "REVINF.", "232BAD5."]);
+    }
+
+    #[test]
+    fn test_change_baud_rate_failed() {
+        let mut port = MockPort::new(115200);
+        // device acknowledges, but stays at the previous baud rate
+        port.ignore_baud = true;
+
+        let mut transport = Transport::new(Box::new(port)).with_timeout(Duration::from_millis(10));
+
+        assert_eq!(
+            transport.change_baud_rate(BaudRate::Baud9600),
+            Err(Error::Timeout)
+        );
+        assert_eq!(transport.port().baud, 115200);
+        assert_eq!(transport.port().sent, ["232BAD5!", "REVINF.", "232BAD9!"]);
+    }
+
+    #[test]
+    fn test_change_baud_rate_rejected() {
+        let mut port = MockPort::new(115200);
+        // device switches baud rate, but rejects the verification query
+        port.rejects.push("REVINF".into());
+
+        let mut transport = Transport::new(Box::new(port));
+
+        assert_eq!(
+            transport.change_baud_rate(BaudRate::Baud9600),
+            Err(Error::Rejected {
+                command: "REVINF".into(),
+                status: ReplyStatus::Nak
+            })
+        );
+        assert_eq!(transport.port().sent, ["232BAD5!", "REVINF.", "232BAD9!"]);
+        assert_eq!(transport.port().baud, 115200);
+        assert_eq!(transport.port().device_baud, 115200);
+    }
+
+    #[test]
+    fn test_read_barcode_acked() {
+        let fmt = ReadFormat::new().with_suffix(b"\r");
+        let mut port = MockPort::new(115200);
+
+        port.rx.extend(b"12345\r");
+        port.reads.push_back(b"1234567890\r".to_vec());
+
+        let mut transport = Transport::new(Box::new(port)).with_timeout(Duration::from_millis(10));
+        let read = transport
+            .read_barcode_acked(&fmt, DEFAULT_HOST_ACK_RETRIES, |r| r.payload().len() == 10)
+            .unwrap();
+
+        assert_eq!(read.barcode().payload(), b"1234567890");
+        assert_eq!(read.retries(), 1);
+        assert_eq!(transport.port().acks, [NAK, ACK]);
+    }
+
+    #[test]
+    fn test_read_barcode_acked_retries() {
+        let fmt = ReadFormat::new().with_suffix(b"\r");
+        let mut port = MockPort::new(115200);
+
+        port.rx.extend(b"bad\r");
+        port.reads.extend([b"bad\r".to_vec(), b"bad\r".to_vec()]);
+
+        let mut transport = Transport::new(Box::new(port)).with_timeout(Duration::from_millis(10));
+
+        assert_eq!(
+            transport.read_barcode_acked(&fmt, 1, |r| r.payload() == b"good"),
+            Err(Error::RetriesExhausted { attempts: 2 })
+        );
+        assert_eq!(transport.port().acks, [NAK, NAK]);
+        assert_eq!(
+            transport.read_barcode(&ReadFormat::new()),
+            Err(Error::InvalidArgument(
+                "read format suffix must not be empty"
+            ))
+        );
+        assert_eq!(
+            transport.read_barcode_acked(&ReadFormat::new(), 1, |_| true),
+            Err(Error::InvalidArgument(
+                "read format suffix must not be empty"
+            ))
+        );
+
+        transport.port_mut().rx.clear();
+        assert_eq!(
+            transport.read_barcode_acked(&fmt, 1, |_| true),
+            Err(Error::Timeout)
+        );
+    }
+}